@@ -2,8 +2,14 @@
 //!
 //! This module provides test vectors for ensuring consistent encoding/decoding
 //! across different blockchain types and transaction formats.
+//!
+//! Vector sets can be kept outside the crate as JSON or TOML files and loaded
+//! with [`GoldenVectorSet::load_from_dir`]. New vectors are recorded by
+//! encoding their inputs with [`GoldenVector::record`] and written back with
+//! [`GoldenVectorSet::write_to_file`].
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// A golden vector test case for encoding verification
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -41,12 +47,25 @@ pub enum GoldenVectorInput {
     SubstrateExtrinsic {
         pallet: String,
         call: String,
+        /// Index of the pallet in the runtime's `construct_runtime!`
+        pallet_index: u8,
+        /// Index of the call within the pallet
+        call_index: u8,
         args: Vec<SubstrateValue>,
         era: Option<SubstrateEra>,
         nonce: u64,
+        #[serde(with = "u128_compat")]
         tip: u128,
+        /// Runtime `spec_version` signed into the payload
+        spec_version: u32,
+        /// Runtime `transaction_version` signed into the payload
+        transaction_version: u32,
         genesis_hash: String,
         block_hash: String,
+        /// `CheckMetadataHash` mode signed into the extra (`true` for `Enabled`)
+        metadata_hash_mode: bool,
+        /// `CheckMetadataHash` metadata hash signed into the additional data
+        metadata_hash: Option<String>,
     },
     /// Simple balance transfer
     BalanceTransfer {
@@ -58,18 +77,18 @@ pub enum GoldenVectorInput {
 
 /// Substrate value for encoding
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type")]
+#[serde(tag = "type", content = "value")]
 pub enum SubstrateValue {
     U8(u8),
     U16(u16),
     U32(u32),
     U64(u64),
-    U128(u128),
+    U128(#[serde(with = "u128_compat")] u128),
     Bool(bool),
     String(String),
     Bytes(Vec<u8>),
     AccountId(String),
-    Balance(u128),
+    Balance(#[serde(with = "u128_compat")] u128),
 }
 
 /// Substrate era information
@@ -92,6 +111,26 @@ pub enum ChainType {
     Bitcoin,
 }
 
+/// On-disk format of a golden vector file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoldenVectorFormat {
+    /// `.json` files
+    Json,
+    /// `.toml` files
+    Toml,
+}
+
+impl GoldenVectorFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            ext if ext.eq_ignore_ascii_case("json") => Some(Self::Json),
+            ext if ext.eq_ignore_ascii_case("toml") => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
 /// Collection of golden vectors
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GoldenVectorSet {
@@ -124,11 +163,134 @@ impl GoldenVectorSet {
     pub fn verify_all(&self) -> Result<(), String> {
         for (i, vector) in self.vectors.iter().enumerate() {
             if let Err(e) = verify_golden_vector(vector) {
-                return Err(format!("Vector {} failed verification: {}", i, e));
+                return Err(format!(
+                    "Vector {} ({}) failed verification: {}",
+                    i, vector.name, e
+                ));
             }
         }
         Ok(())
     }
+
+    /// Encode `input` and append the resulting vector to the set
+    ///
+    /// See [`GoldenVector::record`].
+    pub fn record(
+        &mut self,
+        name: impl Into<String>,
+        input: GoldenVectorInput,
+        encoding_version: impl Into<String>,
+    ) -> Result<&GoldenVector, String> {
+        self.vectors
+            .push(GoldenVector::record(name, input, encoding_version)?);
+        Ok(self.vectors.last().expect("vector was just pushed"))
+    }
+
+    /// Parse a vector set from a string in the given format
+    pub fn parse(contents: &str, format: GoldenVectorFormat) -> Result<Self, String> {
+        match format {
+            GoldenVectorFormat::Json => {
+                serde_json::from_str(contents).map_err(|e| format!("Invalid JSON: {}", e))
+            }
+            GoldenVectorFormat::Toml => {
+                toml::from_str(contents).map_err(|e| format!("Invalid TOML: {}", e))
+            }
+        }
+    }
+
+    /// Serialize the vector set in the given format
+    pub fn serialize(&self, format: GoldenVectorFormat) -> Result<String, String> {
+        match format {
+            GoldenVectorFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+            GoldenVectorFormat::Toml => {
+                toml::to_string_pretty(self).map_err(|e| format!("Failed to serialize TOML: {}", e))
+            }
+        }
+    }
+
+    /// Load a vector set from a `.json` or `.toml` file
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let format = GoldenVectorFormat::from_path(path)
+            .ok_or_else(|| format!("Unsupported golden vector file: {}", path.display()))?;
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        Self::parse(&contents, format).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Load and merge every `.json` and `.toml` vector file in a directory
+    ///
+    /// Files are read in file-name order so that vector indices are stable
+    /// across runs. Other files and subdirectories are ignored.
+    pub fn load_from_dir(dir: impl AsRef<Path>) -> Result<Self, String> {
+        let dir = dir.as_ref();
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?
+                .path();
+            if path.is_file() && GoldenVectorFormat::from_path(&path).is_some() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut set = Self::new();
+        for path in paths {
+            tracing::debug!("Loading golden vectors from {}", path.display());
+            set.vectors.extend(Self::load_from_file(&path)?.vectors);
+        }
+
+        Ok(set)
+    }
+
+    /// Write the vector set to a `.json` or `.toml` file
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let format = GoldenVectorFormat::from_path(path)
+            .ok_or_else(|| format!("Unsupported golden vector file: {}", path.display()))?;
+
+        std::fs::write(path, self.serialize(format)?)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+impl GoldenVector {
+    /// Record a new golden vector by encoding `input`
+    ///
+    /// The chain type is derived from the input, and `expected_encoded` is
+    /// set to the `0x`-prefixed output of [`encode_golden_vector_input`].
+    /// EVM inputs go through alloy's transaction encoders. Substrate inputs
+    /// go through this module's SCALE encoder, not subxt; apex-sdk-substrate
+    /// tests it against subxt's signer payload.
+    pub fn record(
+        name: impl Into<String>,
+        input: GoldenVectorInput,
+        encoding_version: impl Into<String>,
+    ) -> Result<Self, String> {
+        let encoding_version = encoding_version.into();
+        let chain_type = match &input {
+            GoldenVectorInput::EvmTransaction { .. } => ChainType::Ethereum,
+            GoldenVectorInput::SubstrateExtrinsic { .. } => ChainType::Substrate,
+            GoldenVectorInput::BalanceTransfer { .. } => {
+                return Err("BalanceTransfer inputs have no canonical wire encoding".to_string())
+            }
+        };
+        let encoded = encode_golden_vector_input(&input, &encoding_version)?;
+
+        Ok(Self {
+            name: name.into(),
+            input,
+            expected_encoded: format!("0x{}", hex::encode(encoded)),
+            chain_type,
+            encoding_version,
+        })
+    }
 }
 
 impl Default for GoldenVectorSet {
@@ -138,6 +300,11 @@ impl Default for GoldenVectorSet {
 }
 
 /// Verify a single golden vector
+///
+/// The input is re-encoded (EVM inputs with alloy's RLP encoders, Substrate
+/// inputs with this module's SCALE encoder) and compared byte-for-byte with
+/// `expected_encoded`. On mismatch
+/// the error names the first field whose encoding differs.
 pub fn verify_golden_vector(vector: &GoldenVector) -> Result<(), String> {
     match &vector.input {
         GoldenVectorInput::EvmTransaction { .. } => verify_evm_transaction_encoding(vector),
@@ -146,19 +313,97 @@ pub fn verify_golden_vector(vector: &GoldenVector) -> Result<(), String> {
     }
 }
 
+/// Encode a golden vector input into its canonical byte representation
+///
+/// - EVM transactions encode to the unsigned signing payload: the EIP-155
+///   RLP list for legacy transactions, and `type || rlp(fields)` for
+///   EIP-2930 and EIP-1559 transactions.
+/// - Substrate extrinsics encode to the SCALE signing payload with the
+///   Polkadot signed extensions: `call || era || nonce || tip ||
+///   metadata_hash_mode || spec_version || transaction_version ||
+///   genesis_hash || block_hash || metadata_hash`. Payloads longer than 256
+///   bytes are signed as their `blake2_256` hash; vectors keep the unhashed
+///   payload.
+///
+/// `encoding_version` selects the EVM transaction type (`"legacy"`,
+/// `"eip2930"` or `"eip1559"`); any other value infers the type from the
+/// fee fields that are set.
+pub fn encode_golden_vector_input(
+    input: &GoldenVectorInput,
+    encoding_version: &str,
+) -> Result<Vec<u8>, String> {
+    match input {
+        GoldenVectorInput::EvmTransaction { .. } => {
+            let (_, encoded) = encode_evm_transaction(input, encoding_version)?;
+            Ok(encoded)
+        }
+        GoldenVectorInput::SubstrateExtrinsic { .. } => Ok(encode_substrate_extrinsic(input)?
+            .into_iter()
+            .flat_map(|(_, bytes)| bytes)
+            .collect()),
+        GoldenVectorInput::BalanceTransfer { .. } => {
+            Err("BalanceTransfer inputs have no canonical wire encoding".to_string())
+        }
+    }
+}
+
+/// EVM transaction envelope selected for a golden vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EvmTxType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
+impl EvmTxType {
+    /// RLP list field names, in encoding order
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Legacy => &[
+                "nonce",
+                "gas_price",
+                "gas_limit",
+                "to",
+                "value",
+                "data",
+                "chain_id",
+                "eip155_r",
+                "eip155_s",
+            ],
+            Self::Eip2930 => &[
+                "chain_id",
+                "nonce",
+                "gas_price",
+                "gas_limit",
+                "to",
+                "value",
+                "data",
+                "access_list",
+            ],
+            Self::Eip1559 => &[
+                "chain_id",
+                "nonce",
+                "max_priority_fee_per_gas",
+                "max_fee_per_gas",
+                "gas_limit",
+                "to",
+                "value",
+                "data",
+                "access_list",
+            ],
+        }
+    }
+}
+
 /// Verify EVM transaction encoding
 fn verify_evm_transaction_encoding(vector: &GoldenVector) -> Result<(), String> {
     if let GoldenVectorInput::EvmTransaction {
         from,
         to,
         value,
-        gas_limit: _,
-        gas_price: _,
-        max_fee_per_gas: _,
-        max_priority_fee_per_gas: _,
         nonce,
-        data: _,
         chain_id,
+        ..
     } = &vector.input
     {
         tracing::debug!(
@@ -169,20 +414,14 @@ fn verify_evm_transaction_encoding(vector: &GoldenVector) -> Result<(), String>
             nonce,
             chain_id
         );
-        if !vector.expected_encoded.starts_with("0x") {
-            return Err("Expected encoded value must start with 0x".to_string());
-        }
-
-        // Verify hex encoding is valid
-        hex::decode(&vector.expected_encoded[2..])
-            .map_err(|e| format!("Invalid hex encoding: {}", e))?;
+        let expected = decode_expected_hex(&vector.expected_encoded)?;
+        let (tx_type, encoded) = encode_evm_transaction(&vector.input, &vector.encoding_version)?;
 
-        // Additional validations could include:
-        // - Address format validation
-        // - Gas values within reasonable ranges
-        // - Proper EIP-1559 vs legacy transaction format
+        if encoded == expected {
+            return Ok(());
+        }
 
-        Ok(())
+        Err(describe_evm_mismatch(tx_type, &expected, &encoded))
     } else {
         Err("Vector input type mismatch".to_string())
     }
@@ -193,12 +432,9 @@ fn verify_substrate_extrinsic_encoding(vector: &GoldenVector) -> Result<(), Stri
     if let GoldenVectorInput::SubstrateExtrinsic {
         pallet,
         call,
-        args: _,
-        era: _,
         nonce,
         tip,
-        genesis_hash: _,
-        block_hash: _,
+        ..
     } = &vector.input
     {
         tracing::debug!(
@@ -208,20 +444,31 @@ fn verify_substrate_extrinsic_encoding(vector: &GoldenVector) -> Result<(), Stri
             nonce,
             tip
         );
+        let expected = decode_expected_hex(&vector.expected_encoded)?;
+        let fields = encode_substrate_extrinsic(&vector.input)?;
 
-        // Verify hex encoding is valid
-        if !vector.expected_encoded.starts_with("0x") {
-            return Err("Expected encoded value must start with 0x".to_string());
+        let mut offset = 0;
+        for (name, bytes) in &fields {
+            let end = offset + bytes.len();
+            let actual = expected.get(offset..end.min(expected.len())).unwrap_or(&[]);
+            if actual != bytes.as_slice() {
+                return Err(format!(
+                    "Field `{}` differs at byte {}: expected 0x{}, encoded 0x{}",
+                    name,
+                    offset,
+                    hex::encode(actual),
+                    hex::encode(bytes)
+                ));
+            }
+            offset = end;
         }
 
-        hex::decode(&vector.expected_encoded[2..])
-            .map_err(|e| format!("Invalid hex encoding: {}", e))?;
-
-        // Additional validations for Substrate:
-        // - Genesis hash format (32 bytes)
-        // - Block hash format (32 bytes)
-        // - Era period validation
-        // - SCALE encoding verification
+        if offset != expected.len() {
+            return Err(format!(
+                "Expected encoding has {} trailing bytes after `metadata_hash`",
+                expected.len() - offset
+            ));
+        }
 
         Ok(())
     } else {
@@ -230,6 +477,9 @@ fn verify_substrate_extrinsic_encoding(vector: &GoldenVector) -> Result<(), Stri
 }
 
 /// Verify balance transfer encoding
+///
+/// `BalanceTransfer` is chain-agnostic and has no wire format of its own, so
+/// only the shape of the expected encoding is checked.
 fn verify_balance_transfer_encoding(vector: &GoldenVector) -> Result<(), String> {
     if let GoldenVectorInput::BalanceTransfer { from, to, amount } = &vector.input {
         tracing::debug!(
@@ -239,13 +489,7 @@ fn verify_balance_transfer_encoding(vector: &GoldenVector) -> Result<(), String>
             amount
         );
 
-        // Verify hex encoding is valid
-        if !vector.expected_encoded.starts_with("0x") {
-            return Err("Expected encoded value must start with 0x".to_string());
-        }
-
-        hex::decode(&vector.expected_encoded[2..])
-            .map_err(|e| format!("Invalid hex encoding: {}", e))?;
+        decode_expected_hex(&vector.expected_encoded)?;
 
         Ok(())
     } else {
@@ -253,6 +497,320 @@ fn verify_balance_transfer_encoding(vector: &GoldenVector) -> Result<(), String>
     }
 }
 
+/// Decode a `0x`-prefixed expected encoding
+fn decode_expected_hex(expected: &str) -> Result<Vec<u8>, String> {
+    let stripped = expected
+        .strip_prefix("0x")
+        .ok_or_else(|| "Expected encoded value must start with 0x".to_string())?;
+
+    hex::decode(stripped).map_err(|e| format!("Invalid hex encoding: {}", e))
+}
+
+/// Encode an EVM golden vector input into its unsigned signing payload
+fn encode_evm_transaction(
+    input: &GoldenVectorInput,
+    encoding_version: &str,
+) -> Result<(EvmTxType, Vec<u8>), String> {
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxEip2930, TxLegacy};
+
+    let GoldenVectorInput::EvmTransaction {
+        to,
+        value,
+        gas_limit,
+        gas_price,
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+        nonce,
+        data,
+        chain_id,
+        ..
+    } = input
+    else {
+        return Err("Vector input type mismatch".to_string());
+    };
+
+    let tx_type = match encoding_version {
+        "legacy" => EvmTxType::Legacy,
+        "eip2930" => EvmTxType::Eip2930,
+        "eip1559" => EvmTxType::Eip1559,
+        _ if max_fee_per_gas.is_some() => EvmTxType::Eip1559,
+        _ => EvmTxType::Legacy,
+    };
+
+    let to = parse_tx_kind(to)?;
+    let value = parse_hex_u256("value", value)?;
+    let input_data = match data {
+        Some(data) => hex::decode(data.trim_start_matches("0x"))
+            .map_err(|e| format!("Invalid hex in `data`: {}", e))?,
+        None => Vec::new(),
+    };
+    let required = |name: &str, field: &Option<String>| -> Result<u128, String> {
+        let raw = field
+            .as_deref()
+            .ok_or_else(|| format!("`{}` is required for {:?} transactions", name, tx_type))?;
+        parse_hex_u128(name, raw)
+    };
+
+    let mut encoded = Vec::new();
+    match tx_type {
+        EvmTxType::Legacy => TxLegacy {
+            chain_id: Some(*chain_id),
+            nonce: *nonce,
+            gas_price: required("gas_price", gas_price)?,
+            gas_limit: *gas_limit,
+            to,
+            value,
+            input: input_data.into(),
+        }
+        .encode_for_signing(&mut encoded),
+        EvmTxType::Eip2930 => TxEip2930 {
+            chain_id: *chain_id,
+            nonce: *nonce,
+            gas_price: required("gas_price", gas_price)?,
+            gas_limit: *gas_limit,
+            to,
+            value,
+            access_list: Default::default(),
+            input: input_data.into(),
+        }
+        .encode_for_signing(&mut encoded),
+        EvmTxType::Eip1559 => TxEip1559 {
+            chain_id: *chain_id,
+            nonce: *nonce,
+            gas_limit: *gas_limit,
+            max_fee_per_gas: required("max_fee_per_gas", max_fee_per_gas)?,
+            max_priority_fee_per_gas: required(
+                "max_priority_fee_per_gas",
+                max_priority_fee_per_gas,
+            )?,
+            to,
+            value,
+            access_list: Default::default(),
+            input: input_data.into(),
+        }
+        .encode_for_signing(&mut encoded),
+    }
+
+    Ok((tx_type, encoded))
+}
+
+/// Describe the first field where two EVM signing payloads differ
+fn describe_evm_mismatch(tx_type: EvmTxType, expected: &[u8], encoded: &[u8]) -> String {
+    let (expected_prefix, expected_fields) = match split_typed_rlp_list(expected) {
+        Ok(parts) => parts,
+        Err(e) => return format!("Expected encoding is not a valid RLP transaction: {}", e),
+    };
+    let (encoded_prefix, encoded_fields) = match split_typed_rlp_list(encoded) {
+        Ok(parts) => parts,
+        Err(e) => return format!("Encoder produced invalid RLP: {}", e),
+    };
+
+    if expected_prefix != encoded_prefix {
+        return format!(
+            "Field `tx_type` differs: expected {:?}, encoded {:?}",
+            expected_prefix, encoded_prefix
+        );
+    }
+
+    let names = tx_type.field_names();
+    for (i, (exp, enc)) in expected_fields.iter().zip(&encoded_fields).enumerate() {
+        if exp != enc {
+            return format!(
+                "Field `{}` differs: expected 0x{}, encoded 0x{}",
+                names.get(i).copied().unwrap_or("unknown"),
+                hex::encode(exp),
+                hex::encode(enc)
+            );
+        }
+    }
+
+    format!(
+        "Field count differs: expected {} RLP fields, encoded {}",
+        expected_fields.len(),
+        encoded_fields.len()
+    )
+}
+
+/// Split an optionally typed RLP transaction into its type byte and raw list items
+fn split_typed_rlp_list(bytes: &[u8]) -> Result<(Option<u8>, Vec<&[u8]>), String> {
+    let (tx_type, mut payload) = match bytes.first() {
+        Some(&first) if first < 0x7f => (Some(first), &bytes[1..]),
+        _ => (None, bytes),
+    };
+
+    let header = alloy_rlp::Header::decode(&mut payload).map_err(|e| e.to_string())?;
+    if !header.list {
+        return Err("expected an RLP list".to_string());
+    }
+    let mut body = payload
+        .get(..header.payload_length)
+        .ok_or_else(|| "RLP list is truncated".to_string())?;
+
+    let mut items = Vec::new();
+    while !body.is_empty() {
+        let start = body;
+        let item = alloy_rlp::Header::decode(&mut body).map_err(|e| e.to_string())?;
+        let consumed = start.len() - body.len() + item.payload_length;
+        let raw = start
+            .get(..consumed)
+            .ok_or_else(|| "RLP item is truncated".to_string())?;
+        items.push(raw);
+        body = &start[consumed..];
+    }
+
+    Ok((tx_type, items))
+}
+
+/// Encode a Substrate golden vector input as named SCALE segments
+fn encode_substrate_extrinsic(
+    input: &GoldenVectorInput,
+) -> Result<Vec<(&'static str, Vec<u8>)>, String> {
+    use parity_scale_codec::{Compact, Encode};
+
+    let GoldenVectorInput::SubstrateExtrinsic {
+        pallet_index,
+        call_index,
+        args,
+        era,
+        nonce,
+        tip,
+        spec_version,
+        transaction_version,
+        genesis_hash,
+        block_hash,
+        metadata_hash_mode,
+        metadata_hash,
+        ..
+    } = input
+    else {
+        return Err("Vector input type mismatch".to_string());
+    };
+
+    let mut call = vec![*pallet_index, *call_index];
+    for arg in args {
+        call.extend(encode_substrate_value(arg)?);
+    }
+
+    let metadata_hash = metadata_hash
+        .as_deref()
+        .map(|hash| parse_hash("metadata_hash", hash))
+        .transpose()?;
+
+    Ok(vec![
+        ("call", call),
+        ("era", encode_era(era.as_ref())),
+        ("nonce", Compact(*nonce).encode()),
+        ("tip", Compact(*tip).encode()),
+        ("metadata_hash_mode", (*metadata_hash_mode as u8).encode()),
+        ("spec_version", spec_version.encode()),
+        ("transaction_version", transaction_version.encode()),
+        (
+            "genesis_hash",
+            parse_hash("genesis_hash", genesis_hash)?.to_vec(),
+        ),
+        ("block_hash", parse_hash("block_hash", block_hash)?.to_vec()),
+        ("metadata_hash", metadata_hash.encode()),
+    ])
+}
+
+/// SCALE-encode a single call argument
+///
+/// `AccountId` encodes as `MultiAddress::Id` and `Balance` as a compact
+/// `u128`, matching the argument types of the `Balances` transfer calls.
+fn encode_substrate_value(value: &SubstrateValue) -> Result<Vec<u8>, String> {
+    use parity_scale_codec::{Compact, Encode};
+
+    Ok(match value {
+        SubstrateValue::U8(v) => v.encode(),
+        SubstrateValue::U16(v) => v.encode(),
+        SubstrateValue::U32(v) => v.encode(),
+        SubstrateValue::U64(v) => v.encode(),
+        SubstrateValue::U128(v) => v.encode(),
+        SubstrateValue::Bool(v) => v.encode(),
+        SubstrateValue::String(v) => v.encode(),
+        SubstrateValue::Bytes(v) => v.encode(),
+        SubstrateValue::AccountId(v) => {
+            let mut encoded = vec![0u8];
+            encoded.extend_from_slice(&decode_account_id(v)?);
+            encoded
+        }
+        SubstrateValue::Balance(v) => Compact(*v).encode(),
+    })
+}
+
+/// Encode a transaction era the way `sp_runtime::generic::Era` does
+///
+/// `None` is an immortal era. Mortal periods are rounded up to a power of two
+/// in `4..=65536`.
+fn encode_era(era: Option<&SubstrateEra>) -> Vec<u8> {
+    let Some(era) = era else {
+        return vec![0];
+    };
+
+    let period = era
+        .period
+        .checked_next_power_of_two()
+        .unwrap_or(1 << 16)
+        .clamp(4, 1 << 16);
+    let phase = era.phase % period;
+    let quantize_factor = (period >> 12).max(1);
+    let encoded = (period.trailing_zeros() - 1).clamp(1, 15) as u16
+        | (((phase / quantize_factor) << 4) as u16);
+
+    encoded.to_le_bytes().to_vec()
+}
+
+/// Decode an SS58 or `0x`-hex account into its 32-byte public key
+fn decode_account_id(account: &str) -> Result<[u8; 32], String> {
+    if account.starts_with("0x") {
+        return parse_hash("account_id", account);
+    }
+
+    apex_sdk_types::Address::substrate_checked(account)
+        .map_err(|e| format!("Invalid account `{}`: {}", account, e))?;
+    let decoded = bs58::decode(account)
+        .into_vec()
+        .map_err(|e| format!("Invalid account `{}`: {}", account, e))?;
+
+    // 1- or 2-byte network prefix, 32-byte account, 2-byte checksum
+    let prefix_len = if decoded.first().is_some_and(|b| b & 0b0100_0000 != 0) {
+        2
+    } else {
+        1
+    };
+    decoded
+        .get(prefix_len..prefix_len + 32)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("Account `{}` is not a 32-byte account id", account))
+}
+
+fn parse_hash(name: &str, value: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid hex in `{}`: {}", name, e))?;
+    bytes
+        .try_into()
+        .map_err(|_| format!("`{}` must be 32 bytes", name))
+}
+
+fn parse_tx_kind(to: &str) -> Result<alloy_primitives::TxKind, String> {
+    if to.is_empty() {
+        return Ok(alloy_primitives::TxKind::Create);
+    }
+    to.parse::<alloy_primitives::Address>()
+        .map(alloy_primitives::TxKind::Call)
+        .map_err(|e| format!("Invalid `to` address: {}", e))
+}
+
+fn parse_hex_u256(name: &str, value: &str) -> Result<alloy_primitives::U256, String> {
+    alloy_primitives::U256::from_str_radix(value.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("Invalid hex quantity in `{}`: {}", name, e))
+}
+
+fn parse_hex_u128(name: &str, value: &str) -> Result<u128, String> {
+    u128::from_str_radix(value.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("Invalid hex quantity in `{}`: {}", name, e))
+}
+
 /// Load golden vectors from embedded test data
 pub fn load_default_golden_vectors() -> GoldenVectorSet {
     let mut vectors = GoldenVectorSet::new();
@@ -272,8 +830,10 @@ pub fn load_default_golden_vectors() -> GoldenVectorSet {
             data: None,
             chain_id: 1,
         },
-        // Valid complete RLP-encoded legacy transaction (unsigned/example signature)
-        expected_encoded: "0xf86c808504a817c800825208947742d35cc6634c0532925a3b844bc9e7595f0beb7820384808025a01234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdefa0abcdef1234567890abcdef1234567890abcdef1234567890abcdef12345678900".to_string(),
+        // EIP-155 signing payload: rlp([nonce, gas_price, gas_limit, to, value, data, chain_id, 0, 0])
+        expected_encoded:
+            "0xe6808504a817c80082520894742d35cc6634c0532925a3b844bc9e7595f0beb78203e880018080"
+                .to_string(),
         chain_type: ChainType::Ethereum,
         encoding_version: "legacy".to_string(),
     });
@@ -293,8 +853,8 @@ pub fn load_default_golden_vectors() -> GoldenVectorSet {
             data: None,
             chain_id: 1,
         },
-        // Valid complete EIP-1559 RLP-encoded transaction (unsigned/example signature)
-        expected_encoded: "0x02f86f0101843b9aca008477359400825208947742d35cc6634c0532925a3b844bc9e7595f0beb782038480c001a0fedcba0987654321fedcba0987654321fedcba0987654321fedcba0987654321a0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef01".to_string(),
+        // EIP-1559 signing payload: 0x02 || rlp([chain_id, nonce, tip, fee_cap, gas_limit, to, value, data, access_list])
+        expected_encoded: "0x02e90101843b9aca00847735940082520894742d35cc6634c0532925a3b844bc9e7595f0beb78203e880c0".to_string(),
         chain_type: ChainType::Ethereum,
         encoding_version: "eip1559".to_string(),
     });
@@ -305,6 +865,8 @@ pub fn load_default_golden_vectors() -> GoldenVectorSet {
         input: GoldenVectorInput::SubstrateExtrinsic {
             pallet: "Balances".to_string(),
             call: "transfer_keep_alive".to_string(),
+            pallet_index: 5,
+            call_index: 3,
             args: vec![
                 SubstrateValue::AccountId("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string()),
                 SubstrateValue::Balance(1_000_000_000_000), // 1 DOT
@@ -312,11 +874,16 @@ pub fn load_default_golden_vectors() -> GoldenVectorSet {
             era: Some(SubstrateEra { period: 64, phase: 32 }),
             nonce: 0,
             tip: 0,
+            spec_version: 1_003_000,
+            transaction_version: 26,
             genesis_hash: "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3".to_string(),
             block_hash: "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3".to_string(),
+            metadata_hash_mode: false,
+            metadata_hash: None,
         },
-        // Valid complete SCALE-encoded extrinsic (example format - properly padded)
-        expected_encoded: "0x450284004e29c1cb7faca89927e98dcbe06c01a1e66e4e89b5f1fb75ae9dc68c67d05b48d50140420f0000000000000000000000000000d1018282d71a8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f070010a5d4e0".to_string(),
+        // subxt signer payload: call || era || nonce || tip || metadata_hash_mode ||
+        // spec || tx_version || genesis || block || metadata_hash
+        expected_encoded: "0x050300d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d070010a5d4e80502000000f84d0f001a00000091b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c391b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c300".to_string(),
         chain_type: ChainType::Substrate,
         encoding_version: "scale_v1".to_string(),
     });
@@ -324,6 +891,52 @@ pub fn load_default_golden_vectors() -> GoldenVectorSet {
     vectors
 }
 
+/// Serde helpers for `u128` fields
+///
+/// TOML integers are 64-bit signed, so values that do not fit in an `i64` are
+/// written as decimal strings. Both integers and strings are accepted on read.
+mod u128_compat {
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        match i64::try_from(*value) {
+            Ok(small) => serializer.serialize_i64(small),
+            Err(_) => serializer.serialize_str(&value.to_string()),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        struct U128Visitor;
+
+        impl de::Visitor<'_> for U128Visitor {
+            type Value = u128;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an unsigned integer or a decimal string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<u128, E> {
+                Ok(v.into())
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<u128, E> {
+                u128::try_from(v).map_err(E::custom)
+            }
+
+            fn visit_u128<E: de::Error>(self, v: u128) -> Result<u128, E> {
+                Ok(v)
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<u128, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(U128Visitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,8 +997,7 @@ mod tests {
     fn test_verify_golden_vectors() {
         let vectors = load_default_golden_vectors();
 
-        // This should pass with our placeholder verification
-        assert!(vectors.verify_all().is_ok());
+        assert_eq!(vectors.verify_all(), Ok(()));
     }
 
     #[tokio::test]
@@ -404,11 +1016,91 @@ mod tests {
                 data: None,
                 chain_id: 1,
             },
-            expected_encoded: "0xf86c808504a817c800825208947742d35cc6634c0532925a3b844bc9e7595f0beb7820384808025a0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0a0fedcba9876543210fedcba9876543210fedcba9876543210fedcba98765432100".to_string(),
+            expected_encoded:
+                "0xe6808504a817c80082520894742d35cc6634c0532925a3b844bc9e7595f0beb78203e880018080"
+                    .to_string(),
             chain_type: ChainType::Ethereum,
             encoding_version: "legacy".to_string(),
         };
 
         assert!(verify_golden_vector(&vector).is_ok());
     }
+
+    #[test]
+    fn test_evm_mismatch_reports_first_differing_field() {
+        let mut vector = load_default_golden_vectors().vectors.remove(0);
+        if let GoldenVectorInput::EvmTransaction { gas_limit, .. } = &mut vector.input {
+            *gas_limit = 50_000;
+        }
+
+        let err = verify_golden_vector(&vector).unwrap_err();
+        assert!(err.contains("`gas_limit`"), "{}", err);
+    }
+
+    #[test]
+    fn test_evm_eip2930_encoding() {
+        let input = GoldenVectorInput::EvmTransaction {
+            from: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+            to: "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb7".to_string(),
+            value: "0x0".to_string(),
+            gas_limit: 21000,
+            gas_price: Some("0x1".to_string()),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            nonce: 0,
+            data: Some("0xdeadbeef".to_string()),
+            chain_id: 1,
+        };
+
+        let encoded = encode_golden_vector_input(&input, "eip2930").unwrap();
+        assert_eq!(encoded[0], 0x01);
+        // Empty access list is the last RLP field
+        assert_eq!(encoded.last(), Some(&0xc0));
+    }
+
+    #[test]
+    fn test_substrate_mismatch_reports_first_differing_field() {
+        let mut vector = load_default_golden_vectors().vectors.remove(2);
+        if let GoldenVectorInput::SubstrateExtrinsic { tip, .. } = &mut vector.input {
+            *tip = 1;
+        }
+
+        let err = verify_golden_vector(&vector).unwrap_err();
+        assert!(err.contains("`tip`"), "{}", err);
+    }
+
+    #[test]
+    fn test_substrate_input_requires_runtime_indices() {
+        let vector = load_default_golden_vectors().vectors.remove(2);
+        let input = serde_json::to_value(&vector.input).unwrap();
+        assert!(serde_json::from_value::<GoldenVectorInput>(input.clone()).is_ok());
+
+        for field in [
+            "pallet_index",
+            "call_index",
+            "spec_version",
+            "transaction_version",
+            "metadata_hash_mode",
+        ] {
+            let mut missing = input.clone();
+            missing.as_object_mut().unwrap().remove(field);
+            assert!(
+                serde_json::from_value::<GoldenVectorInput>(missing).is_err(),
+                "{} should be required",
+                field
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_era() {
+        assert_eq!(encode_era(None), vec![0x00]);
+        assert_eq!(
+            encode_era(Some(&SubstrateEra {
+                period: 64,
+                phase: 32
+            })),
+            vec![0x05, 0x02]
+        );
+    }
 }
@@ -0,0 +1,166 @@
+//! Chain head tracking for block subscriptions
+//!
+//! [`HeadTracker`] remembers the hashes of recently emitted blocks so that
+//! block watchers can tell whether a new head extends the chain they have
+//! already reported, skips over blocks (a gap that needs backfilling) or
+//! replaces blocks at heights that were already emitted (a reorg).
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Which chain head a block subscription follows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockSubscriptionMode {
+    /// Follow the best (latest) block; may observe reorgs
+    #[default]
+    Best,
+    /// Follow finalized blocks only
+    Finalized,
+}
+
+/// A block emitted by a block subscription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewHead {
+    /// Block number
+    pub number: u64,
+    /// Block hash
+    pub hash: String,
+    /// Parent block hash
+    pub parent_hash: String,
+    /// Block timestamp in seconds, if the chain reports one
+    pub timestamp: Option<u64>,
+    /// Whether this block replaces a previously emitted block at the same height
+    pub reorged: bool,
+}
+
+/// Tracks recently emitted blocks to detect gaps and reorgs
+#[derive(Debug, Clone)]
+pub struct HeadTracker {
+    canonical: BTreeMap<u64, String>,
+    depth: usize,
+}
+
+impl Default for HeadTracker {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl HeadTracker {
+    /// Create a tracker that remembers the last `depth` blocks
+    pub fn new(depth: usize) -> Self {
+        Self {
+            canonical: BTreeMap::new(),
+            depth: depth.max(1),
+        }
+    }
+
+    /// Get the highest emitted block number
+    pub fn last(&self) -> Option<u64> {
+        self.canonical.keys().next_back().copied()
+    }
+
+    /// Check whether a block has already been emitted
+    pub fn contains(&self, number: u64, hash: &str) -> bool {
+        self.canonical.get(&number).is_some_and(|h| h == hash)
+    }
+
+    /// Check whether a block attaches to the emitted chain
+    ///
+    /// Returns `false` when the parent is missing (a gap) or differs from the
+    /// emitted block at that height (a reorg); in both cases the caller should
+    /// fetch the parent and check again. Blocks whose parent is older than the
+    /// tracked window are assumed to connect.
+    pub fn connects(&self, number: u64, parent_hash: &str) -> bool {
+        let (Some(first), Some(last)) = (self.first(), self.last()) else {
+            return true;
+        };
+        let Some(parent_number) = number.checked_sub(1) else {
+            return true;
+        };
+
+        if parent_number < first {
+            return true;
+        }
+        if parent_number > last {
+            return false;
+        }
+
+        self.canonical
+            .get(&parent_number)
+            .is_some_and(|hash| hash == parent_hash)
+    }
+
+    /// Record an emitted block, returning `true` if it replaced a different block
+    ///
+    /// Blocks above the recorded height are forgotten, since they belong to
+    /// the abandoned fork.
+    pub fn record(&mut self, number: u64, hash: &str) -> bool {
+        let reorged = self.canonical.get(&number).is_some_and(|h| h != hash);
+
+        self.canonical.split_off(&(number + 1));
+        self.canonical.insert(number, hash.to_string());
+
+        while self.canonical.len() > self.depth {
+            self.canonical.pop_first();
+        }
+
+        reorged
+    }
+
+    fn first(&self) -> Option<u64> {
+        self.canonical.keys().next().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_tracker_connects_anything() {
+        let tracker = HeadTracker::default();
+        assert!(tracker.connects(100, "0xabc"));
+        assert_eq!(tracker.last(), None);
+    }
+
+    #[test]
+    fn test_detects_gap() {
+        let mut tracker = HeadTracker::default();
+        tracker.record(10, "0x0a");
+
+        assert!(tracker.connects(11, "0x0a"));
+        assert!(!tracker.connects(13, "0x0c"));
+    }
+
+    #[test]
+    fn test_detects_reorg() {
+        let mut tracker = HeadTracker::default();
+        assert!(!tracker.record(10, "0x0a"));
+        assert!(!tracker.record(11, "0x0b"));
+        assert!(!tracker.record(12, "0x0c"));
+
+        // A competing block 12 whose parent is the emitted block 11
+        assert!(tracker.connects(12, "0x0b"));
+        assert!(tracker.record(12, "0x0c2"));
+
+        // A competing block 11 on a fork from 10 drops the old block 12
+        assert!(!tracker.connects(12, "0x0b2"));
+        assert!(tracker.connects(11, "0x0a"));
+        assert!(tracker.record(11, "0x0b2"));
+        assert_eq!(tracker.last(), Some(11));
+        assert!(!tracker.contains(12, "0x0c2"));
+    }
+
+    #[test]
+    fn test_depth_limits_window() {
+        let mut tracker = HeadTracker::new(2);
+        tracker.record(1, "0x01");
+        tracker.record(2, "0x02");
+        tracker.record(3, "0x03");
+
+        assert!(!tracker.contains(1, "0x01"));
+        // Parents older than the window are assumed to connect
+        assert!(tracker.connects(2, "0xff"));
+    }
+}
@@ -48,9 +48,14 @@ pub mod metrics;
 /// Golden vectors for encoding verification
 pub mod golden_vectors;
 
+/// Chain head tracking for block subscriptions
+pub mod head_tracker;
+
 pub use golden_vectors::{
-    load_default_golden_vectors, verify_golden_vector, ChainType, GoldenVector, GoldenVectorSet,
+    encode_golden_vector_input, load_default_golden_vectors, verify_golden_vector, ChainType,
+    GoldenVector, GoldenVectorFormat, GoldenVectorSet,
 };
+pub use head_tracker::{BlockSubscriptionMode, HeadTracker, NewHead};
 pub use metrics::{MetricType, MetricsCollector};
 pub use pipeline::{TransactionPipeline, TransactionResult};
 
@@ -181,6 +181,8 @@ fn test_substrate_golden_vector_creation() {
         input: GoldenVectorInput::SubstrateExtrinsic {
             pallet: "Balances".to_string(),
             call: "transfer_keep_alive".to_string(),
+            pallet_index: 5,
+            call_index: 3,
             args: vec![
                 SubstrateValue::AccountId(
                     "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
@@ -193,10 +195,14 @@ fn test_substrate_golden_vector_creation() {
             }),
             nonce: 0,
             tip: 0,
+            spec_version: 1_003_000,
+            transaction_version: 26,
             genesis_hash: "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3"
                 .to_string(),
             block_hash: "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3"
                 .to_string(),
+            metadata_hash_mode: false,
+            metadata_hash: None,
         },
         expected_encoded: "450284d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
             .to_string(),
@@ -254,3 +260,121 @@ fn test_hex_encoding_validation() {
     assert!(hex::decode("xyz").is_err());
     assert!(hex::decode("deadbeeg").is_err()); // 'g' is not valid hex
 }
+
+fn substrate_transfer_input(tip: u128) -> GoldenVectorInput {
+    use apex_sdk_core::golden_vectors::{SubstrateEra, SubstrateValue};
+
+    GoldenVectorInput::SubstrateExtrinsic {
+        pallet: "Balances".to_string(),
+        call: "transfer_keep_alive".to_string(),
+        pallet_index: 10,
+        call_index: 3,
+        args: vec![
+            SubstrateValue::AccountId(
+                "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string(),
+            ),
+            // Larger than i64::MAX, stored as a string in TOML
+            SubstrateValue::Balance(10_000_000_000_000_000_000_000),
+        ],
+        era: Some(SubstrateEra {
+            period: 64,
+            phase: 7,
+        }),
+        nonce: 3,
+        tip,
+        spec_version: 1_016_000,
+        transaction_version: 27,
+        genesis_hash: "0xe143f23803ac50e8f6f8e62695d1ce9e4e1d68aa36c1cd2cfd15340213f3423e"
+            .to_string(),
+        block_hash: "0xe143f23803ac50e8f6f8e62695d1ce9e4e1d68aa36c1cd2cfd15340213f3423e"
+            .to_string(),
+        metadata_hash_mode: true,
+        metadata_hash: Some(
+            "0x1111111111111111111111111111111111111111111111111111111111111111".to_string(),
+        ),
+    }
+}
+
+fn evm_eip1559_input() -> GoldenVectorInput {
+    GoldenVectorInput::EvmTransaction {
+        from: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        to: "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb7".to_string(),
+        value: "0xde0b6b3a7640000".to_string(),
+        gas_limit: 21000,
+        gas_price: None,
+        max_fee_per_gas: Some("0x77359400".to_string()),
+        max_priority_fee_per_gas: Some("0x3b9aca00".to_string()),
+        nonce: 7,
+        data: None,
+        chain_id: 1284,
+    }
+}
+
+#[test]
+fn test_record_and_load_golden_vector_corpus() {
+    use apex_sdk_core::golden_vectors::GoldenVectorFormat;
+
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut moonbeam = GoldenVectorSet::new();
+    moonbeam
+        .record("moonbeam_transfer", evm_eip1559_input(), "eip1559")
+        .unwrap();
+    moonbeam
+        .write_to_file(dir.path().join("a_moonbeam.json"))
+        .unwrap();
+
+    let mut westend = GoldenVectorSet::new();
+    westend
+        .record("westend_transfer", substrate_transfer_input(1), "scale_v1")
+        .unwrap();
+    westend
+        .write_to_file(dir.path().join("b_westend.toml"))
+        .unwrap();
+
+    // Unrelated files are skipped
+    std::fs::write(dir.path().join("README.md"), "vectors").unwrap();
+
+    let loaded = GoldenVectorSet::load_from_dir(dir.path()).unwrap();
+    assert_eq!(loaded.vectors.len(), 2);
+    assert_eq!(loaded.vectors[0].name, "moonbeam_transfer");
+    assert_eq!(loaded.vectors[0].chain_type, ChainType::Ethereum);
+    assert_eq!(loaded.vectors[1].name, "westend_transfer");
+    assert_eq!(loaded.vectors[1].chain_type, ChainType::Substrate);
+    assert_eq!(loaded.vectors[1], westend.vectors[0]);
+    assert!(loaded.verify_all().is_ok());
+
+    assert_eq!(
+        GoldenVectorFormat::from_path(&dir.path().join("b_westend.toml")),
+        Some(GoldenVectorFormat::Toml)
+    );
+}
+
+#[test]
+fn test_loaded_corpus_detects_regressions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("westend.json");
+
+    let mut set = GoldenVectorSet::new();
+    set.record("westend_transfer", substrate_transfer_input(0), "scale_v1")
+        .unwrap();
+    set.vectors[0].input = substrate_transfer_input(5);
+    set.write_to_file(&path).unwrap();
+
+    let err = GoldenVectorSet::load_from_file(&path)
+        .unwrap()
+        .verify_all()
+        .unwrap_err();
+    assert!(err.contains("westend_transfer"), "{}", err);
+    assert!(err.contains("`tip`"), "{}", err);
+}
+
+#[test]
+fn test_load_rejects_unsupported_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("vectors.yaml");
+    std::fs::write(&path, "version: 1").unwrap();
+
+    assert!(GoldenVectorSet::load_from_file(&path).is_err());
+    assert!(GoldenVectorSet::load_from_dir(dir.path().join("missing")).is_err());
+}
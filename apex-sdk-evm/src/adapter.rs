@@ -28,6 +28,7 @@ pub struct EvmAdapter {
     >,
     chain_name: String,
     rpc_url: String,
+    ws_url: Option<String>,
 }
 
 impl EvmAdapter {
@@ -40,6 +41,7 @@ impl EvmAdapter {
             pipeline: None,
             chain_name: chain_name.to_string(),
             rpc_url: rpc_url.to_string(),
+            ws_url: None,
         })
     }
 
@@ -48,6 +50,36 @@ impl EvmAdapter {
         Self::new(rpc_url, "EVM").await
     }
 
+    /// Connect to an EVM chain, batching concurrent JSON-RPC requests
+    ///
+    /// All components built from this adapter, including the receipt watcher
+    /// and nonce manager, share the batching transport.
+    pub async fn connect_with_batching(
+        rpc_url: &str,
+        config: crate::rpc_batch::BatchConfig,
+    ) -> Result<Self, Error> {
+        let provider = EvmProvider::new_with_batching(rpc_url, config).await?;
+
+        Ok(Self {
+            provider,
+            pipeline: None,
+            chain_name: "EVM".to_string(),
+            rpc_url: rpc_url.to_string(),
+            ws_url: None,
+        })
+    }
+
+    /// Configure a WebSocket endpoint used for block and log subscriptions
+    ///
+    /// [`block_watcher`](Self::block_watcher) and
+    /// [`event_watcher`](Self::event_watcher) subscribe over it. Finalized
+    /// blocks, bounded log queries and failed subscriptions fall back to HTTP
+    /// polling.
+    pub fn with_ws_endpoint(mut self, ws_url: impl Into<String>) -> Self {
+        self.ws_url = Some(ws_url.into());
+        self
+    }
+
     /// Configure the adapter with a signer and create the transaction pipeline
     pub fn with_signer(mut self, signer: EvmSigner) -> Self {
         let provider_clone = self.provider.clone();
@@ -117,6 +149,35 @@ impl EvmAdapter {
         &self.provider
     }
 
+    /// Get a block watcher for this chain
+    ///
+    /// Uses `newHeads` over the configured WebSocket endpoint when available,
+    /// otherwise polls over HTTP.
+    pub fn block_watcher(&self) -> crate::block_watcher::EvmBlockWatcher {
+        let watcher = crate::block_watcher::EvmBlockWatcher::new(self.provider.provider.clone());
+        match &self.ws_url {
+            Some(ws_url) => watcher.with_ws_endpoint(ws_url.clone()),
+            None => watcher,
+        }
+    }
+
+    /// Get an event watcher for this chain
+    ///
+    /// Subscribes to `logs` over the configured WebSocket endpoint when
+    /// available, otherwise polls `eth_getLogs` over HTTP.
+    pub fn event_watcher(&self) -> crate::events::EvmEventWatcher {
+        let watcher = crate::events::EvmEventWatcher::new(self.provider.provider.clone());
+        match &self.ws_url {
+            Some(ws_url) => watcher.with_ws_endpoint(ws_url.clone()),
+            None => watcher,
+        }
+    }
+
+    /// Get a receipt watcher for this chain
+    pub fn receipt_watcher(&self) -> EvmReceiptWatcher {
+        EvmReceiptWatcher::new(self.provider.provider.clone())
+    }
+
     /// Get the chain ID
     pub fn chain_id(&self) -> u64 {
         self.provider.chain_id()
@@ -277,12 +338,7 @@ impl EvmAdapter {
 
     /// Get transaction executor for direct transaction operations
     pub fn get_transaction_executor(&self) -> crate::transaction::TransactionExecutor {
-        // Create a basic ProviderType from the provider
-        // Note: This is a simplified approach for test compatibility
-        use alloy::providers::ProviderBuilder;
-        let provider =
-            ProviderBuilder::new().connect_http("http://localhost:8545".parse().unwrap());
-        let provider_type = crate::ProviderType::new(provider);
+        let provider_type = crate::ProviderType::new(self.provider.provider.clone());
         crate::transaction::TransactionExecutor::new(provider_type)
     }
 }
@@ -300,10 +356,7 @@ impl ChainAdapter for EvmAdapter {
             return Err(format!("Invalid transaction hash format: {}", tx_hash));
         }
 
-        // Create receipt watcher to get transaction status
-        let receipt_watcher = EvmReceiptWatcher::new(self.provider.provider.clone());
-
-        match receipt_watcher.get_receipt_status(tx_hash).await {
+        match self.receipt_watcher().get_receipt_status(tx_hash).await {
             Ok(Some(status)) => Ok(status),
             Ok(None) => {
                 // Transaction not found on chain
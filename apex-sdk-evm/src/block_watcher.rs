@@ -0,0 +1,223 @@
+//! EVM block watcher
+//!
+//! Follows new blocks via `eth_subscribe("newHeads")` when a WebSocket
+//! endpoint is configured, falling back to HTTP polling of the `latest` or
+//! `finalized` block tag. Skipped blocks are backfilled by parent hash and
+//! reorgs are reported through [`NewHead::reorged`].
+
+use crate::{AlloyHttpProvider, Error};
+use alloy::eips::BlockNumberOrTag;
+use alloy::primitives::B256;
+use alloy::providers::{Provider, ProviderBuilder, WsConnect};
+use alloy::rpc::types::Header;
+use apex_sdk_core::{BlockSubscriptionMode, HeadTracker, NewHead};
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+/// Maximum number of missing blocks fetched when the chain skips ahead
+const MAX_BACKFILL_DEPTH: usize = 256;
+
+/// EVM block watcher
+#[derive(Debug, Clone)]
+pub struct EvmBlockWatcher {
+    provider: AlloyHttpProvider,
+    ws_url: Option<String>,
+    polling_interval: Duration,
+    tracker_depth: usize,
+}
+
+impl EvmBlockWatcher {
+    /// Create a new EVM block watcher using HTTP polling
+    pub fn new(provider: AlloyHttpProvider) -> Self {
+        Self {
+            provider,
+            ws_url: None,
+            polling_interval: Duration::from_millis(2000),
+            tracker_depth: 64,
+        }
+    }
+
+    /// Use a WebSocket endpoint for `newHeads` subscriptions
+    pub fn with_ws_endpoint(mut self, ws_url: impl Into<String>) -> Self {
+        self.ws_url = Some(ws_url.into());
+        self
+    }
+
+    /// Set the polling interval used over HTTP
+    pub fn with_polling_interval(mut self, interval: Duration) -> Self {
+        self.polling_interval = interval;
+        self
+    }
+
+    /// Set how many recent blocks are remembered for reorg detection
+    pub fn with_tracker_depth(mut self, depth: usize) -> Self {
+        self.tracker_depth = depth;
+        self
+    }
+
+    /// Watch new blocks
+    ///
+    /// Passes every new block to `on_block` in order. Best blocks are read
+    /// from the WebSocket subscription if one is configured, switching to HTTP
+    /// polling if it cannot be established or drops. Finalized blocks are
+    /// always polled via the `finalized` tag. Returns once `on_block` returns
+    /// `false`.
+    pub async fn watch<F>(&self, mode: BlockSubscriptionMode, mut on_block: F) -> Result<(), Error>
+    where
+        F: FnMut(NewHead) -> bool,
+    {
+        let mut tracker = HeadTracker::new(self.tracker_depth);
+
+        if let (BlockSubscriptionMode::Best, Some(ws_url)) = (mode, &self.ws_url) {
+            match self.watch_ws(ws_url, &mut tracker, &mut on_block).await {
+                Ok(()) => return Ok(()),
+                Err(e) => warn!(
+                    "WebSocket block subscription failed, falling back to HTTP polling: {}",
+                    e
+                ),
+            }
+        }
+
+        self.watch_polling(mode, &mut tracker, &mut on_block).await
+    }
+
+    /// Follow `newHeads` over WebSocket
+    async fn watch_ws<F>(
+        &self,
+        ws_url: &str,
+        tracker: &mut HeadTracker,
+        on_block: &mut F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(NewHead) -> bool,
+    {
+        let ws_provider = ProviderBuilder::new()
+            .connect_ws(WsConnect::new(ws_url))
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to connect to {}: {}", ws_url, e)))?;
+
+        let mut subscription = ws_provider
+            .subscribe_blocks()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to subscribe to newHeads: {}", e)))?;
+
+        debug!("Subscribed to newHeads at {}", ws_url);
+
+        loop {
+            let header = subscription
+                .recv()
+                .await
+                .map_err(|e| Error::Connection(format!("newHeads subscription error: {}", e)))?;
+
+            if !self.handle_head(header, tracker, on_block).await? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Poll the `latest` or `finalized` block over HTTP
+    async fn watch_polling<F>(
+        &self,
+        mode: BlockSubscriptionMode,
+        tracker: &mut HeadTracker,
+        on_block: &mut F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(NewHead) -> bool,
+    {
+        let tag = match mode {
+            BlockSubscriptionMode::Best => BlockNumberOrTag::Latest,
+            BlockSubscriptionMode::Finalized => BlockNumberOrTag::Finalized,
+        };
+
+        loop {
+            let block = self
+                .provider
+                .get_block_by_number(tag)
+                .await
+                .map_err(|e| Error::Connection(format!("Failed to get {} block: {}", tag, e)))?
+                .ok_or_else(|| Error::Connection(format!("Node returned no {} block", tag)))?;
+
+            if !self.handle_head(block.header, tracker, on_block).await? {
+                return Ok(());
+            }
+
+            sleep(self.polling_interval).await;
+        }
+    }
+
+    /// Emit a new head and any unemitted ancestors, returning `false` to stop
+    async fn handle_head<F>(
+        &self,
+        head: Header,
+        tracker: &mut HeadTracker,
+        on_block: &mut F,
+    ) -> Result<bool, Error>
+    where
+        F: FnMut(NewHead) -> bool,
+    {
+        if tracker.contains(head.number, &format_hash(head.hash)) {
+            return Ok(true);
+        }
+
+        let mut pending = vec![head];
+        while let Some(oldest) = pending.last() {
+            if tracker.connects(oldest.number, &format_hash(oldest.parent_hash)) {
+                break;
+            }
+            if pending.len() >= MAX_BACKFILL_DEPTH {
+                warn!(
+                    "Stopped backfilling after {} blocks; emitting from block {}",
+                    MAX_BACKFILL_DEPTH, oldest.number
+                );
+                break;
+            }
+
+            let parent = self.get_header(oldest.parent_hash).await?;
+            pending.push(parent);
+        }
+
+        if pending.len() > 1 {
+            debug!("Backfilled {} blocks", pending.len() - 1);
+        }
+
+        for header in pending.iter().rev() {
+            let hash = format_hash(header.hash);
+            let reorged = tracker.record(header.number, &hash);
+            if reorged {
+                warn!(
+                    "Reorg detected at block {}: new hash {}",
+                    header.number, hash
+                );
+            }
+
+            let head = NewHead {
+                number: header.number,
+                hash,
+                parent_hash: format_hash(header.parent_hash),
+                timestamp: Some(header.timestamp),
+                reorged,
+            };
+            if !on_block(head) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Fetch a block header by hash
+    async fn get_header(&self, hash: B256) -> Result<Header, Error> {
+        self.provider
+            .get_block_by_hash(hash)
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get block {}: {}", hash, e)))?
+            .map(|block| block.header)
+            .ok_or_else(|| Error::Connection(format!("Block {} not found", hash)))
+    }
+}
+
+fn format_hash(hash: B256) -> String {
+    format!("0x{:x}", hash)
+}
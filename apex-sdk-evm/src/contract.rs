@@ -2,14 +2,23 @@
 //!
 //! This module provides tools for interacting with smart contracts, including:
 //! - Generic contract calls
-//! - ERC-20 token transfers and balance checks
-//! - Contract deployment (future)
+//! - ABI-driven calls, transactions and log decoding from a JSON ABI
+//! - ERC-20 token transfers, allowances and EIP-2612 permits
+//! - ERC-721 and ERC-1155 tokens, with ERC-165 interface detection
+//! - Contract deployment with CREATE and CREATE2 address prediction
 
+use crate::transaction::TransactionExecutor;
+use crate::wallet::Wallet;
 use crate::{Error, EvmAdapter};
-use alloy::primitives::{Address as EthAddress, Bytes, U256};
-use alloy::rpc::types::TransactionRequest;
+use alloy::dyn_abi::{DynSolType, DynSolValue, ErrorExt, EventExt, FunctionExt, JsonAbiExt};
+use alloy::json_abi::{Event, Function, JsonAbi};
+use alloy::primitives::{address, Address as EthAddress, Bytes, B256, U256};
+use alloy::rpc::json_rpc::ErrorPayload;
+use alloy::rpc::types::{Log, TransactionReceipt, TransactionRequest};
 use alloy::sol;
-use alloy::sol_types::SolCall;
+use alloy::sol_types::{Panic, Revert, SolCall, SolError, SolEvent};
+use std::fmt;
+use std::time::Duration;
 
 // Define ERC-20 interface using alloy's sol! macro
 sol! {
@@ -22,19 +31,176 @@ sol! {
         function name() external view returns (string);
         function approve(address spender, uint256 amount) external returns (bool);
         function allowance(address owner, address spender) external view returns (uint256);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+    }
+}
+
+// EIP-2612 permit extension
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC20Permit {
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        function nonces(address owner) external view returns (uint256);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+}
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC721 {
+        event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+        event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+        event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+
+        function balanceOf(address owner) external view returns (uint256);
+        function ownerOf(uint256 tokenId) external view returns (address);
+        function safeTransferFrom(address from, address to, uint256 tokenId, bytes data) external;
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function transferFrom(address from, address to, uint256 tokenId) external;
+        function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+        function getApproved(uint256 tokenId) external view returns (address);
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+        function name() external view returns (string);
+        function symbol() external view returns (string);
+        function tokenURI(uint256 tokenId) external view returns (string);
+    }
+}
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface IERC1155 {
+        event TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value);
+        event TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values);
+        event ApprovalForAll(address indexed account, address indexed operator, bool approved);
+
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
+        function setApprovalForAll(address operator, bool approved) external;
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
+        function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] values, bytes data) external;
+        function uri(uint256 id) external view returns (string);
+    }
+}
+
+/// ERC-165 interface ID of ERC-165 itself
+pub const INTERFACE_ID_ERC165: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+/// ERC-165 interface ID of ERC-721
+pub const INTERFACE_ID_ERC721: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+/// ERC-165 interface ID of the ERC-721 metadata extension
+pub const INTERFACE_ID_ERC721_METADATA: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+/// ERC-165 interface ID of ERC-1155
+pub const INTERFACE_ID_ERC1155: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+/// ERC-165 interface ID of the ERC-1155 metadata URI extension
+pub const INTERFACE_ID_ERC1155_METADATA_URI: [u8; 4] = [0x0e, 0x89, 0x34, 0x1c];
+
+/// Decoded reason for a reverted call
+#[derive(Debug, Clone, PartialEq)]
+pub enum RevertReason {
+    /// `Error(string)` from `revert("...")` or `require(..., "...")`
+    Error(String),
+    /// `Panic(uint256)` from a failed assertion, overflow, etc.
+    Panic(U256),
+    /// A custom error declared in the contract ABI
+    Custom {
+        /// Error name
+        name: String,
+        /// Decoded error arguments
+        args: Vec<DynSolValue>,
+    },
+    /// Revert data that could not be decoded
+    Unknown(Bytes),
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Error(message) => write!(f, "{}", message),
+            RevertReason::Panic(code) => write!(f, "panic code 0x{:x}", code),
+            RevertReason::Custom { name, args } => {
+                let args: Vec<String> = args.iter().map(format_value).collect();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+            RevertReason::Unknown(data) if data.is_empty() => write!(f, "no revert data"),
+            RevertReason::Unknown(data) => write!(f, "unknown revert data {}", data),
+        }
+    }
+}
+
+/// An event log decoded with the contract ABI
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog {
+    /// Event name
+    pub name: String,
+    /// Address of the contract that emitted the log
+    pub address: EthAddress,
+    /// Event parameters in declaration order, indexed and non-indexed
+    pub params: Vec<(String, DynSolValue)>,
+}
+
+impl DecodedLog {
+    /// Get a parameter by name
+    pub fn param(&self, name: &str) -> Option<&DynSolValue> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
     }
 }
 
 /// A wrapper around a smart contract address
+///
+/// With a JSON ABI attached ([`with_abi`](Self::with_abi)) functions can be
+/// called by name with [`DynSolValue`] arguments, reverts are decoded
+/// (including custom errors) and receipt logs are decoded by event signature.
 pub struct Contract {
     address: EthAddress,
     adapter: EvmAdapter,
+    abi: Option<JsonAbi>,
 }
 
 impl Contract {
     /// Create a new contract instance
     pub fn new(address: EthAddress, adapter: EvmAdapter) -> Self {
-        Self { address, adapter }
+        Self {
+            address,
+            adapter,
+            abi: None,
+        }
+    }
+
+    /// Create a contract instance from a JSON ABI
+    ///
+    /// Accepts either a bare ABI array or a compiler artifact with an `abi` field.
+    pub fn from_abi_json(
+        address: EthAddress,
+        adapter: EvmAdapter,
+        json: &str,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(address, adapter).with_abi(parse_abi(json)?))
+    }
+
+    /// Attach a contract ABI
+    pub fn with_abi(mut self, abi: JsonAbi) -> Self {
+        self.abi = Some(abi);
+        self
     }
 
     /// Get the contract address
@@ -42,6 +208,11 @@ impl Contract {
         self.address
     }
 
+    /// Get the contract ABI, if any
+    pub fn abi(&self) -> Option<&JsonAbi> {
+        self.abi.as_ref()
+    }
+
     /// Call a view function on the contract
     pub async fn call_view(&self, data: Vec<u8>) -> Result<Vec<u8>, Error> {
         use alloy::providers::Provider;
@@ -56,10 +227,611 @@ impl Contract {
             .provider
             .call(tx)
             .await
-            .map_err(|e| Error::Contract(format!("Contract call failed: {}", e)))?;
+            .map_err(|e| {
+                match e
+                    .as_error_resp()
+                    .and_then(|payload| payload.as_revert_data())
+                {
+                    Some(data) => Error::Contract(format!(
+                        "Contract call reverted: {}",
+                        self.decode_revert(&data)
+                    )),
+                    None => Error::Contract(format!("Contract call failed: {}", e)),
+                }
+            })?;
 
         Ok(result.to_vec())
     }
+
+    /// Check whether the contract implements an interface, per ERC-165
+    ///
+    /// Contracts that do not implement ERC-165 itself (including accounts
+    /// without code) report `false`.
+    pub async fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
+        if !self.supports_interface_raw(INTERFACE_ID_ERC165).await?
+            || self.supports_interface_raw([0xff; 4]).await?
+        {
+            return Ok(false);
+        }
+        if interface_id == INTERFACE_ID_ERC165 {
+            return Ok(true);
+        }
+
+        self.supports_interface_raw(interface_id).await
+    }
+
+    /// Single `supportsInterface` call, treating reverts and bad output as `false`
+    ///
+    /// Transport and other node errors are returned.
+    async fn supports_interface_raw(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
+        use alloy::providers::Provider;
+
+        let call = IERC165::supportsInterfaceCall {
+            interfaceId: interface_id.into(),
+        };
+        let tx = TransactionRequest::default()
+            .to(self.address)
+            .input(Bytes::from(call.abi_encode()).into());
+
+        match self.adapter.provider().provider.call(tx).await {
+            // ERC-165 requires exactly one 32-byte word
+            Ok(result) if result.len() == 32 => Ok(
+                IERC165::supportsInterfaceCall::abi_decode_returns_validate(&result)
+                    .unwrap_or(false),
+            ),
+            Ok(_) => Ok(false),
+            Err(e) if e.as_error_resp().is_some_and(is_revert) => Ok(false),
+            Err(e) => Err(Error::Connection(format!(
+                "supportsInterface call failed: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Look up a function by name or full signature
+    ///
+    /// Overloaded functions must be given by signature, e.g.
+    /// `"safeTransferFrom(address,address,uint256)"`, unless only one overload
+    /// takes `arg_count` arguments.
+    pub fn function(&self, name: &str, arg_count: usize) -> Result<&Function, Error> {
+        let abi = self.require_abi()?;
+
+        if let Some(open) = name.find('(') {
+            return abi
+                .functions
+                .get(&name[..open])
+                .and_then(|overloads| overloads.iter().find(|f| f.signature() == name))
+                .ok_or_else(|| Error::Contract(format!("Function {} not found in ABI", name)));
+        }
+
+        let overloads = abi
+            .functions
+            .get(name)
+            .ok_or_else(|| Error::Contract(format!("Function {} not found in ABI", name)))?;
+        if let [function] = overloads.as_slice() {
+            return Ok(function);
+        }
+
+        let mut candidates = overloads.iter().filter(|f| f.inputs.len() == arg_count);
+        match (candidates.next(), candidates.next()) {
+            (Some(function), None) => Ok(function),
+            (None, _) => Err(Error::Contract(format!(
+                "No overload of {} takes {} arguments",
+                name, arg_count
+            ))),
+            (Some(_), Some(_)) => Err(Error::Contract(format!(
+                "Function {} is overloaded; use its full signature",
+                name
+            ))),
+        }
+    }
+
+    /// Encode calldata for a function
+    pub fn encode_call(&self, function: &str, args: &[DynSolValue]) -> Result<Vec<u8>, Error> {
+        self.function(function, args.len())?
+            .abi_encode_input(args)
+            .map_err(|e| Error::Contract(format!("Failed to encode {} arguments: {}", function, e)))
+    }
+
+    /// Encode calldata for a function from string arguments
+    ///
+    /// Each argument is parsed according to its ABI type, e.g. `"0x..."` for
+    /// addresses and bytes, `"1.5 ether"` or `"1000"` for integers and
+    /// `"[1,2]"` for arrays.
+    pub fn encode_call_str(&self, function: &str, args: &[&str]) -> Result<Vec<u8>, Error> {
+        let abi_function = self.function(function, args.len())?;
+        if abi_function.inputs.len() != args.len() {
+            return Err(Error::Contract(format!(
+                "{} takes {} arguments, got {}",
+                function,
+                abi_function.inputs.len(),
+                args.len()
+            )));
+        }
+
+        let values = abi_function
+            .inputs
+            .iter()
+            .zip(args)
+            .map(|(param, arg)| {
+                let ty: DynSolType = param.ty.parse().map_err(|e| {
+                    Error::Contract(format!("Unsupported ABI type {}: {}", param.ty, e))
+                })?;
+                ty.coerce_str(arg).map_err(|e| {
+                    Error::Contract(format!("Invalid value for {}: {}", param.name, e))
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.encode_call(&abi_function.signature(), &values)
+    }
+
+    /// Decode a function's return data
+    pub fn decode_output(&self, function: &str, data: &[u8]) -> Result<Vec<DynSolValue>, Error> {
+        let abi_function = match function.find('(') {
+            Some(_) => self.function(function, 0)?,
+            None => self.function_by_name(function)?,
+        };
+        abi_function
+            .abi_decode_output(data)
+            .map_err(|e| Error::Contract(format!("Failed to decode {} output: {}", function, e)))
+    }
+
+    /// Decode revert data into a reason
+    ///
+    /// Recognizes `Error(string)`, `Panic(uint256)` and the custom errors in
+    /// the attached ABI.
+    pub fn decode_revert(&self, data: &[u8]) -> RevertReason {
+        if let Ok(revert) = Revert::abi_decode(data) {
+            return RevertReason::Error(revert.reason);
+        }
+        if let Ok(panic) = Panic::abi_decode(data) {
+            return RevertReason::Panic(panic.code);
+        }
+
+        let custom = self.abi.as_ref().and_then(|abi| {
+            let selector = data.get(..4)?;
+            abi.errors().find_map(|error| {
+                if error.selector().as_slice() != selector {
+                    return None;
+                }
+                let decoded = error.decode_error(data).ok()?;
+                Some(RevertReason::Custom {
+                    name: error.name.clone(),
+                    args: decoded.body,
+                })
+            })
+        });
+
+        custom.unwrap_or_else(|| RevertReason::Unknown(Bytes::copy_from_slice(data)))
+    }
+
+    /// Call a function with `eth_call` and decode its return values
+    ///
+    /// Reverts are reported as [`Error::Contract`] with the decoded reason.
+    pub async fn call(
+        &self,
+        function: &str,
+        args: &[DynSolValue],
+    ) -> Result<Vec<DynSolValue>, Error> {
+        let abi_function = self.function(function, args.len())?;
+        let data = self.encode_call(&abi_function.signature(), args)?;
+        let output = self.call_view(data).await?;
+
+        abi_function
+            .abi_decode_output(&output)
+            .map_err(|e| Error::Contract(format!("Failed to decode {} output: {}", function, e)))
+    }
+
+    /// Send a state-changing function call
+    ///
+    /// The transaction is built, signed and submitted by `executor`. Returns
+    /// the transaction hash; decode the receipt's logs with
+    /// [`decode_receipt_logs`](Self::decode_receipt_logs).
+    pub async fn send(
+        &self,
+        executor: &TransactionExecutor,
+        wallet: &Wallet,
+        function: &str,
+        args: &[DynSolValue],
+        value: U256,
+    ) -> Result<B256, Error> {
+        let data = self.encode_call(function, args)?;
+        executor
+            .send_transaction(wallet, self.address, value, Some(data))
+            .await
+    }
+
+    /// Decode a log emitted by this contract
+    ///
+    /// Returns `None` for logs from other addresses or events not in the ABI.
+    pub fn decode_log(&self, log: &Log) -> Option<DecodedLog> {
+        let log = &log.inner;
+        if log.address != self.address {
+            return None;
+        }
+        let abi = self.abi.as_ref()?;
+        let topics = log.data.topics();
+
+        // Match on the signature topic, then try anonymous events in turn
+        let by_selector = topics.first().and_then(|selector| {
+            abi.events()
+                .filter(|event| !event.anonymous && event.selector() == *selector)
+                .find_map(|event| decode_event(event, topics, &log.data.data))
+        });
+        let decoded = by_selector.or_else(|| {
+            abi.events()
+                .filter(|event| event.anonymous)
+                .find_map(|event| decode_event(event, topics, &log.data.data))
+        });
+
+        decoded.map(|(name, params)| DecodedLog {
+            name,
+            address: log.address,
+            params,
+        })
+    }
+
+    /// Decode this contract's logs from a transaction receipt
+    pub fn decode_receipt_logs(&self, receipt: &TransactionReceipt) -> Vec<DecodedLog> {
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter_map(|log| self.decode_log(log))
+            .collect()
+    }
+
+    /// Look up a function by name only, rejecting overloaded names
+    fn function_by_name(&self, name: &str) -> Result<&Function, Error> {
+        match self.require_abi()?.function(name).map(Vec::as_slice) {
+            Some([function]) => Ok(function),
+            Some([_, ..]) => Err(Error::Contract(format!(
+                "Function {} is overloaded; use its full signature",
+                name
+            ))),
+            _ => Err(Error::Contract(format!(
+                "Function {} not found in ABI",
+                name
+            ))),
+        }
+    }
+
+    fn require_abi(&self) -> Result<&JsonAbi, Error> {
+        self.abi
+            .as_ref()
+            .ok_or_else(|| Error::Contract("Contract has no ABI".to_string()))
+    }
+}
+
+/// Parse a JSON ABI, either a bare array or an artifact with an `abi` field
+pub fn parse_abi(json: &str) -> Result<JsonAbi, Error> {
+    let value: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| Error::Contract(format!("Failed to parse ABI JSON: {}", e)))?;
+    let abi = match value {
+        serde_json::Value::Object(mut artifact) if artifact.contains_key("abi") => {
+            artifact.remove("abi").unwrap_or_default()
+        }
+        value => value,
+    };
+
+    serde_json::from_value(abi).map_err(|e| Error::Contract(format!("Invalid ABI: {}", e)))
+}
+
+/// Whether a JSON-RPC error reports a reverted call
+///
+/// Nodes return revert data when there is any; an empty revert, such as a
+/// `revert()` or a call to a contract without a fallback function, only says so
+/// in the message.
+fn is_revert(payload: &ErrorPayload) -> bool {
+    payload.as_revert_data().is_some() || payload.message.to_lowercase().contains("revert")
+}
+
+/// Decode a log with an event, naming its parameters in declaration order
+pub(crate) fn decode_event(
+    event: &Event,
+    topics: &[B256],
+    data: &[u8],
+) -> Option<(String, Vec<(String, DynSolValue)>)> {
+    let decoded = event.decode_log_parts(topics.iter().copied(), data).ok()?;
+    let mut indexed = decoded.indexed.into_iter();
+    let mut body = decoded.body.into_iter();
+
+    let params = event
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = if input.indexed {
+                indexed.next()
+            } else {
+                body.next()
+            };
+            value.map(|value| (input.name.clone(), value))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((event.name.clone(), params))
+}
+
+/// Format a dynamic value for error messages
+fn format_value(value: &DynSolValue) -> String {
+    match value {
+        DynSolValue::Address(address) => address.to_string(),
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::Int(i, _) => i.to_string(),
+        DynSolValue::Uint(u, _) => u.to_string(),
+        DynSolValue::String(s) => format!("{:?}", s),
+        DynSolValue::FixedBytes(word, size) => format!("0x{}", hex::encode(&word[..*size])),
+        DynSolValue::Bytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => {
+            let values: Vec<String> = values.iter().map(format_value).collect();
+            format!("[{}]", values.join(", "))
+        }
+        other => format!("{:?}", other),
+    }
+}
+
+/// Canonical deterministic deployment proxy (`CREATE2` factory)
+///
+/// Deployed at the same address on most EVM chains; calldata is the 32-byte
+/// salt followed by the init code.
+pub const DETERMINISTIC_DEPLOYER: EthAddress = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+
+/// Default time to wait for a deployment receipt
+pub const DEFAULT_DEPLOY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Address of a contract created with `CREATE` by `deployer` at `nonce`
+pub fn create_address(deployer: EthAddress, nonce: u64) -> EthAddress {
+    deployer.create(nonce)
+}
+
+/// Address of a contract created with `CREATE2` by `deployer`
+pub fn create2_address(deployer: EthAddress, salt: B256, init_code: &[u8]) -> EthAddress {
+    deployer.create2_from_code(salt, init_code)
+}
+
+/// Check whether an address has contract code
+pub async fn code_exists(adapter: &EvmAdapter, address: EthAddress) -> Result<bool, Error> {
+    use alloy::providers::Provider;
+
+    let code = adapter
+        .provider()
+        .provider
+        .get_code_at(address)
+        .await
+        .map_err(|e| Error::Connection(format!("Failed to get code: {}", e)))?;
+
+    Ok(!code.is_empty())
+}
+
+/// A deployed contract
+#[derive(Debug, Clone)]
+pub struct Deployment {
+    /// Address of the deployed contract
+    pub address: EthAddress,
+    /// Deployment transaction hash
+    pub tx_hash: B256,
+    /// Deployment transaction receipt
+    pub receipt: TransactionReceipt,
+}
+
+/// Contract deployer with CREATE and CREATE2 address prediction
+///
+/// Without a salt the contract is created directly by the wallet (`CREATE`).
+/// With [`with_salt`](Self::with_salt) it is created through
+/// [`DETERMINISTIC_DEPLOYER`] (`CREATE2`), giving the same address on every
+/// chain for the same bytecode, constructor arguments and salt.
+#[derive(Debug, Clone)]
+pub struct ContractDeployer {
+    bytecode: Vec<u8>,
+    abi: Option<JsonAbi>,
+    constructor_args: Vec<DynSolValue>,
+    value: U256,
+    salt: Option<B256>,
+    factory: EthAddress,
+    timeout: Duration,
+}
+
+impl ContractDeployer {
+    /// Create a deployer for creation bytecode
+    pub fn new(bytecode: Vec<u8>) -> Self {
+        Self {
+            bytecode,
+            abi: None,
+            constructor_args: Vec::new(),
+            value: U256::ZERO,
+            salt: None,
+            factory: DETERMINISTIC_DEPLOYER,
+            timeout: DEFAULT_DEPLOY_TIMEOUT,
+        }
+    }
+
+    /// Create a deployer from a compiler artifact with `abi` and `bytecode`
+    ///
+    /// `bytecode` may be a hex string or an object with an `object` field, as
+    /// emitted by Foundry and Hardhat.
+    pub fn from_artifact(json: &str) -> Result<Self, Error> {
+        let artifact: serde_json::Value = serde_json::from_str(json)
+            .map_err(|e| Error::Contract(format!("Failed to parse artifact JSON: {}", e)))?;
+
+        let bytecode = artifact
+            .get("bytecode")
+            .and_then(|bytecode| bytecode.get("object").or(Some(bytecode)))
+            .and_then(|bytecode| bytecode.as_str())
+            .ok_or_else(|| Error::Contract("Artifact has no bytecode".to_string()))?;
+        let bytecode = hex::decode(bytecode.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::Contract(format!("Invalid bytecode hex: {}", e)))?;
+
+        Ok(Self::new(bytecode).with_abi(parse_abi(json)?))
+    }
+
+    /// Set the ABI used to encode constructor arguments
+    pub fn with_abi(mut self, abi: JsonAbi) -> Self {
+        self.abi = Some(abi);
+        self
+    }
+
+    /// Set constructor arguments
+    pub fn with_constructor_args(mut self, args: Vec<DynSolValue>) -> Self {
+        self.constructor_args = args;
+        self
+    }
+
+    /// Set the value sent to the constructor
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Deploy with `CREATE2` using `salt`
+    pub fn with_salt(mut self, salt: B256) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Use a different `CREATE2` factory with the same calldata layout
+    pub fn with_factory(mut self, factory: EthAddress) -> Self {
+        self.factory = factory;
+        self
+    }
+
+    /// Set how long to wait for the deployment receipt
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Init code: the bytecode followed by the ABI-encoded constructor arguments
+    pub fn init_code(&self) -> Result<Vec<u8>, Error> {
+        let mut init_code = self.bytecode.clone();
+
+        let constructor = self.abi.as_ref().and_then(|abi| abi.constructor());
+        match constructor {
+            Some(constructor) => {
+                let args = constructor
+                    .abi_encode_input(&self.constructor_args)
+                    .map_err(|e| {
+                        Error::Contract(format!("Failed to encode constructor arguments: {}", e))
+                    })?;
+                init_code.extend_from_slice(&args);
+            }
+            None if !self.constructor_args.is_empty() => {
+                return Err(Error::Contract(
+                    "Constructor arguments need an ABI with a constructor".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        Ok(init_code)
+    }
+
+    /// Predict the `CREATE2` address, if a salt is set
+    ///
+    /// This needs no RPC access and is the same on every chain.
+    pub fn predict_create2_address(&self) -> Result<Option<EthAddress>, Error> {
+        self.salt
+            .map(|salt| Ok(create2_address(self.factory, salt, &self.init_code()?)))
+            .transpose()
+    }
+
+    /// Predict the address the contract will be deployed at by `from`
+    ///
+    /// For `CREATE` this uses the account's current nonce.
+    pub async fn predict_address(
+        &self,
+        adapter: &EvmAdapter,
+        from: EthAddress,
+    ) -> Result<EthAddress, Error> {
+        use alloy::providers::Provider;
+
+        if let Some(address) = self.predict_create2_address()? {
+            return Ok(address);
+        }
+
+        let nonce = adapter
+            .provider()
+            .provider
+            .get_transaction_count(from)
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get nonce: {}", e)))?;
+
+        Ok(create_address(from, nonce))
+    }
+
+    /// Deploy the contract and wait for its receipt
+    ///
+    /// Fails if a `CREATE2` target already has code, if the factory is
+    /// missing, if the transaction reverts, or if no code ends up at the
+    /// predicted address.
+    pub async fn deploy(
+        &self,
+        adapter: &EvmAdapter,
+        executor: &TransactionExecutor,
+        wallet: &Wallet,
+    ) -> Result<Deployment, Error> {
+        let init_code = self.init_code()?;
+        let address = self.predict_address(adapter, wallet.eth_address()).await?;
+
+        let tx_hash = match self.salt {
+            Some(salt) => {
+                if code_exists(adapter, address).await? {
+                    return Err(Error::Contract(format!(
+                        "Contract already deployed at {}",
+                        address
+                    )));
+                }
+                if !code_exists(adapter, self.factory).await? {
+                    return Err(Error::Contract(format!(
+                        "CREATE2 factory {} is not deployed on this chain",
+                        self.factory
+                    )));
+                }
+
+                let mut data = salt.to_vec();
+                data.extend_from_slice(&init_code);
+                executor
+                    .send_transaction(wallet, self.factory, self.value, Some(data))
+                    .await?
+            }
+            None => {
+                executor
+                    .send_deploy_transaction(wallet, self.value, init_code)
+                    .await?
+            }
+        };
+        tracing::info!("Deploying contract to {} in {:?}", address, tx_hash);
+
+        let receipt = executor.wait_for_receipt(tx_hash, self.timeout).await?;
+        if !receipt.status() {
+            return Err(Error::Transaction(format!(
+                "Deployment transaction {:?} reverted",
+                tx_hash
+            )));
+        }
+        if let Some(created) = receipt.contract_address {
+            if created != address {
+                return Err(Error::Contract(format!(
+                    "Contract deployed at {} instead of predicted {}",
+                    created, address
+                )));
+            }
+        }
+        if !code_exists(adapter, address).await? {
+            return Err(Error::Contract(format!(
+                "No contract code at {} after deployment",
+                address
+            )));
+        }
+
+        Ok(Deployment {
+            address,
+            tx_hash,
+            receipt,
+        })
+    }
 }
 
 /// ERC-20 Token wrapper
@@ -125,4 +897,463 @@ impl Erc20 {
         let call = IERC20::approveCall { spender, amount };
         call.abi_encode()
     }
+
+    /// Get the token contract address
+    pub fn address(&self) -> EthAddress {
+        self.contract.address()
+    }
+
+    /// Get the amount `spender` may transfer on behalf of `owner`
+    pub async fn allowance(&self, owner: EthAddress, spender: EthAddress) -> Result<U256, Error> {
+        let call = IERC20::allowanceCall { owner, spender };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC20::allowanceCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode allowance response: {}", e)))
+    }
+
+    /// Prepare a transferFrom transaction data
+    pub fn encode_transfer_from(&self, from: EthAddress, to: EthAddress, amount: U256) -> Vec<u8> {
+        let call = IERC20::transferFromCall { from, to, amount };
+        call.abi_encode()
+    }
+
+    /// Get the EIP-2612 permit nonce of an owner
+    pub async fn nonces(&self, owner: EthAddress) -> Result<U256, Error> {
+        let call = IERC20Permit::noncesCall { owner };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC20Permit::noncesCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode nonces response: {}", e)))
+    }
+
+    /// Get the token's EIP-712 domain separator
+    pub async fn domain_separator(&self) -> Result<B256, Error> {
+        let call = IERC20Permit::DOMAIN_SEPARATORCall {};
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC20Permit::DOMAIN_SEPARATORCall::abi_decode_returns(&result).map_err(|e| {
+            Error::Contract(format!("Failed to decode DOMAIN_SEPARATOR response: {}", e))
+        })
+    }
+
+    /// Sign an EIP-2612 permit allowing `spender` to spend `value` until `deadline`
+    ///
+    /// Uses the token's `DOMAIN_SEPARATOR()` and the owner's current nonce.
+    /// Submit the result with [`encode_permit`](Self::encode_permit).
+    pub async fn sign_permit(
+        &self,
+        wallet: &Wallet,
+        spender: EthAddress,
+        value: U256,
+        deadline: U256,
+    ) -> Result<PermitSignature, Error> {
+        use alloy::sol_types::SolStruct;
+
+        let owner = wallet.eth_address();
+        let permit = Permit {
+            owner,
+            spender,
+            value,
+            nonce: self.nonces(owner).await?,
+            deadline,
+        };
+        let domain_separator = self.domain_separator().await?;
+
+        let mut digest_input = [0u8; 66];
+        digest_input[..2].copy_from_slice(&[0x19, 0x01]);
+        digest_input[2..34].copy_from_slice(domain_separator.as_slice());
+        digest_input[34..].copy_from_slice(permit.eip712_hash_struct().as_slice());
+        let digest = alloy::primitives::keccak256(digest_input);
+
+        let signature = wallet.sign_typed_data_hash(&digest).await?;
+
+        Ok(PermitSignature {
+            owner,
+            spender,
+            value,
+            deadline,
+            v: 27 + signature.v() as u8,
+            r: signature.r().into(),
+            s: signature.s().into(),
+        })
+    }
+
+    /// Prepare a permit transaction data from a signed permit
+    pub fn encode_permit(&self, permit: &PermitSignature) -> Vec<u8> {
+        let call = IERC20Permit::permitCall {
+            owner: permit.owner,
+            spender: permit.spender,
+            value: permit.value,
+            deadline: permit.deadline,
+            v: permit.v,
+            r: permit.r,
+            s: permit.s,
+        };
+        call.abi_encode()
+    }
+}
+
+/// A signed EIP-2612 permit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermitSignature {
+    /// Token owner granting the allowance
+    pub owner: EthAddress,
+    /// Spender receiving the allowance
+    pub spender: EthAddress,
+    /// Allowance amount
+    pub value: U256,
+    /// Timestamp after which the permit is invalid
+    pub deadline: U256,
+    /// Signature recovery ID (27 or 28)
+    pub v: u8,
+    /// Signature `r`
+    pub r: B256,
+    /// Signature `s`
+    pub s: B256,
+}
+
+/// ERC-721 non-fungible token wrapper
+pub struct Erc721 {
+    contract: Contract,
+}
+
+impl Erc721 {
+    /// Create a new ERC-721 token instance
+    pub fn new(address: EthAddress, adapter: EvmAdapter) -> Self {
+        Self {
+            contract: Contract::new(address, adapter),
+        }
+    }
+
+    /// Get the token contract address
+    pub fn address(&self) -> EthAddress {
+        self.contract.address()
+    }
+
+    /// Check whether the contract reports an interface via ERC-165
+    pub async fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
+        self.contract.supports_interface(interface_id).await
+    }
+
+    /// Get the number of tokens owned by an address
+    pub async fn balance_of(&self, owner: EthAddress) -> Result<U256, Error> {
+        let call = IERC721::balanceOfCall { owner };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::balanceOfCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode balanceOf response: {}", e)))
+    }
+
+    /// Get the owner of a token
+    pub async fn owner_of(&self, token_id: U256) -> Result<EthAddress, Error> {
+        let call = IERC721::ownerOfCall { tokenId: token_id };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::ownerOfCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode ownerOf response: {}", e)))
+    }
+
+    /// Get the collection name (metadata extension)
+    pub async fn name(&self) -> Result<String, Error> {
+        let call = IERC721::nameCall {};
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::nameCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode name response: {}", e)))
+    }
+
+    /// Get the collection symbol (metadata extension)
+    pub async fn symbol(&self) -> Result<String, Error> {
+        let call = IERC721::symbolCall {};
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::symbolCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode symbol response: {}", e)))
+    }
+
+    /// Get the metadata URI of a token (metadata extension)
+    pub async fn token_uri(&self, token_id: U256) -> Result<String, Error> {
+        let call = IERC721::tokenURICall { tokenId: token_id };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::tokenURICall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode tokenURI response: {}", e)))
+    }
+
+    /// Get the address approved for a token
+    pub async fn get_approved(&self, token_id: U256) -> Result<EthAddress, Error> {
+        let call = IERC721::getApprovedCall { tokenId: token_id };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::getApprovedCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode getApproved response: {}", e)))
+    }
+
+    /// Check whether `operator` may manage all of `owner`'s tokens
+    pub async fn is_approved_for_all(
+        &self,
+        owner: EthAddress,
+        operator: EthAddress,
+    ) -> Result<bool, Error> {
+        let call = IERC721::isApprovedForAllCall { owner, operator };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC721::isApprovedForAllCall::abi_decode_returns(&result).map_err(|e| {
+            Error::Contract(format!("Failed to decode isApprovedForAll response: {}", e))
+        })
+    }
+
+    /// Prepare a safeTransferFrom transaction data
+    ///
+    /// Non-empty `data` is passed to the receiver's `onERC721Received`.
+    pub fn encode_safe_transfer_from(
+        &self,
+        from: EthAddress,
+        to: EthAddress,
+        token_id: U256,
+        data: Vec<u8>,
+    ) -> Vec<u8> {
+        if data.is_empty() {
+            IERC721::safeTransferFrom_1Call {
+                from,
+                to,
+                tokenId: token_id,
+            }
+            .abi_encode()
+        } else {
+            IERC721::safeTransferFrom_0Call {
+                from,
+                to,
+                tokenId: token_id,
+                data: data.into(),
+            }
+            .abi_encode()
+        }
+    }
+
+    /// Prepare a transferFrom transaction data
+    pub fn encode_transfer_from(
+        &self,
+        from: EthAddress,
+        to: EthAddress,
+        token_id: U256,
+    ) -> Vec<u8> {
+        let call = IERC721::transferFromCall {
+            from,
+            to,
+            tokenId: token_id,
+        };
+        call.abi_encode()
+    }
+
+    /// Prepare an approve transaction data
+    pub fn encode_approve(&self, to: EthAddress, token_id: U256) -> Vec<u8> {
+        let call = IERC721::approveCall {
+            to,
+            tokenId: token_id,
+        };
+        call.abi_encode()
+    }
+
+    /// Prepare a setApprovalForAll transaction data
+    pub fn encode_set_approval_for_all(&self, operator: EthAddress, approved: bool) -> Vec<u8> {
+        let call = IERC721::setApprovalForAllCall { operator, approved };
+        call.abi_encode()
+    }
+
+    /// Decode a `Transfer` log emitted by this contract
+    pub fn decode_transfer(&self, log: &Log) -> Option<IERC721::Transfer> {
+        decode_token_event(self.address(), log)
+    }
+
+    /// Decode this contract's `Transfer` logs from a transaction receipt
+    pub fn transfers(&self, receipt: &TransactionReceipt) -> Vec<IERC721::Transfer> {
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter_map(|log| self.decode_transfer(log))
+            .collect()
+    }
+}
+
+/// An ERC-1155 transfer, from either `TransferSingle` or `TransferBatch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erc1155Transfer {
+    /// Account that performed the transfer
+    pub operator: EthAddress,
+    /// Sender (zero for mints)
+    pub from: EthAddress,
+    /// Recipient (zero for burns)
+    pub to: EthAddress,
+    /// Token IDs
+    pub ids: Vec<U256>,
+    /// Amounts, one per token ID
+    pub values: Vec<U256>,
+}
+
+/// ERC-1155 multi-token wrapper
+pub struct Erc1155 {
+    contract: Contract,
+}
+
+impl Erc1155 {
+    /// Create a new ERC-1155 token instance
+    pub fn new(address: EthAddress, adapter: EvmAdapter) -> Self {
+        Self {
+            contract: Contract::new(address, adapter),
+        }
+    }
+
+    /// Get the token contract address
+    pub fn address(&self) -> EthAddress {
+        self.contract.address()
+    }
+
+    /// Check whether the contract reports an interface via ERC-165
+    pub async fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
+        self.contract.supports_interface(interface_id).await
+    }
+
+    /// Get an account's balance of a token
+    pub async fn balance_of(&self, account: EthAddress, id: U256) -> Result<U256, Error> {
+        let call = IERC1155::balanceOfCall { account, id };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC1155::balanceOfCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode balanceOf response: {}", e)))
+    }
+
+    /// Get balances for pairs of accounts and token IDs
+    pub async fn balance_of_batch(
+        &self,
+        accounts: Vec<EthAddress>,
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Error> {
+        if accounts.len() != ids.len() {
+            return Err(Error::Contract(format!(
+                "balanceOfBatch needs one ID per account, got {} accounts and {} IDs",
+                accounts.len(),
+                ids.len()
+            )));
+        }
+
+        let call = IERC1155::balanceOfBatchCall { accounts, ids };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC1155::balanceOfBatchCall::abi_decode_returns(&result).map_err(|e| {
+            Error::Contract(format!("Failed to decode balanceOfBatch response: {}", e))
+        })
+    }
+
+    /// Get the metadata URI template of a token
+    ///
+    /// Clients substitute `{id}` with the lowercase, zero-padded hex token ID.
+    pub async fn uri(&self, id: U256) -> Result<String, Error> {
+        let call = IERC1155::uriCall { id };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC1155::uriCall::abi_decode_returns(&result)
+            .map_err(|e| Error::Contract(format!("Failed to decode uri response: {}", e)))
+    }
+
+    /// Check whether `operator` may manage all of `account`'s tokens
+    pub async fn is_approved_for_all(
+        &self,
+        account: EthAddress,
+        operator: EthAddress,
+    ) -> Result<bool, Error> {
+        let call = IERC1155::isApprovedForAllCall { account, operator };
+        let result = self.contract.call_view(call.abi_encode()).await?;
+
+        IERC1155::isApprovedForAllCall::abi_decode_returns(&result).map_err(|e| {
+            Error::Contract(format!("Failed to decode isApprovedForAll response: {}", e))
+        })
+    }
+
+    /// Prepare a setApprovalForAll transaction data
+    pub fn encode_set_approval_for_all(&self, operator: EthAddress, approved: bool) -> Vec<u8> {
+        let call = IERC1155::setApprovalForAllCall { operator, approved };
+        call.abi_encode()
+    }
+
+    /// Prepare a safeTransferFrom transaction data
+    pub fn encode_safe_transfer_from(
+        &self,
+        from: EthAddress,
+        to: EthAddress,
+        id: U256,
+        value: U256,
+        data: Vec<u8>,
+    ) -> Vec<u8> {
+        let call = IERC1155::safeTransferFromCall {
+            from,
+            to,
+            id,
+            value,
+            data: data.into(),
+        };
+        call.abi_encode()
+    }
+
+    /// Prepare a safeBatchTransferFrom transaction data
+    pub fn encode_safe_batch_transfer_from(
+        &self,
+        from: EthAddress,
+        to: EthAddress,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Vec<u8>,
+    ) -> Vec<u8> {
+        let call = IERC1155::safeBatchTransferFromCall {
+            from,
+            to,
+            ids,
+            values,
+            data: data.into(),
+        };
+        call.abi_encode()
+    }
+
+    /// Decode a `TransferSingle` or `TransferBatch` log emitted by this contract
+    pub fn decode_transfer(&self, log: &Log) -> Option<Erc1155Transfer> {
+        if let Some(single) = decode_token_event::<IERC1155::TransferSingle>(self.address(), log) {
+            return Some(Erc1155Transfer {
+                operator: single.operator,
+                from: single.from,
+                to: single.to,
+                ids: vec![single.id],
+                values: vec![single.value],
+            });
+        }
+
+        decode_token_event::<IERC1155::TransferBatch>(self.address(), log).map(|batch| {
+            Erc1155Transfer {
+                operator: batch.operator,
+                from: batch.from,
+                to: batch.to,
+                ids: batch.ids,
+                values: batch.values,
+            }
+        })
+    }
+
+    /// Decode this contract's transfer logs from a transaction receipt
+    pub fn transfers(&self, receipt: &TransactionReceipt) -> Vec<Erc1155Transfer> {
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter_map(|log| self.decode_transfer(log))
+            .collect()
+    }
+}
+
+/// Decode a typed event from a log emitted by `address`
+fn decode_token_event<E: SolEvent>(address: EthAddress, log: &Log) -> Option<E> {
+    if log.address() != address {
+        return None;
+    }
+    E::decode_log(&log.inner).ok().map(|decoded| decoded.data)
 }
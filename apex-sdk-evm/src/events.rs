@@ -0,0 +1,686 @@
+//! EVM event watcher
+//!
+//! Follows new logs via `eth_subscribe("logs")` when a WebSocket endpoint is
+//! configured, falling back to `eth_getLogs` polling over HTTP. Logs are
+//! mapped onto the chain-agnostic [`Event`] type. Event names are resolved
+//! from `topic0` using the signatures in the filter (for example
+//! `"Transfer(address,address,uint256)"`), the events of any ABI added with
+//! [`EvmEventWatcher::with_abi`] and a small table of well-known token events;
+//! logs with an unknown signature are named after their `topic0` hash. Logs
+//! whose event is known from an ABI or the token table are decoded into named
+//! `params`.
+
+use crate::contract::decode_event;
+use crate::{AlloyHttpProvider, Error};
+use alloy::dyn_abi::DynSolValue;
+use alloy::json_abi::{Event as AbiEvent, JsonAbi};
+use alloy::primitives::{keccak256, Address as EthAddress, B256};
+use alloy::providers::{Provider, ProviderBuilder, WsConnect};
+use alloy::rpc::types::{Filter, Log};
+use apex_sdk_types::{Address, Event, EventFilter};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+/// Signatures of widely used token events, resolved without an explicit ABI
+pub const KNOWN_EVENT_SIGNATURES: &[&str] = &[
+    "Transfer(address,address,uint256)",
+    "Approval(address,address,uint256)",
+    "ApprovalForAll(address,address,bool)",
+    "TransferSingle(address,address,address,uint256,uint256)",
+    "TransferBatch(address,address,address,uint256[],uint256[])",
+    "Deposit(address,uint256)",
+    "Withdrawal(address,uint256)",
+];
+
+/// Declarations of the well-known token events, used to decode their logs
+///
+/// ERC-20 and ERC-721 share the `Transfer` and `Approval` signatures and differ
+/// only in whether the amount is indexed, so both variants are listed.
+const KNOWN_EVENTS: &[&str] = &[
+    "event Transfer(address indexed from, address indexed to, uint256 value)",
+    "event Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+    "event Approval(address indexed owner, address indexed spender, uint256 value)",
+    "event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId)",
+    "event ApprovalForAll(address indexed owner, address indexed operator, bool approved)",
+    "event TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value)",
+    "event TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values)",
+    "event Deposit(address indexed dst, uint256 wad)",
+    "event Withdrawal(address indexed src, uint256 wad)",
+];
+
+/// Compute the `topic0` hash of an event signature
+pub fn event_signature_topic(signature: &str) -> B256 {
+    keccak256(signature.as_bytes())
+}
+
+/// EVM event watcher
+#[derive(Debug, Clone)]
+pub struct EvmEventWatcher {
+    provider: AlloyHttpProvider,
+    ws_url: Option<String>,
+    events: Vec<AbiEvent>,
+    polling_interval: Duration,
+    max_block_range: u64,
+}
+
+impl EvmEventWatcher {
+    /// Create a new EVM event watcher using HTTP polling
+    pub fn new(provider: AlloyHttpProvider) -> Self {
+        Self {
+            provider,
+            ws_url: None,
+            events: Vec::new(),
+            polling_interval: Duration::from_millis(2000),
+            max_block_range: 1000,
+        }
+    }
+
+    /// Use a WebSocket endpoint for `logs` subscriptions
+    pub fn with_ws_endpoint(mut self, ws_url: impl Into<String>) -> Self {
+        self.ws_url = Some(ws_url.into());
+        self
+    }
+
+    /// Resolve and decode the events of a contract ABI
+    ///
+    /// ABI events take precedence over the well-known token events. Anonymous
+    /// events have no signature topic and are ignored.
+    pub fn with_abi(mut self, abi: &JsonAbi) -> Self {
+        self.events
+            .extend(abi.events().filter(|event| !event.anonymous).cloned());
+        self
+    }
+
+    /// Set the polling interval
+    pub fn with_polling_interval(mut self, interval: Duration) -> Self {
+        self.polling_interval = interval;
+        self
+    }
+
+    /// Set the maximum number of blocks requested by a single `eth_getLogs` call
+    pub fn with_max_block_range(mut self, max_block_range: u64) -> Self {
+        self.max_block_range = max_block_range.max(1);
+        self
+    }
+
+    /// Get all events matching the filter between two blocks (inclusive)
+    ///
+    /// The range is split into chunks of at most `max_block_range` blocks.
+    pub async fn get_events(
+        &self,
+        filter: &EventFilter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Event>, Error> {
+        let query = LogQuery::new(filter, &self.events)?;
+        let logs = self
+            .fetch_logs(&query, filter, from_block, to_block)
+            .await?;
+        Ok(logs.iter().map(|log| query.log_to_event(log)).collect())
+    }
+
+    /// Watch for events matching the filter
+    ///
+    /// Starts from `filter.from_block` (or the current head) and passes every
+    /// matching event to `on_event`. Open-ended filters follow new logs over
+    /// the WebSocket subscription if one is configured, switching to HTTP
+    /// polling if it cannot be established or drops; filters with a
+    /// `to_block` are always polled. Returns once `filter.to_block` has been
+    /// processed or `on_event` returns `false`.
+    pub async fn watch<F>(&self, filter: EventFilter, mut on_event: F) -> Result<(), Error>
+    where
+        F: FnMut(Event) -> bool,
+    {
+        let query = LogQuery::new(&filter, &self.events)?;
+
+        let mut resume = Resume::new(match filter.from_block {
+            Some(from) => from,
+            None => self.get_current_block().await?,
+        });
+
+        if let (None, Some(ws_url)) = (filter.to_block, &self.ws_url) {
+            match self
+                .watch_ws(ws_url, &query, &filter, &mut resume, &mut on_event)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    warn!(
+                        "WebSocket log subscription failed, falling back to HTTP polling: {}",
+                        e
+                    );
+                    resume.rewind();
+                }
+            }
+        }
+
+        self.watch_polling(&query, &filter, &mut resume, &mut on_event)
+            .await
+    }
+
+    /// Follow `logs` over WebSocket, catching up over HTTP first
+    async fn watch_ws<F>(
+        &self,
+        ws_url: &str,
+        query: &LogQuery,
+        filter: &EventFilter,
+        resume: &mut Resume,
+        on_event: &mut F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(Event) -> bool,
+    {
+        let ws_provider = ProviderBuilder::new()
+            .connect_ws(WsConnect::new(ws_url))
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to connect to {}: {}", ws_url, e)))?;
+
+        // Subscribe before catching up so that no log falls between the two
+        let mut subscription = ws_provider
+            .subscribe_logs(&query.base_filter())
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to subscribe to logs: {}", e)))?;
+
+        debug!("Subscribed to logs at {}", ws_url);
+
+        let head = self.get_current_block().await?;
+        if resume.next_block <= head {
+            debug!(
+                "Catching up on logs for blocks {}..={}",
+                resume.next_block, head
+            );
+            for log in self
+                .fetch_logs(query, filter, resume.next_block, head)
+                .await?
+            {
+                if !on_event(query.log_to_event(&log)) {
+                    return Ok(());
+                }
+            }
+            resume.next_block = head + 1;
+        }
+
+        loop {
+            let log = subscription
+                .recv()
+                .await
+                .map_err(|e| Error::Connection(format!("Log subscription error: {}", e)))?;
+
+            if !(query.topics_complete || query.matches_locally(&log, filter))
+                || !resume.is_new(&log)
+            {
+                continue;
+            }
+
+            resume.emitted(&log);
+            if !on_event(query.log_to_event(&log)) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Poll `eth_getLogs` over HTTP
+    async fn watch_polling<F>(
+        &self,
+        query: &LogQuery,
+        filter: &EventFilter,
+        resume: &mut Resume,
+        on_event: &mut F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(Event) -> bool,
+    {
+        loop {
+            let head = self.get_current_block().await?;
+            let target = filter.to_block.map_or(head, |to| to.min(head));
+
+            if resume.next_block <= target {
+                debug!(
+                    "Fetching logs for blocks {}..={}",
+                    resume.next_block, target
+                );
+                for log in self
+                    .fetch_logs(query, filter, resume.next_block, target)
+                    .await?
+                {
+                    if resume.is_new(&log) && !on_event(query.log_to_event(&log)) {
+                        return Ok(());
+                    }
+                }
+                resume.next_block = target + 1;
+            }
+
+            if filter.to_block.is_some_and(|to| resume.next_block > to) {
+                debug!("Event watcher reached end of block range");
+                return Ok(());
+            }
+
+            sleep(self.polling_interval).await;
+        }
+    }
+
+    /// Get the current block number
+    async fn get_current_block(&self) -> Result<u64, Error> {
+        self.provider
+            .get_block_number()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get block number: {}", e)))
+    }
+
+    /// Fetch the matching logs for a block range, chunked by `max_block_range`
+    async fn fetch_logs(
+        &self,
+        query: &LogQuery,
+        filter: &EventFilter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Log>, Error> {
+        let mut matching = Vec::new();
+        let mut start = from_block;
+
+        while start <= to_block {
+            let end = start.saturating_add(self.max_block_range - 1).min(to_block);
+
+            let logs = self
+                .provider
+                .get_logs(&query.to_filter(start, end))
+                .await
+                .map_err(|e| Error::Connection(format!("Failed to get logs: {}", e)))?;
+
+            matching.extend(
+                logs.into_iter()
+                    .filter(|log| query.topics_complete || query.matches_locally(log, filter)),
+            );
+
+            if end == u64::MAX {
+                break;
+            }
+            start = end + 1;
+        }
+
+        Ok(matching)
+    }
+}
+
+/// Position up to which logs have been passed on
+///
+/// The WebSocket subscription records the last log it emitted so that HTTP
+/// polling can resume inside that block without repeating it.
+#[derive(Debug, Clone, Copy)]
+struct Resume {
+    /// First block whose logs have not been fetched
+    next_block: u64,
+    /// Block number and log index of the last log emitted from the subscription
+    last_log: Option<(u64, u64)>,
+}
+
+impl Resume {
+    fn new(next_block: u64) -> Self {
+        Self {
+            next_block,
+            last_log: None,
+        }
+    }
+
+    /// Whether a log lies after everything emitted so far
+    ///
+    /// Removed logs always pass, since they retract a log emitted earlier.
+    fn is_new(&self, log: &Log) -> bool {
+        if log.removed {
+            return true;
+        }
+        let Some(block) = log.block_number else {
+            return true;
+        };
+        if block < self.next_block {
+            return false;
+        }
+        match (self.last_log, log.log_index) {
+            (Some(last), Some(index)) => (block, index) > last,
+            _ => true,
+        }
+    }
+
+    /// Record a log emitted from the subscription
+    fn emitted(&mut self, log: &Log) {
+        if log.removed {
+            return;
+        }
+        if let (Some(block), Some(index)) = (log.block_number, log.log_index) {
+            self.last_log = Some(
+                self.last_log
+                    .map_or((block, index), |last| last.max((block, index))),
+            );
+        }
+    }
+
+    /// Resume fetching from the block of the last emitted log
+    fn rewind(&mut self) {
+        if let Some((block, _)) = self.last_log {
+            self.next_block = self.next_block.max(block);
+        }
+    }
+}
+
+/// Log query derived from an [`EventFilter`]
+#[derive(Debug, Clone)]
+struct LogQuery {
+    addresses: Vec<EthAddress>,
+    /// Requested `topic0` values, empty when all events are wanted
+    topics: Vec<B256>,
+    /// Whether every requested event name resolved to a topic, so that the
+    /// node-side topic filter is sufficient
+    topics_complete: bool,
+    /// Known signatures by topic
+    signatures: HashMap<B256, String>,
+    /// Event declarations by topic, tried in order when decoding
+    events: HashMap<B256, Vec<AbiEvent>>,
+}
+
+impl LogQuery {
+    fn new(filter: &EventFilter, abi_events: &[AbiEvent]) -> Result<Self, Error> {
+        let mut addresses = Vec::new();
+        for address in filter.addresses.iter().flatten() {
+            match address {
+                Address::Evm(addr) => addresses.push(
+                    EthAddress::from_str(addr)
+                        .map_err(|e| Error::InvalidAddress(format!("{}: {}", addr, e)))?,
+                ),
+                Address::Substrate(addr) => {
+                    return Err(Error::InvalidAddress(format!(
+                        "Substrate address {} cannot be used to filter EVM logs",
+                        addr
+                    )))
+                }
+            }
+        }
+
+        let mut signatures: HashMap<B256, String> = KNOWN_EVENT_SIGNATURES
+            .iter()
+            .map(|sig| (event_signature_topic(sig), sig.to_string()))
+            .collect();
+
+        let mut events: HashMap<B256, Vec<AbiEvent>> = HashMap::new();
+        let known_events = KNOWN_EVENTS
+            .iter()
+            .filter_map(|declaration| AbiEvent::parse(declaration).ok());
+        for event in abi_events.iter().cloned().chain(known_events) {
+            signatures
+                .entry(event.selector())
+                .or_insert_with(|| event.signature());
+            events.entry(event.selector()).or_default().push(event);
+        }
+
+        let mut topics = Vec::new();
+        let mut topics_complete = true;
+        for name in filter.event_names.iter().flatten() {
+            if name.contains('(') {
+                let topic = event_signature_topic(name);
+                signatures.insert(topic, name.clone());
+                topics.push(topic);
+            } else if let Ok(topic) = B256::from_str(name) {
+                topics.push(topic);
+            } else {
+                let known: Vec<B256> = signatures
+                    .iter()
+                    .filter(|(_, sig)| signature_name(sig) == name)
+                    .map(|(topic, _)| *topic)
+                    .collect();
+
+                if known.is_empty() {
+                    debug!(
+                        "Event name {} has no known signature; pass the full signature to filter by it",
+                        name
+                    );
+                    topics_complete = false;
+                }
+                topics.extend(known);
+            }
+        }
+
+        Ok(Self {
+            addresses,
+            topics: if topics_complete { topics } else { Vec::new() },
+            topics_complete,
+            signatures,
+            events,
+        })
+    }
+
+    /// Address and topic filter without a block range, as used by subscriptions
+    fn base_filter(&self) -> Filter {
+        let mut filter = Filter::new();
+        if !self.addresses.is_empty() {
+            filter = filter.address(self.addresses.clone());
+        }
+        if !self.topics.is_empty() {
+            filter = filter.event_signature(self.topics.clone());
+        }
+        filter
+    }
+
+    fn to_filter(&self, from_block: u64, to_block: u64) -> Filter {
+        self.base_filter().from_block(from_block).to_block(to_block)
+    }
+
+    /// Match a log by name, signature or `topic0` when the node could not filter it
+    fn matches_locally(&self, log: &Log, filter: &EventFilter) -> bool {
+        let Some(topic) = log.topics().first() else {
+            return filter.event_names.is_none();
+        };
+
+        filter.matches_name(&format!("0x{:x}", topic))
+            || self
+                .signatures
+                .get(topic)
+                .is_some_and(|signature| filter.matches_name(signature))
+    }
+
+    /// Decode a log with the first known event for its `topic0` that fits
+    fn decode(&self, log: &Log) -> Option<(String, Vec<(String, DynSolValue)>)> {
+        let topics = log.topics();
+        self.events
+            .get(topics.first()?)?
+            .iter()
+            .find_map(|event| decode_event(event, topics, &log.data().data))
+    }
+
+    fn log_to_event(&self, log: &Log) -> Event {
+        let topics = log.topics();
+        let signature = topics
+            .first()
+            .and_then(|topic| self.signatures.get(topic))
+            .cloned();
+        let decoded = self.decode(log);
+
+        let name = match (&decoded, &signature, topics.first()) {
+            (Some((name, _)), _, _) => name.clone(),
+            (None, Some(sig), _) => signature_name(sig).to_string(),
+            (None, None, Some(topic)) => format!("0x{:x}", topic),
+            (None, None, None) => "Anonymous".to_string(),
+        };
+
+        let params = decoded.map(|(_, params)| {
+            params
+                .iter()
+                .enumerate()
+                .map(|(i, (name, value))| {
+                    let key = if name.is_empty() {
+                        i.to_string()
+                    } else {
+                        name.clone()
+                    };
+                    (key, value_to_json(value))
+                })
+                .collect::<serde_json::Map<_, _>>()
+        });
+
+        let data = serde_json::json!({
+            "address": format!("{:?}", log.address()),
+            "signature": signature,
+            "params": params,
+            "topics": topics.iter().map(|t| format!("0x{:x}", t)).collect::<Vec<_>>(),
+            "data": format!("0x{}", hex::encode(&log.data().data)),
+            "block_hash": log.block_hash.map(|h| format!("0x{:x}", h)),
+            "removed": log.removed,
+        });
+
+        Event {
+            name,
+            data,
+            block_number: log.block_number,
+            tx_hash: log.transaction_hash.map(|h| format!("0x{:x}", h)),
+            index: log.log_index.map(|i| i as u32),
+        }
+    }
+}
+
+/// Strip the parameter list from an event signature
+fn signature_name(signature: &str) -> &str {
+    signature.split('(').next().unwrap_or(signature)
+}
+
+/// Convert a decoded value to JSON
+///
+/// Integers are written as decimal strings since they may exceed the range of
+/// JSON numbers.
+fn value_to_json(value: &DynSolValue) -> serde_json::Value {
+    match value {
+        DynSolValue::Address(address) => address.to_checksum(None).into(),
+        DynSolValue::Bool(b) => (*b).into(),
+        DynSolValue::Int(i, _) => i.to_string().into(),
+        DynSolValue::Uint(u, _) => u.to_string().into(),
+        DynSolValue::String(s) => s.clone().into(),
+        DynSolValue::FixedBytes(word, size) => format!("0x{}", hex::encode(&word[..*size])).into(),
+        DynSolValue::Bytes(bytes) => format!("0x{}", hex::encode(bytes)).into(),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => values.iter().map(value_to_json).collect(),
+        other => format!("{:?}", other).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(names: Option<Vec<&str>>) -> EventFilter {
+        EventFilter {
+            event_names: names.map(|n| n.into_iter().map(String::from).collect()),
+            addresses: None,
+            from_block: None,
+            to_block: None,
+        }
+    }
+
+    #[test]
+    fn test_event_signature_topic() {
+        assert_eq!(
+            format!(
+                "0x{:x}",
+                event_signature_topic("Transfer(address,address,uint256)")
+            ),
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
+    #[test]
+    fn test_log_query_resolves_known_names() {
+        let query = LogQuery::new(&filter(Some(vec!["Transfer"])), &[]).unwrap();
+        assert!(query.topics_complete);
+        assert_eq!(
+            query.topics,
+            vec![event_signature_topic("Transfer(address,address,uint256)")]
+        );
+
+        let query = LogQuery::new(&filter(Some(vec!["Swap(address,uint256)"])), &[]).unwrap();
+        assert_eq!(
+            query.topics,
+            vec![event_signature_topic("Swap(address,uint256)")]
+        );
+    }
+
+    #[test]
+    fn test_log_query_unknown_name_filters_locally() {
+        let query = LogQuery::new(&filter(Some(vec!["Transfer", "Swap"])), &[]).unwrap();
+        assert!(!query.topics_complete);
+        assert!(query.topics.is_empty());
+    }
+
+    #[test]
+    fn test_log_query_rejects_substrate_address() {
+        let mut f = filter(None);
+        f.addresses = Some(vec![Address::substrate(
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        )]);
+        assert!(matches!(
+            LogQuery::new(&f, &[]),
+            Err(Error::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_known_events_parse() {
+        for declaration in KNOWN_EVENTS {
+            let event = AbiEvent::parse(declaration).unwrap();
+            assert!(KNOWN_EVENT_SIGNATURES.contains(&event.signature().as_str()));
+        }
+    }
+
+    #[test]
+    fn test_log_query_decodes_abi_events() {
+        let swap = AbiEvent::parse("event Swap(address indexed sender, uint256 amount)").unwrap();
+        let query =
+            LogQuery::new(&filter(Some(vec!["Swap"])), std::slice::from_ref(&swap)).unwrap();
+        assert!(query.topics_complete);
+        assert_eq!(query.topics, vec![swap.selector()]);
+
+        let sender = EthAddress::repeat_byte(0x11);
+        let log = Log {
+            inner: alloy::primitives::Log::new_unchecked(
+                EthAddress::ZERO,
+                vec![swap.selector(), sender.into_word()],
+                alloy::primitives::U256::from(7u64)
+                    .to_be_bytes::<32>()
+                    .to_vec()
+                    .into(),
+            ),
+            ..Default::default()
+        };
+
+        let event = query.log_to_event(&log);
+        assert_eq!(event.name, "Swap");
+        assert_eq!(event.data["params"]["sender"], sender.to_checksum(None));
+        assert_eq!(event.data["params"]["amount"], "7");
+    }
+
+    fn log_at(block: u64, index: u64, removed: bool) -> Log {
+        Log {
+            block_number: Some(block),
+            log_index: Some(index),
+            removed,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resume_skips_emitted_logs() {
+        let mut resume = Resume::new(10);
+        assert!(!resume.is_new(&log_at(9, 0, false)));
+        assert!(resume.is_new(&log_at(10, 0, false)));
+
+        resume.emitted(&log_at(12, 2, false));
+        assert!(!resume.is_new(&log_at(12, 2, false)));
+        assert!(resume.is_new(&log_at(12, 3, false)));
+        assert!(resume.is_new(&log_at(12, 2, true)));
+
+        // Polling picks up again inside the block of the last emitted log
+        resume.rewind();
+        assert_eq!(resume.next_block, 12);
+        assert!(!resume.is_new(&log_at(12, 1, false)));
+        assert!(resume.is_new(&log_at(13, 0, false)));
+    }
+}
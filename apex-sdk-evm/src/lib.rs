@@ -39,22 +39,28 @@
 //! ```
 
 pub mod adapter;
+pub mod block_watcher;
 pub mod broadcaster;
 pub mod cache;
 pub mod contract;
+pub mod events;
 pub mod fee_estimator;
 pub mod metrics;
+pub mod multicall;
 pub mod nonce_manager;
 pub mod pool;
 pub mod provider;
 pub mod receipt_watcher;
+pub mod rpc_batch;
 pub mod signer;
 pub mod transaction;
 pub mod wallet;
 
 // Re-export the main implementations
 pub use adapter::EvmAdapter;
+pub use block_watcher::EvmBlockWatcher;
 pub use broadcaster::EvmBroadcaster;
+pub use events::EvmEventWatcher;
 pub use fee_estimator::EvmFeeEstimator;
 pub use nonce_manager::EvmNonceManager;
 pub use provider::EvmProvider;
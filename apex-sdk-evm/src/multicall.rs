@@ -0,0 +1,246 @@
+//! Multicall3 batching for EVM reads
+//!
+//! This module aggregates many read-only calls into `aggregate3` calls on the
+//! [Multicall3](https://github.com/mds1/multicall) contract, which is deployed
+//! at the same address on most EVM chains:
+//! - Per-call `allowFailure`, with results returned in queue order
+//! - Automatic splitting by call count, calldata size and gas budget
+//! - Typed decoding of results with alloy `SolCall` types
+
+use crate::contract::Contract;
+use crate::{Error, EvmAdapter};
+use alloy::primitives::{address, Address as EthAddress, Bytes};
+use alloy::sol;
+use alloy::sol_types::SolCall;
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+}
+
+/// Canonical Multicall3 deployment address
+pub const MULTICALL3_ADDRESS: EthAddress = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+/// Default maximum number of calls per `aggregate3` call
+pub const DEFAULT_MAX_CALLS: usize = 500;
+
+/// Default maximum total calldata bytes per `aggregate3` call
+pub const DEFAULT_MAX_CALLDATA_BYTES: usize = 128 * 1024;
+
+/// Result of one call in a multicall
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticallResult {
+    /// Whether the call succeeded
+    pub success: bool,
+    /// Return data, or revert data if the call failed
+    pub return_data: Bytes,
+}
+
+impl MulticallResult {
+    /// Decode the return data of a successful call
+    pub fn decode<C: SolCall>(&self) -> Result<C::Return, Error> {
+        if !self.success {
+            return Err(Error::Contract(format!(
+                "{} failed with 0x{}",
+                C::SIGNATURE,
+                hex::encode(&self.return_data)
+            )));
+        }
+
+        C::abi_decode_returns(&self.return_data).map_err(|e| {
+            Error::Contract(format!("Failed to decode {} response: {}", C::SIGNATURE, e))
+        })
+    }
+}
+
+/// Multicall3 aggregator
+///
+/// # Example
+/// ```no_run
+/// use alloy::primitives::Address;
+/// use apex_sdk_evm::contract::IERC20;
+/// use apex_sdk_evm::multicall::Multicall;
+/// use apex_sdk_evm::EvmAdapter;
+///
+/// # async fn example(adapter: EvmAdapter, tokens: Vec<Address>, owner: Address) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut multicall = Multicall::new(adapter);
+/// for token in &tokens {
+///     multicall.add_call(*token, &IERC20::balanceOfCall { account: owner }, true);
+/// }
+///
+/// for (token, result) in tokens.iter().zip(multicall.execute().await?) {
+///     if let Ok(balance) = result.decode::<IERC20::balanceOfCall>() {
+///         println!("{}: {}", token, balance);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Multicall {
+    contract: Contract,
+    calls: Vec<IMulticall3::Call3>,
+    max_calls: usize,
+    max_calldata_bytes: usize,
+    gas_budget: Option<(u64, u64)>,
+}
+
+impl Multicall {
+    /// Create an aggregator using the canonical Multicall3 deployment
+    pub fn new(adapter: EvmAdapter) -> Self {
+        Self::with_address(MULTICALL3_ADDRESS, adapter)
+    }
+
+    /// Create an aggregator using a Multicall3 deployment at `address`
+    pub fn with_address(address: EthAddress, adapter: EvmAdapter) -> Self {
+        Self {
+            contract: Contract::new(address, adapter),
+            calls: Vec::new(),
+            max_calls: DEFAULT_MAX_CALLS,
+            max_calldata_bytes: DEFAULT_MAX_CALLDATA_BYTES,
+            gas_budget: None,
+        }
+    }
+
+    /// Set the maximum number of calls per `aggregate3` call
+    pub fn with_max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = max_calls.max(1);
+        self
+    }
+
+    /// Set the maximum total calldata bytes per `aggregate3` call
+    pub fn with_max_calldata_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_calldata_bytes = max_bytes;
+        self
+    }
+
+    /// Split batches so that `gas_per_call` for each call fits in `batch_gas`
+    ///
+    /// Useful for nodes that cap `eth_call` gas.
+    pub fn with_gas_budget(mut self, batch_gas: u64, gas_per_call: u64) -> Self {
+        self.gas_budget = Some((batch_gas, gas_per_call.max(1)));
+        self
+    }
+
+    /// Queue a call, returning its index in the results
+    ///
+    /// If `allow_failure` is false a revert fails the whole batch.
+    pub fn add(&mut self, target: EthAddress, calldata: Vec<u8>, allow_failure: bool) -> usize {
+        self.calls.push(IMulticall3::Call3 {
+            target,
+            allowFailure: allow_failure,
+            callData: calldata.into(),
+        });
+        self.calls.len() - 1
+    }
+
+    /// Queue a typed call, returning its index in the results
+    pub fn add_call<C: SolCall>(
+        &mut self,
+        target: EthAddress,
+        call: &C,
+        allow_failure: bool,
+    ) -> usize {
+        self.add(target, call.abi_encode(), allow_failure)
+    }
+
+    /// Number of queued calls
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether no calls are queued
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Execute all queued calls and return their results in queue order
+    ///
+    /// The queue is emptied on success, so the aggregator can be reused; on
+    /// error the calls stay queued.
+    pub async fn execute(&mut self) -> Result<Vec<MulticallResult>, Error> {
+        let calls = std::mem::take(&mut self.calls);
+        let results = self.execute_calls(&calls).await;
+        if results.is_err() {
+            self.calls = calls;
+        }
+        results
+    }
+
+    async fn execute_calls(
+        &self,
+        calls: &[IMulticall3::Call3],
+    ) -> Result<Vec<MulticallResult>, Error> {
+        let mut results = Vec::with_capacity(calls.len());
+
+        for batch in self.batches(calls) {
+            tracing::debug!("Sending aggregate3 with {} calls", batch.len());
+            let data = IMulticall3::aggregate3Call {
+                calls: batch.to_vec(),
+            }
+            .abi_encode();
+
+            let output = self.contract.call_view(data).await?;
+            let batch_results =
+                IMulticall3::aggregate3Call::abi_decode_returns(&output).map_err(|e| {
+                    Error::Contract(format!("Failed to decode aggregate3 response: {}", e))
+                })?;
+            if batch_results.len() != batch.len() {
+                return Err(Error::Contract(format!(
+                    "aggregate3 returned {} results for {} calls",
+                    batch_results.len(),
+                    batch.len()
+                )));
+            }
+
+            results.extend(batch_results.into_iter().map(|result| MulticallResult {
+                success: result.success,
+                return_data: result.returnData,
+            }));
+        }
+
+        Ok(results)
+    }
+
+    /// Split calls into batches within the call, calldata and gas limits
+    fn batches<'a>(&self, calls: &'a [IMulticall3::Call3]) -> Vec<&'a [IMulticall3::Call3]> {
+        let max_calls = match self.gas_budget {
+            Some((batch_gas, gas_per_call)) => self
+                .max_calls
+                .min((batch_gas / gas_per_call).max(1) as usize),
+            None => self.max_calls,
+        };
+
+        let mut batches = Vec::new();
+        let mut start = 0;
+        let mut calldata_bytes = 0;
+        for (i, call) in calls.iter().enumerate() {
+            let size = call.callData.len();
+            let full = i - start == max_calls
+                || (i > start && calldata_bytes + size > self.max_calldata_bytes);
+            if full {
+                batches.push(&calls[start..i]);
+                start = i;
+                calldata_bytes = 0;
+            }
+            calldata_bytes += size;
+        }
+        if start < calls.len() {
+            batches.push(&calls[start..]);
+        }
+
+        batches
+    }
+}
@@ -1,8 +1,10 @@
 //! EVM Provider implementation
 
+use crate::rpc_batch::{BatchConfig, RpcBatchLayer};
 use crate::{AlloyHttpProvider, Error};
 use alloy::primitives::Address as EthAddress;
 use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::client::{BatchRequest, ClientBuilder};
 use apex_sdk_core::{BlockInfo, Provider as CoreProvider, SdkError};
 use apex_sdk_types::Address;
 use async_trait::async_trait;
@@ -19,12 +21,24 @@ pub struct EvmProvider {
 impl EvmProvider {
     /// Create a new EVM provider
     pub async fn new(rpc_url: &str) -> Result<Self, Error> {
-        let provider = ProviderBuilder::new().connect_http(
-            rpc_url
-                .parse()
-                .map_err(|e| Error::Connection(format!("Invalid URL: {}", e)))?,
-        );
+        let provider = ProviderBuilder::new().connect_http(parse_url(rpc_url)?);
+        Self::from_provider(provider, rpc_url).await
+    }
+
+    /// Create a new EVM provider that batches concurrent requests
+    ///
+    /// Requests issued within the configured window, such as concurrent
+    /// `get_balance` and `get_transaction_count` calls or receipt polling, are
+    /// sent as a single JSON-RPC batch. Errors are reported per call.
+    pub async fn new_with_batching(rpc_url: &str, config: BatchConfig) -> Result<Self, Error> {
+        let client = ClientBuilder::default()
+            .layer(RpcBatchLayer::new(config))
+            .http(parse_url(rpc_url)?);
+        let provider = ProviderBuilder::new().connect_client(client);
+        Self::from_provider(provider, rpc_url).await
+    }
 
+    async fn from_provider(provider: AlloyHttpProvider, rpc_url: &str) -> Result<Self, Error> {
         let chain_id = provider
             .get_chain_id()
             .await
@@ -47,6 +61,13 @@ impl EvmProvider {
         &self.rpc_url
     }
 
+    /// Start an explicit JSON-RPC batch
+    ///
+    /// Calls added to the batch are sent in one request when it is sent.
+    pub fn new_batch(&self) -> BatchRequest<'_> {
+        BatchRequest::new(self.provider.client())
+    }
+
     /// Convert Address to EthAddress
     fn to_eth_address(&self, address: &Address) -> Result<EthAddress, Error> {
         let addr_str = address.to_string();
@@ -55,6 +76,12 @@ impl EvmProvider {
     }
 }
 
+fn parse_url(rpc_url: &str) -> Result<url::Url, Error> {
+    rpc_url
+        .parse()
+        .map_err(|e| Error::Connection(format!("Invalid URL: {}", e)))
+}
+
 #[async_trait]
 impl CoreProvider for EvmProvider {
     async fn get_block_number(&self) -> Result<u64, SdkError> {
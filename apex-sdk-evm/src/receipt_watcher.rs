@@ -1,20 +1,44 @@
 //! EVM Receipt Watcher implementation
+//!
+//! Receipts are re-fetched on every poll and only counted while the block
+//! they reference is still canonical. A transaction that moves to another
+//! block or disappears is reported as [`TxStatus::Reorged`]. Finality uses the
+//! node's `finalized` and `safe` block tags, falling back to confirmation
+//! counts on nodes that do not support them.
 
 use crate::{AlloyHttpProvider, Error};
+use alloy::eips::BlockNumberOrTag;
 use alloy::primitives::B256;
 use alloy::providers::Provider;
+use alloy::rpc::types::TransactionReceipt;
+use alloy::transports::RpcError;
 use apex_sdk_core::{ConfirmationStrategy, ReceiptWatcher as CoreReceiptWatcher, SdkError};
 use apex_sdk_types::{TransactionStatus, TxStatus};
 use async_trait::async_trait;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::{sleep, timeout};
+use tracing::{debug, warn};
+
+/// Confirmations treated as final when the node has no `finalized` tag
+const FALLBACK_FINALITY_CONFIRMATIONS: u64 = 12;
+
+/// What a receipt watch waits for
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Confirmations(u64),
+    Finalized,
+}
 
 /// EVM receipt watcher implementation
 #[derive(Debug, Clone)]
 pub struct EvmReceiptWatcher {
     provider: AlloyHttpProvider,
     polling_interval: Duration,
+    /// Set once the node rejects the `finalized`/`safe` block tags
+    tags_unsupported: Arc<AtomicBool>,
 }
 
 impl EvmReceiptWatcher {
@@ -23,6 +47,7 @@ impl EvmReceiptWatcher {
         Self {
             provider,
             polling_interval: Duration::from_millis(1000), // 1 second polling
+            tags_unsupported: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -32,6 +57,49 @@ impl EvmReceiptWatcher {
         self
     }
 
+    /// Watch a transaction until the strategy is satisfied
+    ///
+    /// `on_status` is called whenever the observed status or inclusion block
+    /// changes, including a [`TxStatus::Reorged`] status when the transaction
+    /// leaves the block it was seen in. Confirmations restart from the new
+    /// block if the transaction is re-included. If the timeout expires while
+    /// the transaction is reorged out, the reorged status is returned instead
+    /// of a timeout error.
+    pub async fn watch_receipt<F>(
+        &self,
+        tx_hash: &str,
+        strategy: &ConfirmationStrategy,
+        mut on_status: F,
+    ) -> Result<TransactionStatus, Error>
+    where
+        F: FnMut(&TransactionStatus),
+    {
+        match strategy {
+            ConfirmationStrategy::BlockConfirmations {
+                confirmations,
+                timeout_secs,
+            } => {
+                self.track(
+                    tx_hash,
+                    Target::Confirmations(*confirmations as u64),
+                    *timeout_secs,
+                    &mut on_status,
+                )
+                .await
+            }
+            ConfirmationStrategy::Finalized { timeout_secs } => {
+                self.track(tx_hash, Target::Finalized, *timeout_secs, &mut on_status)
+                    .await
+            }
+            ConfirmationStrategy::Immediate => {
+                // Return immediately with pending status
+                let status = TransactionStatus::pending(tx_hash.to_string());
+                on_status(&status);
+                Ok(status)
+            }
+        }
+    }
+
     /// Convert hex string to B256
     fn parse_tx_hash(&self, tx_hash: &str) -> Result<B256, Error> {
         let hash_str = tx_hash.strip_prefix("0x").unwrap_or(tx_hash);
@@ -47,69 +115,210 @@ impl EvmReceiptWatcher {
             .map_err(|e| Error::Connection(format!("Failed to get block number: {}", e)))
     }
 
-    /// Wait for transaction receipt with block confirmations
-    async fn wait_for_confirmations(
+    /// Get the block number for the `finalized` or `safe` tag
+    ///
+    /// Returns `None` if the node does not support the tag. Only a JSON-RPC
+    /// error response marks the tags as unsupported; transport failures are
+    /// returned as errors, which [`track`](Self::track) retries on its next
+    /// poll.
+    async fn get_tagged_block(&self, tag: BlockNumberOrTag) -> Result<Option<u64>, Error> {
+        if self.tags_unsupported.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        match self.provider.get_block_by_number(tag).await {
+            Ok(block) => Ok(block.map(|block| block.header.number)),
+            Err(RpcError::ErrorResp(payload)) => {
+                debug!("Node does not support the {} block tag: {}", tag, payload);
+                self.tags_unsupported.store(true, Ordering::Relaxed);
+                Ok(None)
+            }
+            Err(e) => Err(Error::Connection(format!(
+                "Failed to get {} block: {}",
+                tag, e
+            ))),
+        }
+    }
+
+    /// Fetch the receipt if it references a canonical block
+    ///
+    /// Receipts from blocks that have been replaced by a reorg are treated as
+    /// missing.
+    async fn get_canonical_receipt(&self, hash: B256) -> Result<Option<TransactionReceipt>, Error> {
+        let Some(receipt) = self
+            .provider
+            .get_transaction_receipt(hash)
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get transaction receipt: {}", e)))?
+        else {
+            return Ok(None);
+        };
+
+        let (Some(block_number), Some(block_hash)) = (receipt.block_number, receipt.block_hash)
+        else {
+            return Ok(None);
+        };
+
+        let canonical = self
+            .provider
+            .get_block_by_number(block_number.into())
+            .await
+            .map_err(|e| {
+                Error::Connection(format!("Failed to get block {}: {}", block_number, e))
+            })?;
+
+        match canonical {
+            Some(block) if block.header.hash == block_hash => Ok(Some(receipt)),
+            _ => {
+                debug!(
+                    "Receipt for {} references non-canonical block {}",
+                    hash, block_hash
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Get the current block and, when waiting for finality, the `finalized` block
+    async fn chain_position(&self, target: Target) -> Result<(u64, Option<u64>), Error> {
+        match target {
+            Target::Confirmations(_) => Ok((self.get_current_block().await?, None)),
+            Target::Finalized => {
+                // Issued together so a batching transport sends one request
+                let (current, finalized) = tokio::join!(
+                    self.get_current_block(),
+                    self.get_tagged_block(BlockNumberOrTag::Finalized)
+                );
+                Ok((current?, finalized?))
+            }
+        }
+    }
+
+    /// Poll the receipt until the target is reached
+    ///
+    /// Failed lookups are logged and retried on the next poll until the
+    /// timeout expires.
+    async fn track<F>(
         &self,
         tx_hash: &str,
-        confirmations: u32,
+        target: Target,
         timeout_secs: u64,
-    ) -> Result<TransactionStatus, Error> {
+        on_status: &mut F,
+    ) -> Result<TransactionStatus, Error>
+    where
+        F: FnMut(&TransactionStatus),
+    {
         let hash = self.parse_tx_hash(tx_hash)?;
         let timeout_duration = Duration::from_secs(timeout_secs);
 
-        timeout(timeout_duration, async {
+        let mut included_in: Option<(u64, B256)> = None;
+        let mut last_reported: Option<(TxStatus, Option<u64>)> = None;
+        let mut reorged: Option<TransactionStatus> = None;
+
+        let mut report = |status: &TransactionStatus| {
+            let key = (status.status.clone(), status.block_number);
+            if last_reported.as_ref() != Some(&key) {
+                on_status(status);
+                last_reported = Some(key);
+            }
+        };
+
+        let result = timeout(timeout_duration, async {
             loop {
-                // Check if transaction is in a block
-                if let Some(receipt) = self
-                    .provider
-                    .get_transaction_receipt(hash)
-                    .await
-                    .ok()
-                    .flatten()
-                {
-                    let current_block = self.get_current_block().await?;
+                let receipt = match self.get_canonical_receipt(hash).await {
+                    Ok(receipt) => receipt,
+                    Err(e) => {
+                        warn!("Receipt lookup for {} failed, retrying: {}", tx_hash, e);
+                        sleep(self.polling_interval).await;
+                        continue;
+                    }
+                };
+                let inclusion = receipt
+                    .as_ref()
+                    .and_then(|r| r.block_number.zip(r.block_hash));
+
+                if let Some((number, block_hash)) = included_in {
+                    if inclusion != Some((number, block_hash)) {
+                        warn!(
+                            "Transaction {} was removed from block {} by a reorg",
+                            tx_hash, number
+                        );
+                        let status = TransactionStatus::reorged(
+                            tx_hash.to_string(),
+                            number,
+                            format!("0x{:x}", block_hash),
+                        );
+                        report(&status);
+                        reorged = Some(status);
+                    }
+                }
+                included_in = inclusion;
+
+                if let Some(receipt) = receipt {
+                    let (current_block, finalized) = match self.chain_position(target).await {
+                        Ok(position) => position,
+                        Err(e) => {
+                            warn!("Block lookup for {} failed, retrying: {}", tx_hash, e);
+                            sleep(self.polling_interval).await;
+                            continue;
+                        }
+                    };
                     let tx_block = receipt.block_number.unwrap_or_default();
                     let current_confirmations = current_block.saturating_sub(tx_block);
 
-                    if current_confirmations >= confirmations as u64 {
-                        let status = if receipt.status() {
-                            TxStatus::Confirmed
-                        } else {
-                            TxStatus::Failed
-                        };
-
-                        return Ok(TransactionStatus {
-                            hash: tx_hash.to_string(),
-                            status,
-                            block_number: Some(tx_block),
-                            block_hash: Some(format!(
-                                "0x{:x}",
-                                receipt.block_hash.unwrap_or_default()
-                            )),
-                            gas_used: Some(receipt.gas_used),
-                            effective_gas_price: Some(receipt.effective_gas_price),
-                            confirmations: Some(current_confirmations as u32),
-                            error: None,
-                        });
+                    let reached = match (target, finalized) {
+                        (Target::Confirmations(required), _) => current_confirmations >= required,
+                        (Target::Finalized, Some(finalized)) => finalized >= tx_block,
+                        (Target::Finalized, None) => {
+                            current_confirmations >= FALLBACK_FINALITY_CONFIRMATIONS
+                        }
+                    };
+
+                    let status = match (reached, receipt.status(), target) {
+                        (true, false, _) => TxStatus::Failed,
+                        (true, true, Target::Finalized) => TxStatus::Finalized,
+                        (true, true, Target::Confirmations(_)) => TxStatus::Confirmed,
+                        (false, _, _) => TxStatus::Pending,
+                    };
+
+                    let status = receipt_status(tx_hash, &receipt, status, current_confirmations);
+                    report(&status);
+                    if reached {
+                        return Ok(status);
                     }
                 }
 
                 sleep(self.polling_interval).await;
             }
         })
-        .await
-        .map_err(|_| Error::Transaction("Transaction confirmation timeout".to_string()))?
+        .await;
+
+        match result {
+            Ok(result) => result,
+            // Still reorged out when the time ran out
+            Err(_) => reorged
+                .filter(|_| included_in.is_none())
+                .ok_or_else(|| Error::Transaction("Transaction confirmation timeout".to_string())),
+        }
     }
+}
 
-    /// Wait for transaction to be finalized (same as confirmed for EVM)
-    async fn wait_for_finalization(
-        &self,
-        tx_hash: &str,
-        timeout_secs: u64,
-    ) -> Result<TransactionStatus, Error> {
-        // For EVM, finalization is typically achieved after a certain number of confirmations
-        // We'll use a higher confirmation count to simulate finalization
-        self.wait_for_confirmations(tx_hash, 12, timeout_secs).await
+/// Build a transaction status from a receipt
+fn receipt_status(
+    tx_hash: &str,
+    receipt: &TransactionReceipt,
+    status: TxStatus,
+    confirmations: u64,
+) -> TransactionStatus {
+    TransactionStatus {
+        hash: tx_hash.to_string(),
+        status,
+        block_number: receipt.block_number,
+        block_hash: Some(format!("0x{:x}", receipt.block_hash.unwrap_or_default())),
+        gas_used: Some(receipt.gas_used),
+        effective_gas_price: Some(receipt.effective_gas_price),
+        confirmations: Some(confirmations as u32),
+        error: None,
     }
 }
 
@@ -125,23 +334,9 @@ impl CoreReceiptWatcher for EvmReceiptWatcher {
         tx_hash: &str,
         strategy: &ConfirmationStrategy,
     ) -> Result<TransactionStatus, SdkError> {
-        match strategy {
-            ConfirmationStrategy::BlockConfirmations {
-                confirmations,
-                timeout_secs,
-            } => self
-                .wait_for_confirmations(tx_hash, *confirmations, *timeout_secs)
-                .await
-                .map_err(SdkError::from),
-            ConfirmationStrategy::Finalized { timeout_secs } => self
-                .wait_for_finalization(tx_hash, *timeout_secs)
-                .await
-                .map_err(SdkError::from),
-            ConfirmationStrategy::Immediate => {
-                // Return immediately with pending status
-                Ok(TransactionStatus::pending(tx_hash.to_string()))
-            }
-        }
+        self.watch_receipt(tx_hash, strategy, |_| {})
+            .await
+            .map_err(SdkError::from)
     }
 
     async fn get_receipt_status(
@@ -150,16 +345,19 @@ impl CoreReceiptWatcher for EvmReceiptWatcher {
     ) -> Result<Option<TransactionStatus>, SdkError> {
         let hash = self.parse_tx_hash(tx_hash)?;
 
-        match self.provider.get_transaction_receipt(hash).await {
-            Ok(Some(receipt)) => {
+        match self.get_canonical_receipt(hash).await? {
+            Some(receipt) => {
                 let current_block = self.get_current_block().await?;
                 let tx_block = receipt.block_number.unwrap_or_default();
                 let confirmations = current_block.saturating_sub(tx_block);
 
                 let status = if receipt.status() {
-                    if confirmations >= 100 {
+                    let finalized = self.get_tagged_block(BlockNumberOrTag::Finalized).await?;
+                    let safe = self.get_tagged_block(BlockNumberOrTag::Safe).await?;
+
+                    if finalized.map_or(confirmations >= 100, |n| n >= tx_block) {
                         TxStatus::Finalized
-                    } else if confirmations >= 2 {
+                    } else if safe.map_or(confirmations >= 2, |n| n >= tx_block) {
                         TxStatus::Confirmed
                     } else {
                         TxStatus::Pending
@@ -168,18 +366,14 @@ impl CoreReceiptWatcher for EvmReceiptWatcher {
                     TxStatus::Failed
                 };
 
-                Ok(Some(TransactionStatus {
-                    hash: tx_hash.to_string(),
+                Ok(Some(receipt_status(
+                    tx_hash,
+                    &receipt,
                     status,
-                    block_number: Some(tx_block),
-                    block_hash: Some(format!("0x{:x}", receipt.block_hash.unwrap_or_default())),
-                    gas_used: Some(receipt.gas_used),
-                    effective_gas_price: Some(receipt.effective_gas_price),
-                    confirmations: Some(confirmations as u32),
-                    error: None,
-                }))
+                    confirmations,
+                )))
             }
-            Ok(None) => {
+            None => {
                 // Transaction receipt not found - check if it's in mempool
                 match self.provider.get_transaction_by_hash(hash).await {
                     Ok(Some(_tx)) => {
@@ -192,10 +386,6 @@ impl CoreReceiptWatcher for EvmReceiptWatcher {
                     }
                 }
             }
-            Err(e) => {
-                // Error querying provider
-                Err(Error::Connection(format!("Failed to get transaction receipt: {}", e)).into())
-            }
         }
     }
 }
@@ -0,0 +1,306 @@
+//! JSON-RPC 2.0 request batching for EVM providers
+//!
+//! [`RpcBatchLayer`] is a transport layer that collects the single requests
+//! issued within a short window and sends them as one batch request, routing
+//! each response back to its caller by request ID:
+//! - Errors are per call; one failing call does not fail the others
+//! - A failed batch fails every call in it with the same error kind, so
+//!   retry layers above still see HTTP statuses and error responses
+//! - Batches are capped at a configurable size per endpoint
+//! - Requests that are already batches (see
+//!   [`EvmProvider::new_batch`](crate::EvmProvider::new_batch)) pass through
+//!
+//! Use it through [`EvmProvider::new_with_batching`](crate::EvmProvider::new_with_batching)
+//! or [`EvmAdapter::connect_with_batching`](crate::EvmAdapter::connect_with_batching).
+
+use alloy::rpc::json_rpc::{
+    Id, RequestPacket, Response, ResponsePacket, RpcError, SerializedRequest,
+};
+use alloy::transports::{TransportError, TransportErrorKind, TransportFut};
+use std::collections::HashMap;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tower::{Layer, Service, ServiceExt};
+
+/// Default maximum number of requests per batch
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 50;
+
+/// Default time to wait for more requests before sending a batch
+pub const DEFAULT_BATCH_WINDOW: Duration = Duration::from_millis(5);
+
+/// Configuration for JSON-RPC request batching
+#[derive(Debug, Clone)]
+pub struct BatchConfig {
+    /// Maximum number of requests per batch; many providers reject larger batches
+    pub max_batch_size: usize,
+    /// Time to wait for more requests after the first one arrives
+    pub window: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            window: DEFAULT_BATCH_WINDOW,
+        }
+    }
+}
+
+impl BatchConfig {
+    /// Set the maximum number of requests per batch
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size.max(1);
+        self
+    }
+
+    /// Set the batching window
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+}
+
+/// A queued request and the channel its response goes back on
+type Pending = (
+    SerializedRequest,
+    oneshot::Sender<Result<Response, TransportError>>,
+);
+
+/// Transport layer that batches concurrent JSON-RPC requests
+///
+/// Must be applied within a Tokio runtime, which runs the batching task.
+#[derive(Debug, Clone, Default)]
+pub struct RpcBatchLayer {
+    config: BatchConfig,
+}
+
+impl RpcBatchLayer {
+    /// Create a batching layer
+    pub fn new(config: BatchConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S> Layer<S> for RpcBatchLayer
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    type Service = RpcBatchService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(collect(inner.clone(), receiver, self.config.clone()));
+
+        RpcBatchService { inner, sender }
+    }
+}
+
+/// Transport service created by [`RpcBatchLayer`]
+#[derive(Debug, Clone)]
+pub struct RpcBatchService<S> {
+    inner: S,
+    sender: mpsc::UnboundedSender<Pending>,
+}
+
+impl<S> Service<RequestPacket> for RpcBatchService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let request = match request {
+            RequestPacket::Single(request) => request,
+            batch => return self.inner.call(batch),
+        };
+
+        let (sender, receiver) = oneshot::channel();
+        let queued = self.sender.send((request, sender)).is_ok();
+
+        Box::pin(async move {
+            if !queued {
+                return Err(TransportErrorKind::backend_gone());
+            }
+            let response = receiver
+                .await
+                .map_err(|_| TransportErrorKind::backend_gone())??;
+            Ok(ResponsePacket::Single(response))
+        })
+    }
+}
+
+/// Group queued requests into batches and dispatch them
+async fn collect<S>(inner: S, mut receiver: mpsc::UnboundedReceiver<Pending>, config: BatchConfig)
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    while let Some(first) = receiver.recv().await {
+        let mut pending = vec![first];
+
+        let window = tokio::time::sleep(config.window);
+        tokio::pin!(window);
+        while pending.len() < config.max_batch_size {
+            tokio::select! {
+                _ = &mut window => break,
+                next = receiver.recv() => match next {
+                    Some(request) => pending.push(request),
+                    None => break,
+                },
+            }
+        }
+
+        tokio::spawn(dispatch(inner.clone(), pending));
+    }
+}
+
+/// Send one batch and route the responses back to their callers
+async fn dispatch<S>(mut inner: S, pending: Vec<Pending>)
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Send,
+{
+    let (requests, senders): (Vec<_>, Vec<_>) = pending.into_iter().unzip();
+    let ids: Vec<Id> = requests
+        .iter()
+        .map(|request| request.id().clone())
+        .collect();
+
+    // A lone request goes out as is
+    let packet = match <[SerializedRequest; 1]>::try_from(requests) {
+        Ok([request]) => RequestPacket::Single(request),
+        Err(requests) => {
+            tracing::debug!("Sending JSON-RPC batch of {} requests", requests.len());
+            RequestPacket::Batch(requests)
+        }
+    };
+
+    let result = match inner.ready().await {
+        Ok(service) => service.call(packet).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(packet) => {
+            let mut responses: HashMap<Id, Response> = match packet {
+                ResponsePacket::Single(response) => vec![response],
+                ResponsePacket::Batch(responses) => responses,
+            }
+            .into_iter()
+            .map(|response| (response.id.clone(), response))
+            .collect();
+
+            for (id, sender) in ids.into_iter().zip(senders) {
+                let response = responses.remove(&id).ok_or_else(|| {
+                    TransportErrorKind::custom_str(&format!(
+                        "No response for request {} in batch",
+                        id
+                    ))
+                });
+                let _ = sender.send(response);
+            }
+        }
+        Err(e) => {
+            tracing::debug!("Batch request failed: {}", e);
+            let mut senders = senders.into_iter();
+            let first = senders.next();
+            for sender in senders {
+                let _ = sender.send(Err(copy_error(&e)));
+            }
+            if let Some(sender) = first {
+                let _ = sender.send(Err(e));
+            }
+        }
+    }
+}
+
+/// Copy a transport error, keeping its kind
+///
+/// Errors that carry an opaque source keep only its message.
+fn copy_error(error: &TransportError) -> TransportError {
+    let json_error = |message: String| serde_json::Error::io(std::io::Error::other(message));
+
+    match error {
+        RpcError::ErrorResp(payload) => RpcError::ErrorResp(payload.clone()),
+        RpcError::NullResp => RpcError::NullResp,
+        RpcError::UnsupportedFeature(feature) => RpcError::UnsupportedFeature(feature),
+        RpcError::LocalUsageError(e) => RpcError::LocalUsageError(e.to_string().into()),
+        RpcError::SerError(e) => RpcError::SerError(json_error(e.to_string())),
+        RpcError::DeserError { err, text } => RpcError::DeserError {
+            err: json_error(err.to_string()),
+            text: text.clone(),
+        },
+        RpcError::Transport(kind) => match kind {
+            TransportErrorKind::MissingBatchResponse(id) => {
+                TransportErrorKind::missing_batch_response(id.clone())
+            }
+            TransportErrorKind::BackendGone => TransportErrorKind::backend_gone(),
+            TransportErrorKind::PubsubUnavailable => TransportErrorKind::pubsub_unavailable(),
+            TransportErrorKind::HttpError(e) => {
+                TransportErrorKind::http_error(e.status, e.body.clone())
+            }
+            other => TransportErrorKind::custom_str(&other.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_error_keeps_kind() {
+        let copied = copy_error(&TransportErrorKind::http_error(
+            429,
+            "slow down".to_string(),
+        ));
+        assert!(matches!(
+            copied,
+            RpcError::Transport(TransportErrorKind::HttpError(ref e))
+                if e.status == 429 && e.body == "slow down"
+        ));
+
+        let copied = copy_error(&TransportErrorKind::backend_gone());
+        assert!(matches!(
+            copied,
+            RpcError::Transport(TransportErrorKind::BackendGone)
+        ));
+
+        let copied = copy_error(&TransportErrorKind::custom_str("boom"));
+        assert_eq!(copied.to_string(), "boom");
+    }
+}
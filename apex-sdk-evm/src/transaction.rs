@@ -9,7 +9,7 @@
 use crate::{wallet::Wallet, Error, ProviderType};
 use alloy::consensus::SignableTransaction;
 use alloy::network::TransactionBuilder;
-use alloy::primitives::{Address as EthAddress, Bytes, B256, U256};
+use alloy::primitives::{Address as EthAddress, Bytes, TxKind, B256, U256};
 use alloy::providers::Provider;
 use alloy::rpc::types::{Block, BlockNumberOrTag, TransactionReceipt, TransactionRequest};
 use alloy_eips::eip2718::Encodable2718;
@@ -17,6 +17,9 @@ use apex_sdk_core::{FeeEstimator, SdkError};
 use async_trait::async_trait;
 use std::time::Duration;
 
+/// Interval between receipt polls in [`TransactionExecutor::wait_for_receipt`]
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Configuration for gas estimation and pricing
 #[derive(Debug, Clone)]
 pub struct GasConfig {
@@ -266,13 +269,38 @@ impl TransactionExecutor {
         value: U256,
         data: Option<Vec<u8>>,
         gas_estimate: Option<GasEstimate>,
+    ) -> Result<TransactionRequest, Error> {
+        self.build(wallet, Some(to), value, data, gas_estimate)
+            .await
+    }
+
+    /// Build a contract creation transaction
+    pub async fn build_deploy_transaction(
+        &self,
+        wallet: &Wallet,
+        value: U256,
+        init_code: Vec<u8>,
+        gas_estimate: Option<GasEstimate>,
+    ) -> Result<TransactionRequest, Error> {
+        self.build(wallet, None, value, Some(init_code), gas_estimate)
+            .await
+    }
+
+    /// Build a call (`to` set) or contract creation (`to` unset) transaction
+    async fn build(
+        &self,
+        wallet: &Wallet,
+        to: Option<EthAddress>,
+        value: U256,
+        data: Option<Vec<u8>>,
+        gas_estimate: Option<GasEstimate>,
     ) -> Result<TransactionRequest, Error> {
         let from = wallet.eth_address();
 
         let gas_est = if let Some(est) = gas_estimate {
             est
         } else {
-            self.estimate_gas(from, Some(to), Some(value), data.clone())
+            self.estimate_gas(from, to, Some(value), data.clone())
                 .await?
         };
 
@@ -280,7 +308,7 @@ impl TransactionExecutor {
 
         let mut tx = TransactionRequest::default()
             .with_from(from)
-            .with_to(to)
+            .with_kind(to.map_or(TxKind::Create, TxKind::Call))
             .with_value(value)
             .with_gas_limit(gas_est.gas_limit.to::<u64>())
             .with_nonce(nonce.to::<u64>());
@@ -339,6 +367,20 @@ impl TransactionExecutor {
         self.send_raw_transaction(wallet, tx).await
     }
 
+    /// Send a signed contract creation transaction with retry logic
+    pub async fn send_deploy_transaction(
+        &self,
+        wallet: &Wallet,
+        value: U256,
+        init_code: Vec<u8>,
+    ) -> Result<B256, Error> {
+        let tx = self
+            .build_deploy_transaction(wallet, value, init_code, None)
+            .await?;
+
+        self.send_raw_transaction(wallet, tx).await
+    }
+
     /// Send a pre-built transaction with retry logic
     pub async fn send_raw_transaction(
         &self,
@@ -433,7 +475,7 @@ impl TransactionExecutor {
         tx_hash: B256,
         _confirmations: usize,
     ) -> Result<Option<TransactionReceipt>, Error> {
-        tracing::info!("Waiting for transaction confirmation: {:?}", tx_hash);
+        tracing::debug!("Waiting for transaction confirmation: {:?}", tx_hash);
 
         let receipt = self
             .provider
@@ -452,6 +494,34 @@ impl TransactionExecutor {
 
         Ok(receipt)
     }
+
+    /// Poll for a transaction receipt until it is available or `timeout` elapses
+    ///
+    /// Failed lookups are logged and retried on the next poll.
+    pub async fn wait_for_receipt(
+        &self,
+        tx_hash: B256,
+        timeout: Duration,
+    ) -> Result<TransactionReceipt, Error> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            match self.wait_for_confirmation(tx_hash, 1).await {
+                Ok(Some(receipt)) => return Ok(receipt),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!("Receipt lookup for {:?} failed, retrying: {}", tx_hash, e)
+                }
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::Transaction(format!(
+                    "Timed out waiting for receipt of {:?}",
+                    tx_hash
+                )));
+            }
+            tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+        }
+    }
 }
 
 #[async_trait]
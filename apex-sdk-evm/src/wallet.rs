@@ -7,8 +7,10 @@
 //! - Message signing (EIP-191, EIP-712)
 
 use crate::Error;
+use alloy::dyn_abi::TypedData;
 use alloy::primitives::{Address as EthAddress, Signature, B256};
 use alloy::signers::Signer;
+use alloy::sol_types::{Eip712Domain, SolStruct};
 use alloy_signer_local::{coins_bip39::English, MnemonicBuilder, PrivateKeySigner};
 use apex_sdk_core::{SdkError, Signer as CoreSigner};
 use apex_sdk_types::Address;
@@ -196,6 +198,44 @@ impl Wallet {
         Ok(signature)
     }
 
+    /// Sign EIP-712 typed data, as in `eth_signTypedData_v4`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alloy::dyn_abi::TypedData;
+    /// use apex_sdk_evm::wallet::{recover_typed_data_signer, Wallet};
+    ///
+    /// # async fn example(json: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// let wallet = Wallet::new_random();
+    /// let typed_data: TypedData = serde_json::from_str(json)?;
+    ///
+    /// let signature = wallet.sign_typed_data(&typed_data).await?;
+    /// assert_eq!(recover_typed_data_signer(&typed_data, &signature)?, wallet.eth_address());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, Error> {
+        let hash = typed_data_hash(typed_data)?;
+        self.sign_typed_data_hash(&hash).await
+    }
+
+    /// Sign EIP-712 typed data given as `eth_signTypedData_v4` JSON
+    pub async fn sign_typed_data_json(&self, json: &str) -> Result<Signature, Error> {
+        let typed_data: TypedData = serde_json::from_str(json)
+            .map_err(|e| Error::Other(format!("Invalid EIP-712 typed data: {}", e)))?;
+        self.sign_typed_data(&typed_data).await
+    }
+
+    /// Sign an EIP-712 struct under a domain
+    pub async fn sign_typed_struct<T: SolStruct + Sync>(
+        &self,
+        payload: &T,
+        domain: &Eip712Domain,
+    ) -> Result<Signature, Error> {
+        self.sign_typed_data_hash(&payload.eip712_signing_hash(domain))
+            .await
+    }
+
     /// Get the chain ID configured for this wallet
     pub fn chain_id(&self) -> Option<u64> {
         self.chain_id
@@ -251,6 +291,55 @@ impl CoreSigner for Wallet {
     }
 }
 
+/// Recover the address that signed EIP-712 typed data
+pub fn recover_typed_data_signer(
+    typed_data: &TypedData,
+    signature: &Signature,
+) -> Result<EthAddress, Error> {
+    recover_signer(&typed_data_hash(typed_data)?, signature)
+}
+
+/// Recover the address that signed an EIP-712 struct under a domain
+pub fn recover_typed_struct_signer<T: SolStruct>(
+    payload: &T,
+    domain: &Eip712Domain,
+    signature: &Signature,
+) -> Result<EthAddress, Error> {
+    recover_signer(&payload.eip712_signing_hash(domain), signature)
+}
+
+/// Check that EIP-712 typed data was signed by `signer`
+pub fn verify_typed_data(
+    typed_data: &TypedData,
+    signature: &Signature,
+    signer: EthAddress,
+) -> Result<bool, Error> {
+    Ok(recover_typed_data_signer(typed_data, signature)? == signer)
+}
+
+/// Check that an EIP-712 struct was signed by `signer` under a domain
+pub fn verify_typed_struct<T: SolStruct>(
+    payload: &T,
+    domain: &Eip712Domain,
+    signature: &Signature,
+    signer: EthAddress,
+) -> Result<bool, Error> {
+    Ok(recover_typed_struct_signer(payload, domain, signature)? == signer)
+}
+
+/// EIP-712 signing hash of typed data
+fn typed_data_hash(typed_data: &TypedData) -> Result<B256, Error> {
+    typed_data
+        .eip712_signing_hash()
+        .map_err(|e| Error::Other(format!("Failed to hash EIP-712 typed data: {}", e)))
+}
+
+fn recover_signer(hash: &B256, signature: &Signature) -> Result<EthAddress, Error> {
+    signature
+        .recover_address_from_prehash(hash)
+        .map_err(|e| Error::Other(format!("Failed to recover signer: {}", e)))
+}
+
 /// Wallet manager for handling multiple accounts
 pub struct WalletManager {
     wallets: Vec<Wallet>,
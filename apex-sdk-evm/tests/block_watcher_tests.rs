@@ -0,0 +1,136 @@
+//! Tests for the EVM block watcher with mocked RPC responses
+
+use apex_sdk_core::{BlockSubscriptionMode, NewHead};
+use apex_sdk_evm::EvmAdapter;
+use std::time::Duration;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer,
+};
+
+mod common;
+use common::{block_hash, block_json, mock_chain_id, rpc_result};
+
+async fn setup() -> MockServer {
+    let server = MockServer::start().await;
+    mock_chain_id(&server, 1).await;
+    server
+}
+
+async fn mock_block_by_hash(server: &MockServer, number: u64) {
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByHash"))
+        .and(body_string_contains(block_hash(number)))
+        .respond_with(rpc_result(block_json(number, &block_hash(number))))
+        .mount(server)
+        .await;
+}
+
+async fn collect_blocks(adapter: &EvmAdapter, count: usize) -> Vec<NewHead> {
+    let mut blocks = Vec::new();
+    tokio::time::timeout(
+        Duration::from_secs(10),
+        adapter
+            .block_watcher()
+            .with_polling_interval(Duration::from_millis(10))
+            .watch(BlockSubscriptionMode::Best, |head| {
+                blocks.push(head);
+                blocks.len() < count
+            }),
+    )
+    .await
+    .expect("block watcher timed out")
+    .unwrap();
+    blocks
+}
+
+#[tokio::test]
+async fn test_polling_backfills_skipped_blocks() {
+    let server = setup().await;
+
+    // The first poll sees block 5, later polls see block 8
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .respond_with(rpc_result(block_json(5, &block_hash(5))))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .respond_with(rpc_result(block_json(8, &block_hash(8))))
+        .with_priority(2)
+        .mount(&server)
+        .await;
+    mock_block_by_hash(&server, 6).await;
+    mock_block_by_hash(&server, 7).await;
+
+    let adapter = EvmAdapter::connect(&server.uri()).await.unwrap();
+    let blocks = collect_blocks(&adapter, 4).await;
+
+    let numbers: Vec<u64> = blocks.iter().map(|b| b.number).collect();
+    assert_eq!(numbers, vec![5, 6, 7, 8]);
+    assert!(blocks.iter().all(|b| !b.reorged));
+    assert_eq!(blocks[3].parent_hash, blocks[2].hash);
+    assert_eq!(blocks[0].timestamp, Some(1_700_000_060));
+}
+
+#[tokio::test]
+async fn test_unreachable_ws_falls_back_to_polling() {
+    let server = setup().await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .respond_with(rpc_result(block_json(42, &block_hash(42))))
+        .mount(&server)
+        .await;
+
+    let adapter = EvmAdapter::connect(&server.uri())
+        .await
+        .unwrap()
+        .with_ws_endpoint("ws://127.0.0.1:1");
+    let blocks = collect_blocks(&adapter, 1).await;
+
+    assert_eq!(blocks[0].number, 42);
+    assert_eq!(blocks[0].hash, block_hash(42));
+}
+
+#[tokio::test]
+async fn test_polling_reports_reorged_blocks() {
+    let server = setup().await;
+
+    let fork = block_json(6, &format!("0x{:064x}", 0xf006));
+
+    for (priority, block) in [
+        (1, block_json(5, &block_hash(5))),
+        (2, block_json(6, &block_hash(6))),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_string_contains("eth_getBlockByNumber"))
+            .respond_with(rpc_result(block))
+            .up_to_n_times(1)
+            .with_priority(priority)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .respond_with(rpc_result(fork))
+        .with_priority(3)
+        .mount(&server)
+        .await;
+
+    let adapter = EvmAdapter::connect(&server.uri()).await.unwrap();
+    let blocks = collect_blocks(&adapter, 3).await;
+
+    let numbers: Vec<u64> = blocks.iter().map(|b| b.number).collect();
+    assert_eq!(numbers, vec![5, 6, 6]);
+    assert!(!blocks[1].reorged);
+    assert!(blocks[2].reorged);
+    assert_ne!(blocks[1].hash, blocks[2].hash);
+}
@@ -0,0 +1,67 @@
+//! Shared fixtures for the mocked RPC integration tests
+//!
+//! Each test binary compiles this module separately and uses a subset of it.
+#![allow(dead_code)]
+
+use apex_sdk_evm::EvmAdapter;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Wrap `result` in a successful JSON-RPC response
+pub fn rpc_result(result: serde_json::Value) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": result
+    }))
+}
+
+/// Answer `eth_chainId` with `chain_id`
+pub async fn mock_chain_id(server: &MockServer, chain_id: u64) {
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_chainId"))
+        .respond_with(rpc_result(serde_json::json!(format!("0x{:x}", chain_id))))
+        .mount(server)
+        .await;
+}
+
+/// Connect an adapter to `server`, which reports `chain_id`
+pub async fn connect(server: &MockServer, chain_id: u64) -> EvmAdapter {
+    mock_chain_id(server, chain_id).await;
+    EvmAdapter::connect(&server.uri()).await.unwrap()
+}
+
+/// Deterministic hash of the canonical block `number`
+pub fn block_hash(number: u64) -> String {
+    format!("0x{:064x}", 0xb000 + number)
+}
+
+/// JSON-RPC block `number` with the given hash, whose parent is the canonical
+/// block before it
+pub fn block_json(number: u64, hash: &str) -> serde_json::Value {
+    serde_json::json!({
+        "hash": hash,
+        "parentHash": block_hash(number - 1),
+        "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+        "miner": "0x0000000000000000000000000000000000000000",
+        "stateRoot": format!("0x{:064x}", 1),
+        "transactionsRoot": format!("0x{:064x}", 2),
+        "receiptsRoot": format!("0x{:064x}", 3),
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "difficulty": "0x0",
+        "number": format!("0x{:x}", number),
+        "gasLimit": "0x1c9c380",
+        "gasUsed": "0x0",
+        "timestamp": format!("0x{:x}", 1_700_000_000 + number * 12),
+        "extraData": "0x",
+        "mixHash": format!("0x{:064x}", 0),
+        "nonce": "0x0000000000000000",
+        "baseFeePerGas": "0x7",
+        "uncles": [],
+        "transactions": [],
+        "size": "0x200"
+    })
+}
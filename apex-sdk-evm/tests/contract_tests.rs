@@ -0,0 +1,318 @@
+//! Tests for the ABI-driven contract client with mocked RPC responses
+
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::{address, keccak256, Address, LogData, B256, U256};
+use alloy::sol_types::{Revert, SolError};
+use apex_sdk_evm::contract::{
+    create2_address, create_address, parse_abi, Contract, ContractDeployer, RevertReason,
+    DETERMINISTIC_DEPLOYER,
+};
+use apex_sdk_evm::Error;
+use wiremock::{
+    matchers::{body_string_contains, method},
+    Mock, MockServer, ResponseTemplate,
+};
+
+mod common;
+use common::connect;
+
+const VAULT: Address = address!("5FbDB2315678afecb367f032d93F642f64180aa3");
+const ALICE: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+const VAULT_ABI: &str = r#"[
+    {"type": "function", "name": "deposit", "stateMutability": "payable",
+     "inputs": [{"name": "amount", "type": "uint256"}],
+     "outputs": [{"name": "", "type": "bool"}]},
+    {"type": "function", "name": "balanceOf", "stateMutability": "view",
+     "inputs": [{"name": "account", "type": "address"}],
+     "outputs": [{"name": "", "type": "uint256"}]},
+    {"type": "function", "name": "get", "stateMutability": "view",
+     "inputs": [{"name": "id", "type": "uint256"}],
+     "outputs": [{"name": "", "type": "uint256"}]},
+    {"type": "function", "name": "get", "stateMutability": "view",
+     "inputs": [{"name": "account", "type": "address"}],
+     "outputs": [{"name": "", "type": "uint256"}]},
+    {"type": "error", "name": "InsufficientBalance",
+     "inputs": [{"name": "available", "type": "uint256"}, {"name": "required", "type": "uint256"}]},
+    {"type": "event", "name": "Deposited", "anonymous": false,
+     "inputs": [{"name": "from", "type": "address", "indexed": true},
+                {"name": "amount", "type": "uint256", "indexed": false}]}
+]"#;
+
+async fn vault(server: &MockServer) -> Contract {
+    Contract::from_abi_json(VAULT, connect(server, 31337).await, VAULT_ABI).unwrap()
+}
+
+async fn mock_eth_call(server: &MockServer, response: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .mount(server)
+        .await;
+}
+
+fn insufficient_balance(available: u64, required: u64) -> Vec<u8> {
+    let mut data = keccak256("InsufficientBalance(uint256,uint256)")[..4].to_vec();
+    data.extend_from_slice(&U256::from(available).to_be_bytes::<32>());
+    data.extend_from_slice(&U256::from(required).to_be_bytes::<32>());
+    data
+}
+
+#[test]
+fn test_parse_abi_from_artifact() {
+    let artifact = format!(r#"{{"contractName": "Vault", "abi": {}}}"#, VAULT_ABI);
+    let abi = parse_abi(&artifact).unwrap();
+    assert_eq!(abi.functions().count(), 4);
+
+    assert!(parse_abi("{not json").is_err());
+}
+
+#[tokio::test]
+async fn test_encode_call() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+
+    let data = contract
+        .encode_call("balanceOf", &[DynSolValue::Address(ALICE)])
+        .unwrap();
+    assert_eq!(&data[..4], &keccak256("balanceOf(address)")[..4]);
+    assert_eq!(&data[16..], ALICE.as_slice());
+
+    let from_str = contract
+        .encode_call_str("balanceOf", &["0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"])
+        .unwrap();
+    assert_eq!(from_str, data);
+
+    assert!(contract.encode_call("withdraw", &[]).is_err());
+    assert!(contract
+        .encode_call("balanceOf", &[DynSolValue::Bool(true)])
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_overloaded_functions() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+
+    let result = contract.encode_call("get", &[DynSolValue::Uint(U256::from(1), 256)]);
+    assert!(matches!(result, Err(Error::Contract(msg)) if msg.contains("full signature")));
+
+    let data = contract
+        .encode_call("get(address)", &[DynSolValue::Address(ALICE)])
+        .unwrap();
+    assert_eq!(&data[..4], &keccak256("get(address)")[..4]);
+}
+
+#[tokio::test]
+async fn test_call_decodes_output() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+    mock_eth_call(
+        &server,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": format!("0x{:064x}", 1000)
+        }),
+    )
+    .await;
+
+    let output = contract
+        .call("balanceOf", &[DynSolValue::Address(ALICE)])
+        .await
+        .unwrap();
+    assert_eq!(output, vec![DynSolValue::Uint(U256::from(1000), 256)]);
+}
+
+#[tokio::test]
+async fn test_call_decodes_custom_error() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+    mock_eth_call(
+        &server,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {
+                "code": 3,
+                "message": "execution reverted",
+                "data": format!("0x{}", hex::encode(insufficient_balance(5, 10)))
+            }
+        }),
+    )
+    .await;
+
+    let result = contract
+        .call("deposit", &[DynSolValue::Uint(U256::from(10), 256)])
+        .await;
+    match result {
+        Err(Error::Contract(msg)) => assert!(msg.contains("InsufficientBalance(5, 10)"), "{msg}"),
+        other => panic!("expected a revert, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_decode_revert() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+
+    let revert = Revert::from("not owner").abi_encode();
+    assert_eq!(
+        contract.decode_revert(&revert),
+        RevertReason::Error("not owner".to_string())
+    );
+
+    let mut panic = keccak256("Panic(uint256)")[..4].to_vec();
+    panic.extend_from_slice(&U256::from(0x11).to_be_bytes::<32>());
+    assert_eq!(
+        contract.decode_revert(&panic),
+        RevertReason::Panic(U256::from(0x11))
+    );
+
+    assert_eq!(
+        contract.decode_revert(&insufficient_balance(1, 2)),
+        RevertReason::Custom {
+            name: "InsufficientBalance".to_string(),
+            args: vec![
+                DynSolValue::Uint(U256::from(1), 256),
+                DynSolValue::Uint(U256::from(2), 256)
+            ],
+        }
+    );
+
+    assert!(matches!(
+        contract.decode_revert(&[0xde, 0xad]),
+        RevertReason::Unknown(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_decode_log() {
+    let server = MockServer::start().await;
+    let contract = vault(&server).await;
+
+    let topics = vec![
+        keccak256("Deposited(address,uint256)"),
+        B256::left_padding_from(ALICE.as_slice()),
+    ];
+    let data = U256::from(42).to_be_bytes::<32>().to_vec();
+    let log = alloy::rpc::types::Log {
+        inner: alloy::primitives::Log {
+            address: VAULT,
+            data: LogData::new_unchecked(topics.clone(), data.clone().into()),
+        },
+        ..Default::default()
+    };
+
+    let decoded = contract.decode_log(&log).unwrap();
+    assert_eq!(decoded.name, "Deposited");
+    assert_eq!(decoded.param("from"), Some(&DynSolValue::Address(ALICE)));
+    assert_eq!(
+        decoded.param("amount"),
+        Some(&DynSolValue::Uint(U256::from(42), 256))
+    );
+
+    // Logs from other contracts are ignored
+    let other = alloy::rpc::types::Log {
+        inner: alloy::primitives::Log {
+            address: ALICE,
+            data: LogData::new_unchecked(topics, data.into()),
+        },
+        ..Default::default()
+    };
+    assert!(contract.decode_log(&other).is_none());
+}
+
+#[test]
+fn test_create_address() {
+    let deployer = address!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+    assert_eq!(
+        create_address(deployer, 0),
+        address!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d")
+    );
+    assert_eq!(
+        create_address(deployer, 1),
+        address!("343c43a37d37dff08ae8c4a11544c718abb4fcf8")
+    );
+}
+
+#[test]
+fn test_create2_address() {
+    // EIP-1014 examples
+    assert_eq!(
+        create2_address(Address::ZERO, B256::ZERO, &[0x00]),
+        address!("4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38")
+    );
+    assert_eq!(
+        create2_address(
+            address!("deadbeef00000000000000000000000000000000"),
+            B256::ZERO,
+            &[0x00]
+        ),
+        address!("B928f69Bb1D91Cd65274e3c79d8986362984fDA3")
+    );
+}
+
+#[test]
+fn test_deployer_init_code() {
+    let artifact = r#"{
+        "abi": [{"type": "constructor", "stateMutability": "nonpayable",
+                 "inputs": [{"name": "owner", "type": "address"}]}],
+        "bytecode": {"object": "0x6080604052"}
+    }"#;
+    let deployer = ContractDeployer::from_artifact(artifact)
+        .unwrap()
+        .with_constructor_args(vec![DynSolValue::Address(ALICE)]);
+
+    let init_code = deployer.init_code().unwrap();
+    assert_eq!(&init_code[..5], &[0x60, 0x80, 0x60, 0x40, 0x52]);
+    assert_eq!(&init_code[5 + 12..], ALICE.as_slice());
+
+    // Missing constructor arguments are rejected
+    let missing = ContractDeployer::from_artifact(artifact).unwrap();
+    assert!(missing.init_code().is_err());
+
+    // Arguments without an ABI are rejected
+    let no_abi =
+        ContractDeployer::new(vec![0x60]).with_constructor_args(vec![DynSolValue::Bool(true)]);
+    assert!(no_abi.init_code().is_err());
+}
+
+#[test]
+fn test_predict_create2_address() {
+    let salt = B256::repeat_byte(0x11);
+    let deployer = ContractDeployer::new(vec![0x60, 0x80]).with_salt(salt);
+
+    assert_eq!(
+        deployer.predict_create2_address().unwrap(),
+        Some(DETERMINISTIC_DEPLOYER.create2_from_code(salt, [0x60, 0x80]))
+    );
+    assert_eq!(
+        ContractDeployer::new(vec![0x60])
+            .predict_create2_address()
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_predict_create_address_uses_nonce() {
+    let server = MockServer::start().await;
+    let adapter = connect(&server, 31337).await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_getTransactionCount"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": "0x1"
+        })))
+        .mount(&server)
+        .await;
+
+    let deployer = address!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+    let predicted = ContractDeployer::new(vec![0x60])
+        .predict_address(&adapter, deployer)
+        .await
+        .unwrap();
+    assert_eq!(predicted, create_address(deployer, 1));
+}
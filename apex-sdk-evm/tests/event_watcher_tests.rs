@@ -0,0 +1,159 @@
+//! Tests for the EVM event watcher with mocked RPC responses
+
+use apex_sdk_evm::EvmAdapter;
+use apex_sdk_types::{Address, EventFilter};
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+const TRANSFER_TOPIC: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+async fn mock_rpc(server: &MockServer, rpc_method: &str, result: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains(rpc_method))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": result
+        })))
+        .mount(server)
+        .await;
+}
+
+fn transfer_log(block_number: u64, log_index: u64) -> serde_json::Value {
+    serde_json::json!({
+        "address": TOKEN,
+        "topics": [
+            TRANSFER_TOPIC,
+            "0x000000000000000000000000742d35cc6634c0532925a3b844bc9e7595f0beb7",
+            "0x000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        ],
+        "data": "0x00000000000000000000000000000000000000000000000000000000000003e8",
+        "blockHash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+        "blockNumber": format!("0x{:x}", block_number),
+        "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+        "transactionIndex": "0x0",
+        "logIndex": format!("0x{:x}", log_index),
+        "removed": false
+    })
+}
+
+async fn setup(logs: serde_json::Value) -> (MockServer, EvmAdapter) {
+    let server = MockServer::start().await;
+    mock_rpc(&server, "eth_chainId", serde_json::json!("0x1")).await;
+    mock_rpc(&server, "eth_blockNumber", serde_json::json!("0x10")).await;
+    mock_rpc(&server, "eth_getLogs", logs).await;
+
+    let adapter = EvmAdapter::connect(&server.uri()).await.unwrap();
+    (server, adapter)
+}
+
+fn transfer_filter(from_block: Option<u64>, to_block: Option<u64>) -> EventFilter {
+    EventFilter {
+        event_names: Some(vec!["Transfer".to_string()]),
+        addresses: Some(vec![Address::evm(TOKEN)]),
+        from_block,
+        to_block,
+    }
+}
+
+#[tokio::test]
+async fn test_get_events_decodes_known_logs() {
+    let (_server, adapter) = setup(serde_json::json!([transfer_log(12, 3)])).await;
+
+    let events = adapter
+        .event_watcher()
+        .get_events(&transfer_filter(None, None), 10, 16)
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.name, "Transfer");
+    assert_eq!(event.block_number, Some(12));
+    assert_eq!(event.index, Some(3));
+    assert_eq!(
+        event.tx_hash.as_deref(),
+        Some("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060")
+    );
+    assert_eq!(event.data["address"], TOKEN);
+    assert_eq!(event.data["signature"], "Transfer(address,address,uint256)");
+    assert_eq!(event.data["topics"].as_array().unwrap().len(), 3);
+
+    // Decoded as an ERC-20 transfer
+    let params = &event.data["params"];
+    assert_eq!(
+        params["from"].as_str().unwrap().to_lowercase(),
+        "0x742d35cc6634c0532925a3b844bc9e7595f0beb7"
+    );
+    assert_eq!(
+        params["to"].as_str().unwrap().to_lowercase(),
+        "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+    );
+    assert_eq!(params["value"], "1000");
+}
+
+#[tokio::test]
+async fn test_get_events_filters_unresolved_names_locally() {
+    let (_server, adapter) = setup(serde_json::json!([transfer_log(12, 0)])).await;
+    let filter = EventFilter {
+        event_names: Some(vec!["Swap".to_string()]),
+        addresses: None,
+        from_block: None,
+        to_block: None,
+    };
+
+    let events = adapter
+        .event_watcher()
+        .get_events(&filter, 10, 16)
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn test_watch_stops_at_end_of_range() {
+    let (server, adapter) = setup(serde_json::json!([transfer_log(5, 0)])).await;
+
+    let mut received = Vec::new();
+    adapter
+        .event_watcher()
+        .with_max_block_range(4)
+        .watch(transfer_filter(Some(1), Some(8)), |event| {
+            received.push(event);
+            true
+        })
+        .await
+        .unwrap();
+
+    // The 1..=8 range is fetched in two chunks of four blocks
+    let requests = server.received_requests().await.unwrap();
+    let get_logs_calls = requests
+        .iter()
+        .filter(|r| String::from_utf8_lossy(&r.body).contains("eth_getLogs"))
+        .count();
+    assert_eq!(get_logs_calls, 2);
+    assert_eq!(received.len(), 2);
+}
+
+#[tokio::test]
+async fn test_watch_stops_when_callback_declines() {
+    let (_server, adapter) =
+        setup(serde_json::json!([transfer_log(5, 0), transfer_log(5, 1)])).await;
+
+    let mut received = 0;
+    adapter
+        .event_watcher()
+        .watch(transfer_filter(Some(1), None), |_| {
+            received += 1;
+            false
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(received, 1);
+}
@@ -14,6 +14,9 @@ use wiremock::{
     Mock, MockServer, ResponseTemplate,
 };
 
+mod common;
+use common::mock_chain_id;
+
 // ============================================================================
 // Mock RPC Server Setup
 // ============================================================================
@@ -22,20 +25,6 @@ async fn setup_mock_rpc() -> MockServer {
     MockServer::start().await
 }
 
-async fn mock_chain_id(server: &MockServer, chain_id: u64) {
-    use wiremock::matchers::body_string_contains;
-    Mock::given(method("POST"))
-        .and(path("/"))
-        .and(body_string_contains("eth_chainId"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "result": format!("0x{:x}", chain_id)
-        })))
-        .mount(server)
-        .await;
-}
-
 async fn mock_get_balance(server: &MockServer, address: &str, balance: U256) {
     use wiremock::matchers::body_string_contains;
     // Convert address to lowercase as RPC calls use lowercase
@@ -80,6 +69,7 @@ async fn mock_transaction_receipt_confirmed(server: &MockServer) {
         })))
         .mount(server)
         .await;
+    mock_receipt_block(server).await;
 }
 
 async fn mock_transaction_receipt_failed(server: &MockServer) {
@@ -109,6 +99,59 @@ async fn mock_transaction_receipt_failed(server: &MockServer) {
         })))
         .mount(server)
         .await;
+    mock_receipt_block(server).await;
+}
+
+/// Mock the canonical block referenced by the mocked receipts
+///
+/// The `safe` and `finalized` tags are rejected, as by a node without them.
+async fn mock_receipt_block(server: &MockServer) {
+    use wiremock::matchers::body_string_contains;
+    for tag in ["safe", "finalized"] {
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_string_contains("eth_getBlockByNumber"))
+            .and(body_string_contains(tag))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": { "code": -32602, "message": format!("unknown block tag {}", tag) }
+            })))
+            .mount(server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .and(body_string_contains("[\"0x1\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "hash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                "parentHash": format!("0x{:064x}", 0),
+                "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+                "miner": "0x0000000000000000000000000000000000000000",
+                "stateRoot": format!("0x{:064x}", 1),
+                "transactionsRoot": format!("0x{:064x}", 2),
+                "receiptsRoot": format!("0x{:064x}", 3),
+                "logsBloom": format!("0x{}", "0".repeat(512)),
+                "difficulty": "0x0",
+                "number": "0x1",
+                "gasLimit": "0x1c9c380",
+                "gasUsed": "0x5208",
+                "timestamp": "0x6553f100",
+                "extraData": "0x",
+                "mixHash": format!("0x{:064x}", 0),
+                "nonce": "0x0000000000000000",
+                "baseFeePerGas": "0x7",
+                "uncles": [],
+                "transactions": [],
+                "size": "0x200"
+            }
+        })))
+        .mount(server)
+        .await;
 }
 
 async fn mock_transaction_not_found(server: &MockServer) {
@@ -0,0 +1,182 @@
+//! Tests for Multicall3 batching with a mocked aggregate3 endpoint
+
+use alloy::primitives::{address, Address, U256};
+use alloy::sol_types::{SolCall, SolValue};
+use apex_sdk_evm::contract::IERC20;
+use apex_sdk_evm::multicall::{IMulticall3, Multicall, MulticallResult};
+use wiremock::{
+    matchers::{body_string_contains, method},
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+};
+
+mod common;
+use common::connect;
+
+const FAILING_TOKEN: Address = address!("00000000000000000000000000000000000000ff");
+
+/// Executes `aggregate3` by answering `balanceOf(account)` with the account as a number
+struct Aggregate3Responder;
+
+impl Respond for Aggregate3Responder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let tx = &body["params"][0];
+        let input = tx["input"].as_str().or(tx["data"].as_str()).unwrap();
+        let calldata = hex::decode(input.trim_start_matches("0x")).unwrap();
+        let calls = IMulticall3::aggregate3Call::abi_decode(&calldata)
+            .unwrap()
+            .calls;
+
+        let results: Vec<IMulticall3::Result> = calls
+            .iter()
+            .map(|call| {
+                if call.target == FAILING_TOKEN {
+                    IMulticall3::Result {
+                        success: false,
+                        returnData: vec![0xde, 0xad].into(),
+                    }
+                } else {
+                    let account = U256::from_be_slice(&call.callData[4..36]);
+                    IMulticall3::Result {
+                        success: true,
+                        returnData: account.abi_encode().into(),
+                    }
+                }
+            })
+            .collect();
+
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": body["id"],
+            "result": format!("0x{}", hex::encode(results.abi_encode()))
+        }))
+    }
+}
+
+async fn mock_aggregate3(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .respond_with(Aggregate3Responder)
+        .mount(server)
+        .await;
+}
+
+async fn eth_calls(server: &MockServer) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| String::from_utf8_lossy(&request.body).contains("eth_call"))
+        .count()
+}
+
+fn account(i: u64) -> Address {
+    Address::left_padding_from(&i.to_be_bytes())
+}
+
+fn queue_balances(multicall: &mut Multicall, count: u64) {
+    let token = address!("5FbDB2315678afecb367f032d93F642f64180aa3");
+    for i in 1..=count {
+        multicall.add_call(
+            token,
+            &IERC20::balanceOfCall {
+                account: account(i),
+            },
+            true,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_multicall_results_in_order() {
+    let server = MockServer::start().await;
+    mock_aggregate3(&server).await;
+    let mut multicall = Multicall::new(connect(&server, 1).await);
+
+    queue_balances(&mut multicall, 3);
+    let failing = multicall.add_call(
+        FAILING_TOKEN,
+        &IERC20::balanceOfCall {
+            account: account(4),
+        },
+        true,
+    );
+    assert_eq!(failing, 3);
+    assert_eq!(multicall.len(), 4);
+
+    let results = multicall.execute().await.unwrap();
+    assert_eq!(results.len(), 4);
+    for (i, result) in results.iter().take(3).enumerate() {
+        assert_eq!(
+            result.decode::<IERC20::balanceOfCall>().unwrap(),
+            U256::from(i + 1)
+        );
+    }
+    assert_eq!(
+        results[3],
+        MulticallResult {
+            success: false,
+            return_data: vec![0xde, 0xad].into()
+        }
+    );
+    assert!(results[3].decode::<IERC20::balanceOfCall>().is_err());
+
+    assert!(multicall.is_empty());
+    assert_eq!(eth_calls(&server).await, 1);
+}
+
+#[tokio::test]
+async fn test_multicall_splits_by_call_count() {
+    let server = MockServer::start().await;
+    mock_aggregate3(&server).await;
+    let mut multicall = Multicall::new(connect(&server, 1).await).with_max_calls(10);
+
+    queue_balances(&mut multicall, 25);
+    let results = multicall.execute().await.unwrap();
+
+    assert_eq!(results.len(), 25);
+    assert_eq!(
+        results[24].decode::<IERC20::balanceOfCall>().unwrap(),
+        U256::from(25)
+    );
+    assert_eq!(eth_calls(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_multicall_splits_by_calldata_and_gas() {
+    let server = MockServer::start().await;
+    mock_aggregate3(&server).await;
+
+    // balanceOf calldata is 36 bytes, so 100 bytes fits two calls per batch
+    let mut by_size = Multicall::new(connect(&server, 1).await).with_max_calldata_bytes(100);
+    queue_balances(&mut by_size, 5);
+    assert_eq!(by_size.execute().await.unwrap().len(), 5);
+    assert_eq!(eth_calls(&server).await, 3);
+
+    let server = MockServer::start().await;
+    mock_aggregate3(&server).await;
+    let mut by_gas = Multicall::new(connect(&server, 1).await).with_gas_budget(1_000_000, 250_000);
+    queue_balances(&mut by_gas, 9);
+    assert_eq!(by_gas.execute().await.unwrap().len(), 9);
+    assert_eq!(eth_calls(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_multicall_keeps_calls_on_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {"code": 3, "message": "execution reverted", "data": "0x"}
+        })))
+        .mount(&server)
+        .await;
+    let mut multicall = Multicall::new(connect(&server, 1).await);
+
+    queue_balances(&mut multicall, 2);
+    assert!(multicall.execute().await.is_err());
+    assert_eq!(multicall.len(), 2);
+}
@@ -0,0 +1,367 @@
+//! Tests for reorg-aware receipt tracking with mocked RPC responses
+
+use apex_sdk_core::{ConfirmationStrategy, ReceiptWatcher};
+use apex_sdk_evm::{EvmAdapter, EvmReceiptWatcher};
+use apex_sdk_types::TxStatus;
+use std::time::Duration;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+mod common;
+use common::{block_hash, block_json, mock_chain_id, rpc_result};
+
+const TX_HASH: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+fn receipt_json(number: u64, hash: &str) -> serde_json::Value {
+    serde_json::json!({
+        "transactionHash": TX_HASH,
+        "transactionIndex": "0x0",
+        "blockHash": hash,
+        "blockNumber": format!("0x{:x}", number),
+        "from": "0x742d35cc6634c0532925a3b844bc9e7595f0beb7",
+        "to": "0x0000000000000000000000000000000000000001",
+        "cumulativeGasUsed": "0x5208",
+        "gasUsed": "0x5208",
+        "effectiveGasPrice": "0x3b9aca00",
+        "contractAddress": null,
+        "logs": [],
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "status": "0x1",
+        "type": "0x2"
+    })
+}
+
+async fn setup(head: u64) -> MockServer {
+    let server = MockServer::start().await;
+    mock_chain_id(&server, 1).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_blockNumber"))
+        .respond_with(rpc_result(serde_json::json!(format!("0x{:x}", head))))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn mock_canonical_block(server: &MockServer, number: u64, hash: &str) {
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .and(body_string_contains(format!("[\"0x{:x}\"", number)))
+        .respond_with(rpc_result(block_json(number, hash)))
+        .mount(server)
+        .await;
+}
+
+async fn watcher(server: &MockServer) -> EvmReceiptWatcher {
+    EvmAdapter::connect(&server.uri())
+        .await
+        .unwrap()
+        .receipt_watcher()
+        .with_polling_interval(Duration::from_millis(10))
+}
+
+#[tokio::test]
+async fn test_confirms_receipt_in_canonical_block() {
+    let server = setup(10).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+
+    let status = watcher(&server)
+        .await
+        .wait_for_receipt_with_strategy(
+            TX_HASH,
+            &ConfirmationStrategy::BlockConfirmations {
+                confirmations: 3,
+                timeout_secs: 5,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Confirmed);
+    assert_eq!(status.block_number, Some(5));
+    assert_eq!(status.confirmations, Some(5));
+}
+
+#[tokio::test]
+async fn test_reports_receipt_moved_to_another_block() {
+    let server = setup(9).await;
+    let orphaned = format!("0x{:064x}", 0xf005);
+
+    // The head is at block 6 on the first poll
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_blockNumber"))
+        .respond_with(rpc_result(serde_json::json!("0x6")))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    // The receipt is first seen in a block that is later replaced
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &orphaned)))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(6, &block_hash(6))))
+        .with_priority(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getBlockByNumber"))
+        .and(body_string_contains("[\"0x5\""))
+        .respond_with(rpc_result(block_json(5, &orphaned)))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 6, &block_hash(6)).await;
+
+    let mut seen = Vec::new();
+    let status = watcher(&server)
+        .await
+        .watch_receipt(
+            TX_HASH,
+            &ConfirmationStrategy::BlockConfirmations {
+                confirmations: 2,
+                timeout_secs: 5,
+            },
+            |status| seen.push((status.status.clone(), status.block_number)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Confirmed);
+    assert_eq!(status.block_number, Some(6));
+    assert_eq!(
+        seen,
+        vec![
+            (TxStatus::Pending, Some(5)),
+            (TxStatus::Reorged, Some(5)),
+            (TxStatus::Confirmed, Some(6)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_returns_reorged_when_receipt_drops() {
+    let server = setup(5).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(serde_json::Value::Null))
+        .with_priority(2)
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+
+    let status = watcher(&server)
+        .await
+        .wait_for_receipt_with_strategy(
+            TX_HASH,
+            &ConfirmationStrategy::BlockConfirmations {
+                confirmations: 3,
+                timeout_secs: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Reorged);
+    assert_eq!(status.block_number, Some(5));
+    assert_eq!(status.block_hash, Some(block_hash(5)));
+}
+
+#[tokio::test]
+async fn test_finalization_uses_finalized_tag() {
+    // Only one confirmation, but the finalized tag already covers the block
+    let server = setup(6).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"finalized\""))
+        .respond_with(rpc_result(block_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+
+    let status = watcher(&server)
+        .await
+        .wait_for_receipt_with_strategy(
+            TX_HASH,
+            &ConfirmationStrategy::Finalized { timeout_secs: 5 },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Finalized);
+    assert_eq!(status.confirmations, Some(1));
+}
+
+#[tokio::test]
+async fn test_receipt_status_uses_safe_tag() {
+    let server = setup(6).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"finalized\""))
+        .respond_with(rpc_result(block_json(4, &block_hash(4))))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"safe\""))
+        .respond_with(rpc_result(block_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+
+    let status = watcher(&server)
+        .await
+        .get_receipt_status(TX_HASH)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Confirmed);
+}
+
+#[tokio::test]
+async fn test_finalization_falls_back_when_tag_rejected() {
+    let server = setup(20).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"finalized\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": -32602, "message": "invalid block tag" }
+        })))
+        .mount(&server)
+        .await;
+
+    let status = watcher(&server)
+        .await
+        .wait_for_receipt_with_strategy(
+            TX_HASH,
+            &ConfirmationStrategy::Finalized { timeout_secs: 5 },
+        )
+        .await
+        .unwrap();
+
+    // 15 confirmations are past the fallback finality depth
+    assert_eq!(status.status, TxStatus::Finalized);
+}
+
+#[tokio::test]
+async fn test_receipt_status_propagates_tag_transport_error() {
+    let server = setup(6).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"finalized\""))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"finalized\""))
+        .respond_with(rpc_result(block_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("[\"safe\""))
+        .respond_with(rpc_result(block_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+
+    let watcher = watcher(&server).await;
+    assert!(watcher.get_receipt_status(TX_HASH).await.is_err());
+
+    // The failure did not disable the tags for later queries
+    let status = watcher.get_receipt_status(TX_HASH).await.unwrap().unwrap();
+    assert_eq!(status.status, TxStatus::Finalized);
+}
+
+#[tokio::test]
+async fn test_retries_failed_receipt_lookup() {
+    let server = setup(10).await;
+    // The first receipt lookup hits a server error, the next one succeeds
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(body_string_contains("eth_getTransactionReceipt"))
+        .respond_with(rpc_result(receipt_json(5, &block_hash(5))))
+        .mount(&server)
+        .await;
+    mock_canonical_block(&server, 5, &block_hash(5)).await;
+
+    let status = watcher(&server)
+        .await
+        .wait_for_receipt_with_strategy(
+            TX_HASH,
+            &ConfirmationStrategy::BlockConfirmations {
+                confirmations: 3,
+                timeout_secs: 5,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TxStatus::Confirmed);
+    assert_eq!(status.block_number, Some(5));
+}
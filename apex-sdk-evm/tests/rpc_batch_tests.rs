@@ -0,0 +1,192 @@
+//! Tests for JSON-RPC request batching with a mocked endpoint
+
+use alloy::primitives::U64;
+use apex_sdk_core::Provider as CoreProvider;
+use apex_sdk_evm::rpc_batch::BatchConfig;
+use apex_sdk_evm::EvmProvider;
+use apex_sdk_types::Address;
+use std::time::Duration;
+use wiremock::{matchers::method, Mock, MockServer, Request, Respond, ResponseTemplate};
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+/// Answers single and batch requests, failing balance lookups for BOB
+struct RpcResponder;
+
+impl RpcResponder {
+    fn answer(request: &serde_json::Value) -> serde_json::Value {
+        let id = request["id"].clone();
+        let params = request["params"].to_string().to_lowercase();
+        let result = match request["method"].as_str().unwrap() {
+            "eth_chainId" => "0x1",
+            "eth_blockNumber" => "0x64",
+            "eth_getBalance" if params.contains(&BOB.to_lowercase()) => {
+                return serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": {"code": -32000, "message": "rate limited"}
+                });
+            }
+            "eth_getBalance" => "0x3e8",
+            "eth_getTransactionCount" => "0x7",
+            other => panic!("unexpected method {}", other),
+        };
+        serde_json::json!({"jsonrpc": "2.0", "id": id, "result": result})
+    }
+}
+
+impl Respond for RpcResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let response = match body.as_array() {
+            Some(batch) => batch.iter().map(Self::answer).collect(),
+            None => Self::answer(&body),
+        };
+        ResponseTemplate::new(200).set_body_json(response)
+    }
+}
+
+async fn connect(server: &MockServer, config: BatchConfig) -> EvmProvider {
+    Mock::given(method("POST"))
+        .respond_with(RpcResponder)
+        .mount(server)
+        .await;
+
+    EvmProvider::new_with_batching(&server.uri(), config)
+        .await
+        .unwrap()
+}
+
+/// Sizes of the requests received after connecting, with 1 for single requests
+async fn request_sizes(server: &MockServer) -> Vec<usize> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .skip(1)
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            body.as_array().map_or(1, Vec::len)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_concurrent_calls_share_a_batch() {
+    let server = MockServer::start().await;
+    let provider = connect(&server, BatchConfig::default()).await;
+    assert_eq!(provider.chain_id(), 1);
+
+    let alice = Address::evm(ALICE);
+    let (balance, nonce, block) = tokio::join!(
+        provider.get_balance(&alice),
+        provider.get_transaction_count(&alice),
+        provider.get_block_number()
+    );
+
+    assert_eq!(balance.unwrap(), 1000);
+    assert_eq!(nonce.unwrap(), 7);
+    assert_eq!(block.unwrap(), 100);
+    assert_eq!(request_sizes(&server).await, vec![3]);
+}
+
+#[tokio::test]
+async fn test_errors_are_per_call() {
+    let server = MockServer::start().await;
+    let provider = connect(&server, BatchConfig::default()).await;
+
+    let (alice, bob) = (Address::evm(ALICE), Address::evm(BOB));
+    let (alice_balance, bob_balance, bob_nonce) = tokio::join!(
+        provider.get_balance(&alice),
+        provider.get_balance(&bob),
+        provider.get_transaction_count(&bob)
+    );
+
+    assert_eq!(alice_balance.unwrap(), 1000);
+    assert!(bob_balance
+        .unwrap_err()
+        .to_string()
+        .contains("rate limited"));
+    assert_eq!(bob_nonce.unwrap(), 7);
+    assert_eq!(request_sizes(&server).await, vec![3]);
+}
+
+#[tokio::test]
+async fn test_max_batch_size_splits_batches() {
+    let server = MockServer::start().await;
+    let config = BatchConfig::default()
+        .with_max_batch_size(2)
+        .with_window(Duration::from_millis(50));
+    let provider = connect(&server, config).await;
+
+    let alice = Address::evm(ALICE);
+    let results = tokio::join!(
+        provider.get_balance(&alice),
+        provider.get_balance(&alice),
+        provider.get_balance(&alice),
+        provider.get_balance(&alice),
+        provider.get_balance(&alice)
+    );
+
+    assert_eq!(results.4.unwrap(), 1000);
+    let mut sizes = request_sizes(&server).await;
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![1, 2, 2]);
+}
+
+#[tokio::test]
+async fn test_explicit_batch() {
+    let server = MockServer::start().await;
+    let provider = connect(&server, BatchConfig::default()).await;
+
+    let mut batch = provider.new_batch();
+    let block = batch.add_call::<_, U64>("eth_blockNumber", &()).unwrap();
+    let chain_id = batch.add_call::<_, U64>("eth_chainId", &()).unwrap();
+    batch.send().await.unwrap();
+
+    assert_eq!(block.await.unwrap(), U64::from(100));
+    assert_eq!(chain_id.await.unwrap(), U64::from(1));
+    assert_eq!(request_sizes(&server).await, vec![2]);
+}
+
+#[tokio::test]
+async fn test_batch_failure_keeps_error_kind() {
+    use alloy::providers::Provider;
+    use alloy::transports::{RpcError, TransportErrorKind};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(RpcResponder)
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429).set_body_string("too many requests"))
+        .mount(&server)
+        .await;
+
+    let provider = EvmProvider::new_with_batching(&server.uri(), BatchConfig::default())
+        .await
+        .unwrap();
+
+    let alice = ALICE.parse().unwrap();
+    let (block, balance) = tokio::join!(
+        provider.provider.get_block_number(),
+        provider.provider.get_balance(alice)
+    );
+
+    // Every caller sees the HTTP status, not just a message
+    for error in [block.unwrap_err(), balance.unwrap_err()] {
+        assert!(
+            matches!(
+                error,
+                RpcError::Transport(TransportErrorKind::HttpError(ref e)) if e.status == 429
+            ),
+            "{:?}",
+            error
+        );
+    }
+    assert_eq!(request_sizes(&server).await, vec![2]);
+}
@@ -0,0 +1,349 @@
+//! Tests for the ERC-20, ERC-721 and ERC-1155 token wrappers with mocked RPC responses
+
+use alloy::primitives::{address, keccak256, Address, LogData, Signature, B256, U256};
+use alloy::sol_types::{SolCall, SolEvent, SolStruct, SolValue};
+use apex_sdk_evm::contract::{
+    Erc1155, Erc20, Erc721, IERC20Permit, Permit, IERC1155, IERC165, IERC20, IERC721,
+    INTERFACE_ID_ERC1155, INTERFACE_ID_ERC721,
+};
+use apex_sdk_evm::Wallet;
+use wiremock::{
+    matchers::{body_string_contains, method},
+    Mock, MockServer, ResponseTemplate,
+};
+
+mod common;
+use common::connect;
+
+const TOKEN: Address = address!("5FbDB2315678afecb367f032d93F642f64180aa3");
+const ALICE: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+const BOB: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+// Anvil's first development key (ALICE)
+const ALICE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+/// Answer `eth_call`s whose calldata contains `calldata_hex` with ABI-encoded `output`
+async fn mock_call(server: &MockServer, calldata_hex: &str, output: Vec<u8>) {
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .and(body_string_contains(calldata_hex))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": format!("0x{}", hex::encode(output))
+        })))
+        .mount(server)
+        .await;
+}
+
+fn log(address: Address, topics: Vec<B256>, data: Vec<u8>) -> alloy::rpc::types::Log {
+    alloy::rpc::types::Log {
+        inner: alloy::primitives::Log {
+            address,
+            data: LogData::new_unchecked(topics, data.into()),
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_erc721_views() {
+    let server = MockServer::start().await;
+    let token = Erc721::new(TOKEN, connect(&server, 31337).await);
+    mock_call(
+        &server,
+        &hex::encode(IERC721::ownerOfCall::SELECTOR),
+        ALICE.abi_encode(),
+    )
+    .await;
+    mock_call(
+        &server,
+        &hex::encode(IERC721::tokenURICall::SELECTOR),
+        "ipfs://token/7".to_string().abi_encode(),
+    )
+    .await;
+
+    assert_eq!(token.owner_of(U256::from(7)).await.unwrap(), ALICE);
+    assert_eq!(
+        token.token_uri(U256::from(7)).await.unwrap(),
+        "ipfs://token/7"
+    );
+}
+
+#[tokio::test]
+async fn test_erc721_encoding() {
+    let server = MockServer::start().await;
+    let token = Erc721::new(TOKEN, connect(&server, 31337).await);
+
+    let plain = token.encode_safe_transfer_from(ALICE, BOB, U256::from(1), vec![]);
+    assert_eq!(
+        &plain[..4],
+        &keccak256("safeTransferFrom(address,address,uint256)")[..4]
+    );
+
+    let with_data = token.encode_safe_transfer_from(ALICE, BOB, U256::from(1), vec![0xab]);
+    assert_eq!(
+        &with_data[..4],
+        &keccak256("safeTransferFrom(address,address,uint256,bytes)")[..4]
+    );
+
+    let approval = token.encode_set_approval_for_all(BOB, true);
+    assert_eq!(
+        approval,
+        IERC721::setApprovalForAllCall {
+            operator: BOB,
+            approved: true
+        }
+        .abi_encode()
+    );
+}
+
+#[tokio::test]
+async fn test_erc1155_balance_of_batch() {
+    let server = MockServer::start().await;
+    let token = Erc1155::new(TOKEN, connect(&server, 31337).await);
+    mock_call(
+        &server,
+        &hex::encode(IERC1155::balanceOfBatchCall::SELECTOR),
+        vec![U256::from(5), U256::from(0)].abi_encode(),
+    )
+    .await;
+
+    let balances = token
+        .balance_of_batch(vec![ALICE, BOB], vec![U256::from(1), U256::from(2)])
+        .await
+        .unwrap();
+    assert_eq!(balances, vec![U256::from(5), U256::ZERO]);
+
+    assert!(token
+        .balance_of_batch(vec![ALICE], vec![U256::from(1), U256::from(2)])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_supports_interface() {
+    let server = MockServer::start().await;
+    let token = Erc721::new(TOKEN, connect(&server, 31337).await);
+
+    let selector = hex::encode(IERC165::supportsInterfaceCall::SELECTOR);
+    for (interface_id, supported) in [
+        ("01ffc9a7", true),
+        ("ffffffff", false),
+        ("80ac58cd", true),
+        ("d9b67a26", false),
+    ] {
+        mock_call(
+            &server,
+            &format!("{}{}", selector, interface_id),
+            supported.abi_encode(),
+        )
+        .await;
+    }
+
+    assert!(token.supports_interface(INTERFACE_ID_ERC721).await.unwrap());
+    assert!(!token
+        .supports_interface(INTERFACE_ID_ERC1155)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_supports_interface_without_erc165() {
+    let server = MockServer::start().await;
+    let token = Erc1155::new(TOKEN, connect(&server, 31337).await);
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {"code": 3, "message": "execution reverted", "data": "0x"}
+        })))
+        .mount(&server)
+        .await;
+
+    assert!(!token
+        .supports_interface(INTERFACE_ID_ERC1155)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_supports_interface_propagates_node_errors() {
+    let server = MockServer::start().await;
+    let token = Erc1155::new(TOKEN, connect(&server, 31337).await);
+    Mock::given(method("POST"))
+        .and(body_string_contains("eth_call"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {"code": -32000, "message": "header not found"}
+        })))
+        .mount(&server)
+        .await;
+
+    assert!(token
+        .supports_interface(INTERFACE_ID_ERC1155)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_supports_interface_rejects_short_output() {
+    let server = MockServer::start().await;
+    let token = Erc721::new(TOKEN, connect(&server, 31337).await);
+    mock_call(
+        &server,
+        &hex::encode(IERC165::supportsInterfaceCall::SELECTOR),
+        vec![1],
+    )
+    .await;
+
+    assert!(!token.supports_interface(INTERFACE_ID_ERC721).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_decode_erc721_transfer() {
+    let server = MockServer::start().await;
+    let token = Erc721::new(TOKEN, connect(&server, 31337).await);
+
+    let transfer = log(
+        TOKEN,
+        vec![
+            IERC721::Transfer::SIGNATURE_HASH,
+            ALICE.into_word(),
+            BOB.into_word(),
+            B256::from(U256::from(9)),
+        ],
+        vec![],
+    );
+    assert_eq!(
+        token.decode_transfer(&transfer),
+        Some(IERC721::Transfer {
+            from: ALICE,
+            to: BOB,
+            tokenId: U256::from(9)
+        })
+    );
+
+    // ERC-20 transfers share the signature but carry the amount in data
+    let erc20 = log(
+        TOKEN,
+        vec![
+            IERC721::Transfer::SIGNATURE_HASH,
+            ALICE.into_word(),
+            BOB.into_word(),
+        ],
+        U256::from(9).abi_encode(),
+    );
+    assert_eq!(token.decode_transfer(&erc20), None);
+
+    // Logs from other contracts are ignored
+    let other = log(BOB, transfer.inner.data.topics().to_vec(), vec![]);
+    assert_eq!(token.decode_transfer(&other), None);
+}
+
+#[tokio::test]
+async fn test_decode_erc1155_transfers() {
+    let server = MockServer::start().await;
+    let token = Erc1155::new(TOKEN, connect(&server, 31337).await);
+    let topics = |signature| {
+        vec![
+            signature,
+            BOB.into_word(),
+            ALICE.into_word(),
+            BOB.into_word(),
+        ]
+    };
+
+    let single = log(
+        TOKEN,
+        topics(IERC1155::TransferSingle::SIGNATURE_HASH),
+        (U256::from(1), U256::from(10)).abi_encode_params(),
+    );
+    let decoded = token.decode_transfer(&single).unwrap();
+    assert_eq!(decoded.operator, BOB);
+    assert_eq!(decoded.ids, vec![U256::from(1)]);
+    assert_eq!(decoded.values, vec![U256::from(10)]);
+
+    let batch = log(
+        TOKEN,
+        topics(IERC1155::TransferBatch::SIGNATURE_HASH),
+        (
+            vec![U256::from(1), U256::from(2)],
+            vec![U256::from(3), U256::from(4)],
+        )
+            .abi_encode_params(),
+    );
+    let decoded = token.decode_transfer(&batch).unwrap();
+    assert_eq!(decoded.from, ALICE);
+    assert_eq!(decoded.to, BOB);
+    assert_eq!(decoded.ids, vec![U256::from(1), U256::from(2)]);
+    assert_eq!(decoded.values, vec![U256::from(3), U256::from(4)]);
+}
+
+#[tokio::test]
+async fn test_erc20_allowance_and_transfer_from() {
+    let server = MockServer::start().await;
+    let token = Erc20::new(TOKEN, connect(&server, 31337).await);
+    mock_call(
+        &server,
+        &hex::encode(IERC20::allowanceCall::SELECTOR),
+        U256::from(500).abi_encode(),
+    )
+    .await;
+
+    assert_eq!(token.allowance(ALICE, BOB).await.unwrap(), U256::from(500));
+
+    let data = token.encode_transfer_from(ALICE, BOB, U256::from(5));
+    assert_eq!(
+        &data[..4],
+        &keccak256("transferFrom(address,address,uint256)")[..4]
+    );
+}
+
+#[tokio::test]
+async fn test_erc20_sign_permit() {
+    let server = MockServer::start().await;
+    let token = Erc20::new(TOKEN, connect(&server, 31337).await);
+    let domain_separator = B256::repeat_byte(0x42);
+    mock_call(
+        &server,
+        &hex::encode(IERC20Permit::noncesCall::SELECTOR),
+        U256::from(3).abi_encode(),
+    )
+    .await;
+    mock_call(
+        &server,
+        &hex::encode(IERC20Permit::DOMAIN_SEPARATORCall::SELECTOR),
+        domain_separator.abi_encode(),
+    )
+    .await;
+
+    let wallet = Wallet::from_private_key(ALICE_KEY).unwrap();
+    let deadline = U256::from(1_900_000_000u64);
+    let permit = token
+        .sign_permit(&wallet, BOB, U256::from(1000), deadline)
+        .await
+        .unwrap();
+    assert_eq!(permit.owner, ALICE);
+
+    // The signature recovers to the owner over the EIP-712 digest
+    let struct_hash = Permit {
+        owner: ALICE,
+        spender: BOB,
+        value: U256::from(1000),
+        nonce: U256::from(3),
+        deadline,
+    }
+    .eip712_hash_struct();
+    let digest = keccak256([&[0x19, 0x01][..], &domain_separator[..], &struct_hash[..]].concat());
+    let signature = Signature::new(permit.r.into(), permit.s.into(), permit.v == 28);
+    assert_eq!(
+        signature.recover_address_from_prehash(&digest).unwrap(),
+        ALICE
+    );
+
+    let data = token.encode_permit(&permit);
+    assert_eq!(&data[..4], &IERC20Permit::permitCall::SELECTOR);
+}
@@ -5,8 +5,13 @@
 //! - Testing wallet manager operations
 //! - Testing error handling and edge cases
 
-use alloy::primitives::B256;
-use apex_sdk_evm::wallet::{Wallet, WalletManager};
+use alloy::dyn_abi::TypedData;
+use alloy::primitives::{address, b256, Signature, B256};
+use alloy::sol_types::{eip712_domain, SolStruct};
+use apex_sdk_evm::wallet::{
+    recover_typed_data_signer, recover_typed_struct_signer, verify_typed_data, Wallet,
+    WalletManager,
+};
 
 // ============================================================================
 // Test Constants
@@ -298,6 +303,140 @@ async fn test_wallet_sign_typed_data_hash_different_hashes() {
     assert_ne!(sig1.as_bytes(), sig2.as_bytes());
 }
 
+// Example from the EIP-712 specification, signed with keccak256("cow")
+const EIP712_MAIL: &str = r#"{
+    "types": {
+        "EIP712Domain": [
+            {"name": "name", "type": "string"},
+            {"name": "version", "type": "string"},
+            {"name": "chainId", "type": "uint256"},
+            {"name": "verifyingContract", "type": "address"}
+        ],
+        "Person": [
+            {"name": "name", "type": "string"},
+            {"name": "wallet", "type": "address"}
+        ],
+        "Mail": [
+            {"name": "from", "type": "Person"},
+            {"name": "to", "type": "Person"},
+            {"name": "contents", "type": "string"}
+        ]
+    },
+    "primaryType": "Mail",
+    "domain": {
+        "name": "Ether Mail",
+        "version": "1",
+        "chainId": 1,
+        "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+    },
+    "message": {
+        "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+        "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+        "contents": "Hello, Bob!"
+    }
+}"#;
+const COW_PRIVATE_KEY: &str = "0xc85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4";
+
+alloy::sol! {
+    struct Person {
+        string name;
+        address wallet;
+    }
+
+    struct Mail {
+        Person from;
+        Person to;
+        string contents;
+    }
+}
+
+#[tokio::test]
+async fn test_wallet_sign_typed_data_spec_vector() {
+    let wallet = Wallet::from_private_key(COW_PRIVATE_KEY).unwrap();
+    let typed_data: TypedData = serde_json::from_str(EIP712_MAIL).unwrap();
+
+    assert_eq!(
+        typed_data.eip712_signing_hash().unwrap(),
+        b256!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+    );
+
+    let signature = wallet.sign_typed_data(&typed_data).await.unwrap();
+    assert_eq!(
+        B256::from(signature.r()),
+        b256!("4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d")
+    );
+    assert_eq!(
+        B256::from(signature.s()),
+        b256!("07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562")
+    );
+    assert!(signature.v());
+
+    let from_json = wallet.sign_typed_data_json(EIP712_MAIL).await.unwrap();
+    assert_eq!(from_json, signature);
+}
+
+#[tokio::test]
+async fn test_wallet_sign_typed_struct_matches_json() {
+    let wallet = Wallet::from_private_key(COW_PRIVATE_KEY).unwrap();
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: address!("CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    };
+    let mail = Mail {
+        from: Person {
+            name: "Cow".to_string(),
+            wallet: address!("CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+        },
+        to: Person {
+            name: "Bob".to_string(),
+            wallet: address!("bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+        },
+        contents: "Hello, Bob!".to_string(),
+    };
+
+    let signature = wallet.sign_typed_struct(&mail, &domain).await.unwrap();
+    let typed_data: TypedData = serde_json::from_str(EIP712_MAIL).unwrap();
+    assert_eq!(
+        signature,
+        wallet.sign_typed_data(&typed_data).await.unwrap()
+    );
+    assert_eq!(
+        recover_typed_struct_signer(&mail, &domain, &signature).unwrap(),
+        wallet.eth_address()
+    );
+    assert_eq!(
+        mail.eip712_signing_hash(&domain),
+        typed_data.eip712_signing_hash().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_verify_typed_data() {
+    let wallet = Wallet::new_random();
+    let typed_data: TypedData = serde_json::from_str(EIP712_MAIL).unwrap();
+    let signature = wallet.sign_typed_data(&typed_data).await.unwrap();
+
+    assert_eq!(
+        recover_typed_data_signer(&typed_data, &signature).unwrap(),
+        wallet.eth_address()
+    );
+    assert!(verify_typed_data(&typed_data, &signature, wallet.eth_address()).unwrap());
+
+    let other = Wallet::new_random();
+    assert!(!verify_typed_data(&typed_data, &signature, other.eth_address()).unwrap());
+
+    let tampered = Signature::new(signature.r(), signature.s(), !signature.v());
+    assert!(!verify_typed_data(&typed_data, &tampered, wallet.eth_address()).unwrap_or(false));
+}
+
+#[tokio::test]
+async fn test_wallet_sign_typed_data_json_invalid() {
+    let wallet = Wallet::new_random();
+    assert!(wallet.sign_typed_data_json("{\"types\": 1}").await.is_err());
+}
+
 // ============================================================================
 // Export Private Key Tests
 // ============================================================================
@@ -6,11 +6,20 @@
 //! - Extract block metadata (timestamp, extrinsics, events)
 //! - Detect block finality
 //! - Parse extrinsics and compute hashes
+//! - Follow best or finalized blocks with gap and reorg detection
 
 use crate::Error;
-use apex_sdk_core::{BlockEvent, BlockInfo, DetailedBlockInfo, ExtrinsicInfo};
+use apex_sdk_core::{
+    BlockEvent, BlockInfo, BlockSubscriptionMode, DetailedBlockInfo, ExtrinsicInfo, HeadTracker,
+    NewHead,
+};
 use subxt::{OnlineClient, PolkadotConfig};
-use tracing::debug;
+use tracing::{debug, warn};
+
+/// Maximum number of missing blocks fetched when the stream skips ahead
+const MAX_BACKFILL_DEPTH: usize = 256;
+
+type SubxtBlock = subxt::blocks::Block<PolkadotConfig, OnlineClient<PolkadotConfig>>;
 
 /// Block query client for retrieving and parsing block information
 pub struct BlockQuery {
@@ -429,6 +438,146 @@ impl BlockQuery {
     }
 }
 
+/// Block watcher following best or finalized blocks
+pub struct BlockWatcher {
+    client: OnlineClient<PolkadotConfig>,
+    tracker_depth: usize,
+}
+
+impl BlockWatcher {
+    /// Create a new BlockWatcher instance
+    pub fn new(client: OnlineClient<PolkadotConfig>) -> Self {
+        Self {
+            client,
+            tracker_depth: 64,
+        }
+    }
+
+    /// Set how many recent blocks are remembered for reorg detection
+    pub fn with_tracker_depth(mut self, depth: usize) -> Self {
+        self.tracker_depth = depth;
+        self
+    }
+
+    /// Watch new blocks
+    ///
+    /// Passes every new block to `on_block` in order. Blocks skipped by the
+    /// underlying stream are backfilled via their parent hashes, and when the
+    /// best chain switches forks the new canonical blocks are emitted again
+    /// with `reorged` set. Returns once `on_block` returns `false`.
+    pub async fn watch<F>(&self, mode: BlockSubscriptionMode, mut on_block: F) -> Result<(), Error>
+    where
+        F: FnMut(NewHead) -> bool,
+    {
+        let blocks = self.client.blocks();
+        let mut subscription = match mode {
+            BlockSubscriptionMode::Best => blocks.subscribe_best().await,
+            BlockSubscriptionMode::Finalized => blocks.subscribe_finalized().await,
+        }
+        .map_err(|e| Error::Connection(format!("Failed to subscribe to blocks: {}", e)))?;
+
+        let mut tracker = HeadTracker::new(self.tracker_depth);
+
+        while let Some(block) = subscription.next().await {
+            let block =
+                block.map_err(|e| Error::Connection(format!("Block stream error: {}", e)))?;
+
+            if tracker.contains(block.number() as u64, &format_hash(block.hash())) {
+                continue;
+            }
+
+            for block in self.connect_to_tracked(block, &tracker).await?.iter().rev() {
+                let number = block.number() as u64;
+                let hash = format_hash(block.hash());
+                let reorged = tracker.record(number, &hash);
+                if reorged {
+                    warn!("Reorg detected at block {}: new hash {}", number, hash);
+                }
+
+                let head = NewHead {
+                    number,
+                    hash,
+                    parent_hash: format_hash(block.header().parent_hash),
+                    timestamp: self.block_timestamp(block).await,
+                    reorged,
+                };
+                if !on_block(head) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(Error::Connection("Block subscription ended".to_string()))
+    }
+
+    /// Walk parents from a new head until it attaches to the emitted chain
+    ///
+    /// Returns the new head followed by its unemitted ancestors, newest first.
+    async fn connect_to_tracked(
+        &self,
+        head: SubxtBlock,
+        tracker: &HeadTracker,
+    ) -> Result<Vec<SubxtBlock>, Error> {
+        let mut pending = vec![head];
+
+        while let Some(oldest) = pending.last() {
+            let parent_hash = oldest.header().parent_hash;
+            if tracker.connects(oldest.number() as u64, &format_hash(parent_hash)) {
+                break;
+            }
+            if pending.len() >= MAX_BACKFILL_DEPTH {
+                warn!(
+                    "Stopped backfilling after {} blocks; emitting from block {}",
+                    MAX_BACKFILL_DEPTH,
+                    oldest.number()
+                );
+                break;
+            }
+
+            let parent = self
+                .client
+                .blocks()
+                .at(parent_hash)
+                .await
+                .map_err(|e| Error::Connection(format!("Failed to traverse blocks: {}", e)))?;
+            pending.push(parent);
+        }
+
+        if pending.len() > 1 {
+            debug!("Backfilled {} blocks", pending.len() - 1);
+        }
+
+        Ok(pending)
+    }
+
+    /// Read `Timestamp.Now` at a block, in seconds
+    ///
+    /// Returns `None` if the chain has no timestamp pallet or the read fails.
+    async fn block_timestamp(&self, block: &SubxtBlock) -> Option<u64> {
+        let query = subxt::dynamic::storage("Timestamp", "Now", ());
+        match block.storage().fetch(&query).await {
+            Ok(Some(value)) => value
+                .to_value()
+                .ok()
+                .and_then(|value| value.as_u128())
+                .map(|millis| (millis / 1000) as u64),
+            Ok(None) => None,
+            Err(e) => {
+                debug!(
+                    "Failed to read timestamp of block {}: {}",
+                    block.number(),
+                    e
+                );
+                None
+            }
+        }
+    }
+}
+
+fn format_hash(hash: subxt::utils::H256) -> String {
+    format!("0x{}", hex::encode(hash))
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
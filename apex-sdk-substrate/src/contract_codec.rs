@@ -0,0 +1,689 @@
+//! SCALE codec for ink! contract values
+//!
+//! Encodes message and constructor arguments using the type registry in
+//! [`ContractMetadata`], and decodes return values back into dynamic
+//! [`Value`]s and JSON.
+//!
+//! Encoding is lenient in the ways JSON input needs:
+//!
+//! - Byte arrays and vectors accept `0x`-prefixed hex strings, and 32-byte
+//!   arrays (such as `AccountId`) also accept SS58 addresses
+//! - Integers accept decimal strings, for amounts beyond JSON's number range
+//! - Unit variants accept their name as a string, and `Option` accepts a bare
+//!   value for `Some` and `null` for `None`
+//! - Single-field composites (newtypes) accept their inner value
+//!
+//! Decoded JSON flattens newtypes, renders byte sequences as hex, `Option` as
+//! the value or `null`, and integers that do not fit in 64 bits as strings.
+
+use crate::contracts::{ContractMetadata, Field, TypeDef, TypeDefVariant, Variant};
+use crate::{Error, Result};
+use parity_scale_codec::{Compact, Decode, Encode};
+use subxt::ext::scale_value::{Composite, Primitive, Value, ValueDef};
+
+/// Encode `value` as the registry type `ty`
+pub(crate) fn encode_value(
+    metadata: &ContractMetadata,
+    ty: u32,
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    let def = type_def(metadata, ty)?;
+
+    match &def.def {
+        TypeDefVariant::Composite { fields } => encode_fields(metadata, fields, value, out),
+        TypeDefVariant::Variant { variants } => encode_variant(metadata, def, variants, value, out),
+        TypeDefVariant::Sequence { type_param } => {
+            let items = sequence_items(metadata, *type_param, value, None)?;
+            Compact(items.len() as u32).encode_to(out);
+            encode_items(metadata, *type_param, &items, out)
+        }
+        TypeDefVariant::Array { len, type_param } => {
+            let items = sequence_items(metadata, *type_param, value, Some(*len))?;
+            if items.len() != *len as usize {
+                return Err(Error::Metadata(format!(
+                    "Expected {} items for type {}, got {}",
+                    len,
+                    ty,
+                    items.len()
+                )));
+            }
+            encode_items(metadata, *type_param, &items, out)
+        }
+        TypeDefVariant::Tuple { fields } => {
+            let values = composite_values(value);
+            if fields.is_empty() {
+                return Ok(());
+            }
+            if values.len() != fields.len() {
+                return Err(Error::Metadata(format!(
+                    "Expected a tuple of {} values for type {}",
+                    fields.len(),
+                    ty
+                )));
+            }
+            for (field_ty, value) in fields.iter().zip(values) {
+                encode_value(metadata, *field_ty, value, out)?;
+            }
+            Ok(())
+        }
+        TypeDefVariant::Primitive { primitive } => encode_primitive(primitive, value, out),
+    }
+}
+
+/// Decode a value of the registry type `ty`
+pub(crate) fn decode_value(
+    metadata: &ContractMetadata,
+    ty: u32,
+    input: &mut &[u8],
+) -> Result<Value> {
+    let def = type_def(metadata, ty)?;
+
+    match &def.def {
+        TypeDefVariant::Composite { fields } => Ok(Value::without_context(ValueDef::Composite(
+            decode_fields(metadata, fields, input)?,
+        ))),
+        TypeDefVariant::Variant { variants } => {
+            let index = u8::decode(input).map_err(|e| decode_error(ty, e))?;
+            let variant = variants
+                .iter()
+                .find(|variant| variant.index == index)
+                .ok_or_else(|| {
+                    Error::Metadata(format!("Unknown variant index {} for type {}", index, ty))
+                })?;
+            let values = decode_fields(metadata, &variant.fields, input)?;
+            Ok(Value::variant(variant.name.clone(), values))
+        }
+        TypeDefVariant::Sequence { type_param } => {
+            let Compact(len) = Compact::<u32>::decode(input).map_err(|e| decode_error(ty, e))?;
+            decode_items(metadata, *type_param, len, input)
+        }
+        TypeDefVariant::Array { len, type_param } => {
+            decode_items(metadata, *type_param, *len, input)
+        }
+        TypeDefVariant::Tuple { fields } => {
+            let values = fields
+                .iter()
+                .map(|field_ty| decode_value(metadata, *field_ty, input))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Value::unnamed_composite(values))
+        }
+        TypeDefVariant::Primitive { primitive } => decode_primitive(ty, primitive, input),
+    }
+}
+
+/// Render a value decoded as type `ty` as JSON
+pub(crate) fn value_to_json(
+    metadata: &ContractMetadata,
+    ty: u32,
+    value: &Value,
+) -> Result<serde_json::Value> {
+    let def = type_def(metadata, ty)?;
+
+    match (&def.def, &value.value) {
+        (TypeDefVariant::Composite { fields }, ValueDef::Composite(composite)) => {
+            fields_to_json(metadata, fields, composite)
+        }
+        (TypeDefVariant::Variant { variants }, ValueDef::Variant(variant)) => {
+            let spec = variants
+                .iter()
+                .find(|spec| spec.name == variant.name)
+                .ok_or_else(|| mismatch(ty))?;
+            let inner = fields_to_json(metadata, &spec.fields, &variant.values)?;
+
+            if is_option(def) {
+                return Ok(if spec.fields.is_empty() {
+                    serde_json::Value::Null
+                } else {
+                    inner
+                });
+            }
+            if spec.fields.is_empty() {
+                return Ok(serde_json::Value::String(spec.name.clone()));
+            }
+            Ok(serde_json::json!({ spec.name.clone(): inner }))
+        }
+        (
+            TypeDefVariant::Sequence { type_param } | TypeDefVariant::Array { type_param, .. },
+            ValueDef::Composite(composite),
+        ) => {
+            if is_u8(metadata, *type_param) {
+                let bytes = composite
+                    .values()
+                    .map(|value| value.as_u128().map(|byte| byte as u8))
+                    .collect::<Option<Vec<u8>>>()
+                    .ok_or_else(|| mismatch(ty))?;
+                return Ok(serde_json::Value::String(format!(
+                    "0x{}",
+                    hex::encode(bytes)
+                )));
+            }
+            composite
+                .values()
+                .map(|value| value_to_json(metadata, *type_param, value))
+                .collect::<Result<Vec<_>>>()
+                .map(serde_json::Value::Array)
+        }
+        (TypeDefVariant::Tuple { fields }, ValueDef::Composite(composite)) => {
+            if fields.is_empty() {
+                return Ok(serde_json::Value::Null);
+            }
+            fields
+                .iter()
+                .zip(composite.values())
+                .map(|(field_ty, value)| value_to_json(metadata, *field_ty, value))
+                .collect::<Result<Vec<_>>>()
+                .map(serde_json::Value::Array)
+        }
+        (TypeDefVariant::Primitive { .. }, ValueDef::Primitive(primitive)) => {
+            Ok(primitive_to_json(primitive))
+        }
+        _ => Err(mismatch(ty)),
+    }
+}
+
+/// Convert JSON into a dynamic value for [`encode_value`]
+///
+/// The conversion is untyped; [`encode_value`] resolves objects that name a
+/// variant, hex strings and numeric strings against the registry type.
+pub(crate) fn json_to_value(json: &serde_json::Value) -> Result<Value> {
+    Ok(match json {
+        serde_json::Value::Null => Value::unnamed_variant("None", vec![]),
+        serde_json::Value::Bool(b) => Value::bool(*b),
+        serde_json::Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                Value::u128(n as u128)
+            } else if let Some(n) = n.as_i64() {
+                Value::i128(n as i128)
+            } else {
+                return Err(Error::Metadata(format!(
+                    "Unsupported number {}: contract values must be integers",
+                    n
+                )));
+            }
+        }
+        serde_json::Value::String(s) => Value::string(s.clone()),
+        serde_json::Value::Array(items) => Value::unnamed_composite(
+            items
+                .iter()
+                .map(json_to_value)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        serde_json::Value::Object(fields) => Value::named_composite(
+            fields
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), json_to_value(value)?)))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+    })
+}
+
+/// Check whether `ty` is a `Result` type whose error is ink!'s `LangError`
+///
+/// Returns the `Ok` type if so.
+pub(crate) fn lang_error_ok_type(metadata: &ContractMetadata, ty: u32) -> Option<u32> {
+    let (ok, err) = result_types(metadata, ty)?;
+    let err = type_def(metadata, err).ok()?;
+    (err.path.last().map(String::as_str) == Some("LangError")).then_some(ok)
+}
+
+/// `Ok` and `Err` types of a `Result` type
+pub(crate) fn result_types(metadata: &ContractMetadata, ty: u32) -> Option<(u32, u32)> {
+    let def = type_def(metadata, ty).ok()?;
+    if def.path.last().map(String::as_str) != Some("Result") {
+        return None;
+    }
+    let TypeDefVariant::Variant { variants } = &def.def else {
+        return None;
+    };
+    let field_of = |name: &str| {
+        variants
+            .iter()
+            .find(|variant| variant.name == name)
+            .and_then(|variant| variant.fields.first())
+            .map(|field| field.ty)
+    };
+    Some((field_of("Ok")?, field_of("Err")?))
+}
+
+fn type_def(metadata: &ContractMetadata, ty: u32) -> Result<&TypeDef> {
+    metadata
+        .types
+        .iter()
+        .find(|def| def.id == ty)
+        .ok_or_else(|| Error::Metadata(format!("Type {} not found in contract metadata", ty)))
+}
+
+fn encode_fields(
+    metadata: &ContractMetadata,
+    fields: &[Field],
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    if let ValueDef::Composite(composite) = &value.value {
+        let start = out.len();
+        match encode_composite(metadata, fields, composite, out) {
+            Ok(()) => return Ok(()),
+            // Fall back to treating the whole value as a newtype's inner value
+            Err(_) if fields.len() == 1 => out.truncate(start),
+            Err(e) => return Err(e),
+        }
+    }
+
+    match fields {
+        [] => Ok(()),
+        [field] => encode_value(metadata, field.ty, value, out),
+        _ => Err(Error::Metadata(format!(
+            "Expected a composite of {} fields",
+            fields.len()
+        ))),
+    }
+}
+
+fn encode_composite(
+    metadata: &ContractMetadata,
+    fields: &[Field],
+    composite: &Composite<()>,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    match composite {
+        Composite::Named(values) if fields.iter().all(|field| field.name.is_some()) => {
+            for field in fields {
+                let name = field.name.as_deref().unwrap_or_default();
+                let value = values
+                    .iter()
+                    .find(|(value_name, _)| value_name == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| Error::Metadata(format!("Missing field '{}'", name)))?;
+                encode_value(metadata, field.ty, value, out)?;
+            }
+            Ok(())
+        }
+        _ if composite.len() == fields.len() => {
+            for (field, value) in fields.iter().zip(composite.values()) {
+                encode_value(metadata, field.ty, value, out)?;
+            }
+            Ok(())
+        }
+        _ => Err(Error::Metadata(format!(
+            "Expected {} fields, got {}",
+            fields.len(),
+            composite.len()
+        ))),
+    }
+}
+
+fn encode_variant(
+    metadata: &ContractMetadata,
+    def: &TypeDef,
+    variants: &[Variant],
+    value: &Value,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    let find = |name: &str| {
+        variants
+            .iter()
+            .find(|variant| variant.name == name)
+            .ok_or_else(|| Error::Metadata(format!("Unknown variant '{}'", name)))
+    };
+
+    match &value.value {
+        ValueDef::Variant(variant) => {
+            if let Ok(spec) = find(&variant.name) {
+                out.push(spec.index);
+                return encode_composite(metadata, &spec.fields, &variant.values, out);
+            }
+        }
+        // A unit variant given by name
+        ValueDef::Primitive(Primitive::String(name)) => {
+            if let Some(spec) = variants
+                .iter()
+                .find(|spec| &spec.name == name && spec.fields.is_empty())
+            {
+                out.push(spec.index);
+                return Ok(());
+            }
+        }
+        // A variant given as `{ "Name": fields }`
+        ValueDef::Composite(Composite::Named(fields)) if fields.len() == 1 => {
+            let (name, inner) = &fields[0];
+            if let Ok(spec) = find(name) {
+                out.push(spec.index);
+                return encode_fields(metadata, &spec.fields, inner, out);
+            }
+        }
+        _ => {}
+    }
+
+    // A bare value for `Some`
+    if is_option(def) {
+        let some = find("Some")?;
+        out.push(some.index);
+        return encode_fields(metadata, &some.fields, value, out);
+    }
+
+    Err(Error::Metadata(format!(
+        "Value {} does not match any variant of {}",
+        value,
+        def.path.join("::")
+    )))
+}
+
+/// Items of a sequence or array value, decoding hex and SS58 strings as bytes
+fn sequence_items(
+    metadata: &ContractMetadata,
+    item_ty: u32,
+    value: &Value,
+    len: Option<u32>,
+) -> Result<Vec<Value>> {
+    if let ValueDef::Primitive(Primitive::String(s)) = &value.value {
+        if is_u8(metadata, item_ty) {
+            return Ok(string_bytes(s, len)?
+                .into_iter()
+                .map(|byte| Value::u128(byte as u128))
+                .collect());
+        }
+    }
+
+    Ok(composite_values(value).into_iter().cloned().collect())
+}
+
+fn string_bytes(s: &str, len: Option<u32>) -> Result<Vec<u8>> {
+    if let Some(hex_str) = s.strip_prefix("0x") {
+        return hex::decode(hex_str)
+            .map_err(|e| Error::Metadata(format!("Invalid hex string {}: {}", s, e)));
+    }
+
+    if len == Some(32) {
+        use sp_core::crypto::Ss58Codec;
+        if let Ok(account) = sp_core::crypto::AccountId32::from_ss58check(s) {
+            let bytes: &[u8] = account.as_ref();
+            return Ok(bytes.to_vec());
+        }
+    }
+
+    Err(Error::Metadata(format!(
+        "Expected a 0x-prefixed hex string{}, got {}",
+        if len == Some(32) {
+            " or SS58 address"
+        } else {
+            ""
+        },
+        s
+    )))
+}
+
+fn encode_items(
+    metadata: &ContractMetadata,
+    item_ty: u32,
+    items: &[Value],
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    for item in items {
+        encode_value(metadata, item_ty, item, out)?;
+    }
+    Ok(())
+}
+
+fn encode_primitive(primitive: &str, value: &Value, out: &mut Vec<u8>) -> Result<()> {
+    let invalid = || Error::Metadata(format!("Expected a {} value, got {}", primitive, value));
+
+    let ValueDef::Primitive(prim) = &value.value else {
+        return Err(invalid());
+    };
+
+    match primitive {
+        "bool" => match prim {
+            Primitive::Bool(b) => b.encode_to(out),
+            _ => return Err(invalid()),
+        },
+        "char" => match prim {
+            Primitive::Char(c) => (*c as u32).encode_to(out),
+            Primitive::String(s) if s.chars().count() == 1 => {
+                (s.chars().next().unwrap_or_default() as u32).encode_to(out)
+            }
+            _ => return Err(invalid()),
+        },
+        "str" => match prim {
+            Primitive::String(s) => s.encode_to(out),
+            _ => return Err(invalid()),
+        },
+        "u8" | "u16" | "u32" | "u64" | "u128" => {
+            let n = unsigned(prim).ok_or_else(invalid)?;
+            let bytes = int_width(primitive);
+            if bytes < 16 && n >> (bytes * 8) != 0 {
+                return Err(Error::Metadata(format!(
+                    "{} does not fit in {}",
+                    n, primitive
+                )));
+            }
+            out.extend_from_slice(&n.to_le_bytes()[..bytes]);
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" => {
+            let n = signed(prim).ok_or_else(invalid)?;
+            let bits = int_width(primitive) * 8;
+            if bits < 128 && (n < -(1i128 << (bits - 1)) || n >= 1i128 << (bits - 1)) {
+                return Err(Error::Metadata(format!(
+                    "{} does not fit in {}",
+                    n, primitive
+                )));
+            }
+            out.extend_from_slice(&n.to_le_bytes()[..bits / 8]);
+        }
+        "u256" => match prim {
+            Primitive::U256(bytes) => out.extend_from_slice(bytes),
+            _ => {
+                let n = unsigned(prim).ok_or_else(invalid)?;
+                out.extend_from_slice(&n.to_le_bytes());
+                out.extend_from_slice(&[0u8; 16]);
+            }
+        },
+        "i256" => match prim {
+            Primitive::I256(bytes) => out.extend_from_slice(bytes),
+            _ => {
+                let n = signed(prim).ok_or_else(invalid)?;
+                let fill = if n < 0 { 0xff } else { 0 };
+                out.extend_from_slice(&n.to_le_bytes());
+                out.extend_from_slice(&[fill; 16]);
+            }
+        },
+        other => {
+            return Err(Error::Metadata(format!(
+                "Unsupported primitive type {}",
+                other
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_fields(
+    metadata: &ContractMetadata,
+    fields: &[Field],
+    input: &mut &[u8],
+) -> Result<Composite<()>> {
+    let named = !fields.is_empty() && fields.iter().all(|field| field.name.is_some());
+
+    if named {
+        fields
+            .iter()
+            .map(|field| {
+                Ok((
+                    field.name.clone().unwrap_or_default(),
+                    decode_value(metadata, field.ty, input)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Composite::Named)
+    } else {
+        fields
+            .iter()
+            .map(|field| decode_value(metadata, field.ty, input))
+            .collect::<Result<Vec<_>>>()
+            .map(Composite::Unnamed)
+    }
+}
+
+fn decode_items(
+    metadata: &ContractMetadata,
+    item_ty: u32,
+    len: u32,
+    input: &mut &[u8],
+) -> Result<Value> {
+    // Every item takes at least one byte, except zero-sized ones
+    if len as usize > input.len() && !is_zero_sized(metadata, item_ty) {
+        return Err(Error::Metadata(format!(
+            "Sequence of {} items exceeds the remaining {} bytes",
+            len,
+            input.len()
+        )));
+    }
+
+    let items = (0..len)
+        .map(|_| decode_value(metadata, item_ty, input))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Value::unnamed_composite(items))
+}
+
+fn decode_primitive(ty: u32, primitive: &str, input: &mut &[u8]) -> Result<Value> {
+    let error = |e: parity_scale_codec::Error| decode_error(ty, e);
+
+    Ok(match primitive {
+        "bool" => Value::bool(bool::decode(input).map_err(error)?),
+        "char" => {
+            let code = u32::decode(input).map_err(error)?;
+            Value::char(
+                char::from_u32(code).ok_or_else(|| {
+                    Error::Metadata(format!("Invalid char {} for type {}", code, ty))
+                })?,
+            )
+        }
+        "str" => Value::string(String::decode(input).map_err(error)?),
+        "u8" => Value::u128(u8::decode(input).map_err(error)? as u128),
+        "u16" => Value::u128(u16::decode(input).map_err(error)? as u128),
+        "u32" => Value::u128(u32::decode(input).map_err(error)? as u128),
+        "u64" => Value::u128(u64::decode(input).map_err(error)? as u128),
+        "u128" => Value::u128(u128::decode(input).map_err(error)?),
+        "i8" => Value::i128(i8::decode(input).map_err(error)? as i128),
+        "i16" => Value::i128(i16::decode(input).map_err(error)? as i128),
+        "i32" => Value::i128(i32::decode(input).map_err(error)? as i128),
+        "i64" => Value::i128(i64::decode(input).map_err(error)? as i128),
+        "i128" => Value::i128(i128::decode(input).map_err(error)?),
+        "u256" => Value::primitive(Primitive::U256(<[u8; 32]>::decode(input).map_err(error)?)),
+        "i256" => Value::primitive(Primitive::I256(<[u8; 32]>::decode(input).map_err(error)?)),
+        other => {
+            return Err(Error::Metadata(format!(
+                "Unsupported primitive type {}",
+                other
+            )))
+        }
+    })
+}
+
+fn fields_to_json(
+    metadata: &ContractMetadata,
+    fields: &[Field],
+    composite: &Composite<()>,
+) -> Result<serde_json::Value> {
+    match (fields, composite) {
+        ([], _) => Ok(serde_json::Value::Null),
+        (_, Composite::Named(values)) => {
+            let mut object = serde_json::Map::new();
+            for (field, (name, value)) in fields.iter().zip(values) {
+                object.insert(name.clone(), value_to_json(metadata, field.ty, value)?);
+            }
+            Ok(serde_json::Value::Object(object))
+        }
+        // Newtypes render as their inner value
+        ([field], Composite::Unnamed(values)) if values.len() == 1 => {
+            value_to_json(metadata, field.ty, &values[0])
+        }
+        (_, Composite::Unnamed(values)) => fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| value_to_json(metadata, field.ty, value))
+            .collect::<Result<Vec<_>>>()
+            .map(serde_json::Value::Array),
+    }
+}
+
+fn primitive_to_json(primitive: &Primitive) -> serde_json::Value {
+    match primitive {
+        Primitive::Bool(b) => serde_json::Value::Bool(*b),
+        Primitive::Char(c) => serde_json::Value::String(c.to_string()),
+        Primitive::String(s) => serde_json::Value::String(s.clone()),
+        Primitive::U128(n) => match u64::try_from(*n) {
+            Ok(n) => serde_json::Value::from(n),
+            Err(_) => serde_json::Value::String(n.to_string()),
+        },
+        Primitive::I128(n) => match i64::try_from(*n) {
+            Ok(n) => serde_json::Value::from(n),
+            Err(_) => serde_json::Value::String(n.to_string()),
+        },
+        // Big-endian hex, as 256-bit integers have no JSON representation
+        Primitive::U256(bytes) | Primitive::I256(bytes) => {
+            let mut be = *bytes;
+            be.reverse();
+            serde_json::Value::String(format!("0x{}", hex::encode(be)))
+        }
+    }
+}
+
+fn composite_values(value: &Value) -> Vec<&Value> {
+    match &value.value {
+        ValueDef::Composite(composite) => composite.values().collect(),
+        _ => vec![value],
+    }
+}
+
+fn unsigned(primitive: &Primitive) -> Option<u128> {
+    match primitive {
+        Primitive::U128(n) => Some(*n),
+        Primitive::I128(n) => u128::try_from(*n).ok(),
+        Primitive::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn signed(primitive: &Primitive) -> Option<i128> {
+    match primitive {
+        Primitive::I128(n) => Some(*n),
+        Primitive::U128(n) => i128::try_from(*n).ok(),
+        Primitive::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Width in bytes of an integer primitive such as `u32` or `i64`
+fn int_width(primitive: &str) -> usize {
+    primitive[1..].parse::<usize>().unwrap_or(128) / 8
+}
+
+fn is_u8(metadata: &ContractMetadata, ty: u32) -> bool {
+    matches!(
+        type_def(metadata, ty).map(|def| &def.def),
+        Ok(TypeDefVariant::Primitive { primitive }) if primitive == "u8"
+    )
+}
+
+fn is_zero_sized(metadata: &ContractMetadata, ty: u32) -> bool {
+    match type_def(metadata, ty).map(|def| &def.def) {
+        Ok(TypeDefVariant::Tuple { fields }) => fields.is_empty(),
+        Ok(TypeDefVariant::Composite { fields }) => fields.is_empty(),
+        Ok(TypeDefVariant::Array { len, .. }) => *len == 0,
+        _ => false,
+    }
+}
+
+fn is_option(def: &TypeDef) -> bool {
+    def.path.last().map(String::as_str) == Some("Option")
+}
+
+fn decode_error(ty: u32, e: parity_scale_codec::Error) -> Error {
+    Error::Metadata(format!("Failed to decode type {}: {}", ty, e))
+}
+
+fn mismatch(ty: u32) -> Error {
+    Error::Metadata(format!("Value does not match type {}", ty))
+}
@@ -3,6 +3,11 @@
 //! This module provides functionality for deploying and interacting with
 //! ink! smart contracts on Substrate-based chains.
 //!
+//! Both `pallet-contracts` (Wasm, 32-byte addresses) and `pallet-revive`
+//! (PolkaVM, H160 addresses) are supported; the pallet is detected from the
+//! runtime metadata. Revive contracts are addressed by their fallback account
+//! ID ([`revive_account_id`]) so [`ContractClient`] has one API for both.
+//!
 //! ## Features
 //!
 //! - Deploy compiled ink! contracts (Wasm)
@@ -24,25 +29,111 @@
 //!     constructor_args,
 //! ).await?;
 //!
-//! // Call a contract method
+//! // Call a contract method, encoding arguments from the metadata
 //! let result = contract
-//!     .call("transfer")
-//!     .args(&[recipient, amount])
-//!     .execute(&wallet)
+//!     .call_json("transfer", &json!({ "to": recipient, "value": "1000" }), &wallet)
 //!     .await?;
+//! for event in &result.events {
+//!     println!("{}: {}", event.name, event.json);
+//! }
+//!
+//! // Read a value, decoded into JSON
+//! let balance = contract.read_json("balance_of", &json!([owner]), &caller).await?;
 //! ```
 
-use crate::{Error, Result, Sr25519Signer, Wallet};
+use crate::dispatch_error::transaction_error;
+use crate::{contract_codec, Error, Result, Sr25519Signer, Wallet};
 use serde::{Deserialize, Serialize};
+use subxt::dynamic::Value;
+use subxt::events::EventDetails;
+use subxt::ext::scale_value::{Composite, ValueDef};
 use subxt::{OnlineClient, PolkadotConfig};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Contract address type (32-byte account ID)
 pub type ContractAddress = [u8; 32];
 
+/// Suffix of account IDs derived from an H160 address in pallet-revive
+const REVIVE_ACCOUNT_SUFFIX: [u8; 12] = [0xEE; 12];
+
+/// Contracts pallet exposed by a runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractsPallet {
+    /// `pallet-contracts`: Wasm code, 32-byte addresses, `ContractsApi`
+    Contracts,
+    /// `pallet-revive`: PolkaVM code, H160 addresses, `ReviveApi`
+    Revive,
+}
+
+impl ContractsPallet {
+    /// Detect the contracts pallet from the runtime metadata
+    ///
+    /// Prefers `Contracts` if a runtime has both.
+    pub fn detect(client: &OnlineClient<PolkadotConfig>) -> Option<Self> {
+        let metadata = client.metadata();
+        if metadata.pallet_by_name("Contracts").is_some() {
+            Some(Self::Contracts)
+        } else if metadata.pallet_by_name("Revive").is_some() {
+            Some(Self::Revive)
+        } else {
+            None
+        }
+    }
+
+    /// Pallet name in the runtime
+    pub fn pallet_name(self) -> &'static str {
+        match self {
+            Self::Contracts => "Contracts",
+            Self::Revive => "Revive",
+        }
+    }
+
+    /// Runtime API used for dry runs
+    fn runtime_api(self) -> &'static str {
+        match self {
+            Self::Contracts => "ContractsApi",
+            Self::Revive => "ReviveApi",
+        }
+    }
+}
+
+/// Account ID pallet-revive uses for an H160 address
+///
+/// This is the fallback account (`address ++ [0xEE; 12]`) that contracts and
+/// Ethereum-derived accounts have.
+pub fn revive_account_id(address: [u8; 20]) -> ContractAddress {
+    let mut account = [0u8; 32];
+    account[..20].copy_from_slice(&address);
+    account[20..].copy_from_slice(&REVIVE_ACCOUNT_SUFFIX);
+    account
+}
+
+/// H160 address pallet-revive maps an account ID to
+///
+/// Fallback accounts map back to their address; other accounts map to the
+/// last 20 bytes of their Keccak-256 hash.
+pub fn revive_address(account: &ContractAddress) -> [u8; 20] {
+    let mut address = [0u8; 20];
+    if is_eth_derived(account) {
+        address.copy_from_slice(&account[..20]);
+    } else {
+        address.copy_from_slice(&sp_core::keccak_256(account)[12..]);
+    }
+    address
+}
+
+fn is_eth_derived(account: &ContractAddress) -> bool {
+    account[20..] == REVIVE_ACCOUNT_SUFFIX
+}
+
 /// Contract metadata from the ink! compilation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractMetadata {
+    /// Metadata format version (`4` for ink! v4, `5` for ink! v5)
+    ///
+    /// ink! v4 writes the version as a string and ink! v5 as a number.
+    #[serde(default, deserialize_with = "deserialize_version")]
+    pub version: Option<u32>,
     /// Contract specification
     pub spec: ContractSpec,
     /// Storage layout
@@ -51,6 +142,317 @@ pub struct ContractMetadata {
     pub types: Vec<TypeDef>,
 }
 
+impl ContractMetadata {
+    /// Find a message by label
+    pub fn message(&self, label: &str) -> Result<&MessageSpec> {
+        self.spec
+            .messages
+            .iter()
+            .find(|m| m.label == label)
+            .ok_or_else(|| Error::Transaction(format!("Method '{}' not found", label)))
+    }
+
+    /// Find a constructor by label
+    pub fn constructor(&self, label: &str) -> Result<&ConstructorSpec> {
+        self.spec
+            .constructors
+            .iter()
+            .find(|c| c.label == label)
+            .ok_or_else(|| Error::Transaction(format!("Constructor '{}' not found", label)))
+    }
+
+    /// SCALE-encode message arguments from dynamic values
+    ///
+    /// The result excludes the selector, matching the `args` parameter of
+    /// [`ContractClient::call`] and [`ContractClient::read`].
+    pub fn encode_message_args(&self, label: &str, args: &[Value]) -> Result<Vec<u8>> {
+        self.encode_args(&self.message(label)?.args, args)
+    }
+
+    /// SCALE-encode message arguments from JSON
+    ///
+    /// `args` is an array in argument order, an object keyed by argument
+    /// label, or `null` for messages without arguments.
+    pub fn encode_message_json(&self, label: &str, args: &serde_json::Value) -> Result<Vec<u8>> {
+        let spec = &self.message(label)?.args;
+        self.encode_args(spec, &json_args(spec, args)?)
+    }
+
+    /// SCALE-encode constructor arguments from dynamic values
+    pub fn encode_constructor_args(&self, label: &str, args: &[Value]) -> Result<Vec<u8>> {
+        self.encode_args(&self.constructor(label)?.args, args)
+    }
+
+    /// SCALE-encode constructor arguments from JSON
+    pub fn encode_constructor_json(
+        &self,
+        label: &str,
+        args: &serde_json::Value,
+    ) -> Result<Vec<u8>> {
+        let spec = &self.constructor(label)?.args;
+        self.encode_args(spec, &json_args(spec, args)?)
+    }
+
+    /// SCALE-encode a value as the registry type `ty`
+    pub fn encode_value(&self, ty: u32, value: &Value) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        contract_codec::encode_value(self, ty, value, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decode a value of the registry type `ty`
+    pub fn decode_value(&self, ty: u32, data: &[u8]) -> Result<Value> {
+        let mut input = data;
+        let value = contract_codec::decode_value(self, ty, &mut input)?;
+        if !input.is_empty() {
+            return Err(Error::Metadata(format!(
+                "{} trailing bytes after decoding type {}",
+                input.len(),
+                ty
+            )));
+        }
+        Ok(value)
+    }
+
+    /// Render a value decoded as the registry type `ty` as JSON
+    pub fn value_to_json(&self, ty: u32, value: &Value) -> Result<serde_json::Value> {
+        contract_codec::value_to_json(self, ty, value)
+    }
+
+    /// Decode the return data of a message
+    ///
+    /// ink! 4+ messages return `Result<T, LangError>`; a `LangError` (such as
+    /// `CouldNotReadInput` for bad arguments) is returned as an error and
+    /// `T` is unwrapped. If `T` is itself a `Result`, a contract-level `Err`
+    /// is reported in [`MessageReturn::error`].
+    pub fn decode_message_return(&self, label: &str, data: &[u8]) -> Result<MessageReturn> {
+        let Some(return_type) = &self.message(label)?.return_type else {
+            return Ok(MessageReturn {
+                value: Value::unnamed_composite(vec![]),
+                json: serde_json::Value::Null,
+                error: None,
+            });
+        };
+
+        let mut ty = return_type.ty;
+        let mut value = self.decode_value(ty, data)?;
+
+        if let Some(ok_ty) = contract_codec::lang_error_ok_type(self, ty) {
+            let ValueDef::Variant(variant) = value.value else {
+                return Err(Error::Metadata(format!(
+                    "Return value of '{}' is not a Result",
+                    label
+                )));
+            };
+            let inner = variant.values.into_values().next();
+            match (variant.name.as_str(), inner) {
+                ("Ok", Some(inner)) => {
+                    ty = ok_ty;
+                    value = inner;
+                }
+                (_, inner) => {
+                    return Err(Error::Transaction(format!(
+                        "Message '{}' could not be dispatched: {}",
+                        label,
+                        inner.map_or_else(|| variant.name.clone(), |e| e.to_string())
+                    )));
+                }
+            }
+        }
+
+        let json = self.value_to_json(ty, &value)?;
+        let error = match (&value.value, contract_codec::result_types(self, ty)) {
+            (ValueDef::Variant(variant), Some((_, err_ty))) if variant.name == "Err" => {
+                match variant.values.values().next() {
+                    Some(err) => Some(self.value_to_json(err_ty, err)?),
+                    None => Some(serde_json::Value::Null),
+                }
+            }
+            _ => None,
+        };
+
+        Ok(MessageReturn { value, json, error })
+    }
+
+    /// Decode the data of a `Contracts.ContractEmitted` event
+    ///
+    /// The event format follows the metadata [`version`](Self::version).
+    /// ink! v5 events are matched on their signature topic, the first of the
+    /// event's `topics`, and anonymous events by trying each in turn. ink! v4
+    /// events are matched on the index in the first byte of `data`.
+    pub fn decode_event(&self, data: &[u8], topics: &[[u8; 32]]) -> Result<ContractEvent> {
+        let events = &self.spec.events;
+
+        match self.version {
+            Some(version) if version >= 5 => {}
+            Some(4) => {
+                // ink! v4 prefixes the fields with the event's index
+                let (&index, fields) = data
+                    .split_first()
+                    .ok_or_else(|| Error::Metadata("Contract event data is empty".to_string()))?;
+                let spec = events.get(index as usize).ok_or_else(|| {
+                    Error::Metadata(format!("Contract event index {} not in metadata", index))
+                })?;
+                return self.decode_event_fields(spec, fields);
+            }
+            Some(version) => {
+                return Err(Error::Metadata(format!(
+                    "Unsupported contract metadata version {}",
+                    version
+                )))
+            }
+            None => {
+                return Err(Error::Metadata(
+                    "Contract metadata has no version to decode events with".to_string(),
+                ))
+            }
+        }
+
+        if let Some(topic) = topics.first() {
+            let matching = events.iter().find(|event| {
+                event.signature_topic.as_deref().and_then(parse_topic) == Some(*topic)
+            });
+            if let Some(spec) = matching {
+                return self.decode_event_fields(spec, data);
+            }
+        }
+
+        // Anonymous events have no signature topic to match on
+        events
+            .iter()
+            .filter(|event| event.signature_topic.is_none())
+            .find_map(|spec| self.decode_event_fields(spec, data).ok())
+            .ok_or_else(|| {
+                Error::Metadata(
+                    "No event in the contract metadata matches the emitted event".to_string(),
+                )
+            })
+    }
+
+    fn decode_event_fields(&self, spec: &EventSpec, data: &[u8]) -> Result<ContractEvent> {
+        let mut input = data;
+        let mut fields = Vec::with_capacity(spec.args.len());
+        let mut json = serde_json::Map::new();
+
+        for arg in &spec.args {
+            let value =
+                contract_codec::decode_value(self, arg.type_ref.ty, &mut input).map_err(|e| {
+                    Error::Metadata(format!(
+                        "Failed to decode field '{}' of event {}: {}",
+                        arg.label, spec.label, e
+                    ))
+                })?;
+            json.insert(
+                arg.label.clone(),
+                self.value_to_json(arg.type_ref.ty, &value)?,
+            );
+            fields.push((arg.label.clone(), value));
+        }
+
+        if !input.is_empty() {
+            return Err(Error::Metadata(format!(
+                "{} trailing bytes after decoding event {}",
+                input.len(),
+                spec.label
+            )));
+        }
+
+        Ok(ContractEvent {
+            name: spec.label.clone(),
+            fields,
+            json: serde_json::Value::Object(json),
+        })
+    }
+
+    fn encode_args(&self, spec: &[MessageArg], args: &[Value]) -> Result<Vec<u8>> {
+        if spec.len() != args.len() {
+            return Err(Error::Transaction(format!(
+                "Expected {} arguments, got {}",
+                spec.len(),
+                args.len()
+            )));
+        }
+
+        let mut out = Vec::new();
+        for (arg, value) in spec.iter().zip(args) {
+            contract_codec::encode_value(self, arg.type_ref.ty, value, &mut out).map_err(|e| {
+                Error::Transaction(format!("Failed to encode argument '{}': {}", arg.label, e))
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+/// Decoded return value of a message
+#[derive(Debug, Clone)]
+pub struct MessageReturn {
+    /// Decoded value, with `Result<T, LangError>` unwrapped
+    pub value: Value,
+    /// The value as JSON
+    pub json: serde_json::Value,
+    /// The error if the message returned a contract-level `Err`
+    pub error: Option<serde_json::Value>,
+}
+
+impl MessageReturn {
+    /// Check whether the message returned a contract-level `Err`
+    pub fn is_err(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Decoded ink! contract event
+#[derive(Debug, Clone)]
+pub struct ContractEvent {
+    /// Event name from the metadata
+    pub name: String,
+    /// Fields in declaration order, indexed or not
+    pub fields: Vec<(String, Value)>,
+    /// Fields as a JSON object keyed by name
+    pub json: serde_json::Value,
+}
+
+impl ContractEvent {
+    /// Get a field by name
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Result of a submitted contract call
+#[derive(Debug, Clone)]
+pub struct ContractCallResult {
+    /// Extrinsic hash
+    pub tx_hash: String,
+    /// Events the contract emitted during the call
+    pub events: Vec<ContractEvent>,
+}
+
+/// Order JSON arguments to match the message spec
+fn json_args(spec: &[MessageArg], args: &serde_json::Value) -> Result<Vec<Value>> {
+    let args = match args {
+        serde_json::Value::Null => Vec::new(),
+        serde_json::Value::Array(items) => items.iter().collect(),
+        serde_json::Value::Object(fields) => spec
+            .iter()
+            .map(|arg| {
+                fields
+                    .get(&arg.label)
+                    .ok_or_else(|| Error::Transaction(format!("Missing argument '{}'", arg.label)))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        // A lone value for single-argument messages
+        other => vec![other],
+    };
+
+    args.into_iter()
+        .map(contract_codec::json_to_value)
+        .collect()
+}
+
 /// Contract specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractSpec {
@@ -112,6 +514,9 @@ pub struct EventSpec {
     pub args: Vec<EventArg>,
     /// Documentation
     pub docs: Vec<String>,
+    /// Signature topic (ink! v5, `None` for anonymous events and ink! v4)
+    #[serde(default)]
+    pub signature_topic: Option<String>,
 }
 
 /// Event argument
@@ -205,7 +610,9 @@ pub struct Variant {
 }
 
 /// Gas limit for contract calls
-#[derive(Debug, Clone, Copy, parity_scale_codec::Encode, parity_scale_codec::Decode)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, parity_scale_codec::Encode, parity_scale_codec::Decode,
+)]
 pub struct GasLimit {
     /// Reference time
     pub ref_time: u64,
@@ -248,6 +655,147 @@ pub enum StorageDepositLimit {
     Limited(u128),
 }
 
+/// Margin added to dry-run gas and storage deposit estimates, in percent
+pub const DEFAULT_GAS_MARGIN_PERCENT: u64 = 10;
+
+/// Storage deposit charged or refunded by a contract execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageDeposit {
+    /// Deposit taken from the caller
+    Charge(u128),
+    /// Deposit returned to the caller
+    Refund(u128),
+}
+
+/// Result of dry-running a contract call or instantiation
+///
+/// Returned by `ContractsApi_call` and `ContractsApi_instantiate`.
+#[derive(Debug, Clone)]
+pub struct DryRunResult {
+    /// Weight consumed by the execution
+    pub gas_consumed: GasLimit,
+    /// Weight the execution needs as its limit, which can exceed `gas_consumed`
+    pub gas_required: GasLimit,
+    /// Storage deposit charged or refunded
+    pub storage_deposit: StorageDeposit,
+    /// Debug output of the contract (only on chains with debug output enabled)
+    pub debug_message: String,
+    /// Whether the contract reverted
+    pub reverted: bool,
+    /// Return data of the message or constructor
+    pub data: Vec<u8>,
+    /// Address of the instantiated contract
+    pub contract_address: Option<ContractAddress>,
+    /// Dispatch error if the execution failed outright
+    pub error: Option<String>,
+}
+
+impl DryRunResult {
+    /// Check whether the execution succeeded without reverting
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && !self.reverted
+    }
+
+    /// Gas limit for submission: `gas_required` plus `margin_percent`
+    pub fn gas_limit(&self, margin_percent: u64) -> GasLimit {
+        GasLimit::new(
+            with_margin(self.gas_required.ref_time as u128, margin_percent) as u64,
+            with_margin(self.gas_required.proof_size as u128, margin_percent) as u64,
+        )
+    }
+
+    /// Storage deposit limit for submission: the charge plus `margin_percent`
+    pub fn storage_deposit_limit(&self, margin_percent: u64) -> StorageDepositLimit {
+        match self.storage_deposit {
+            StorageDeposit::Charge(amount) => {
+                StorageDepositLimit::Limited(with_margin(amount, margin_percent))
+            }
+            StorageDeposit::Refund(_) => StorageDepositLimit::Limited(0),
+        }
+    }
+
+    /// Describe why the execution failed
+    fn failure(&self) -> String {
+        let reason = match &self.error {
+            Some(error) => error.clone(),
+            None => format!("contract reverted with 0x{}", hex::encode(&self.data)),
+        };
+        if self.debug_message.is_empty() {
+            reason
+        } else {
+            format!("{} ({})", reason, self.debug_message)
+        }
+    }
+
+    /// Parse a decoded `ContractExecResult` or `ContractInstantiateResult`
+    pub(crate) fn from_value<T>(value: &Value<T>) -> Result<Self> {
+        let missing = |name: &str| Error::Metadata(format!("Dry-run result has no {}", name));
+
+        let weight = |name: &str| {
+            let weight = field(value, name)?;
+            Some(GasLimit::new(
+                number(field(weight, "ref_time")?)? as u64,
+                number(field(weight, "proof_size")?)? as u64,
+            ))
+        };
+
+        let storage_deposit = match field(value, "storage_deposit").map(|v| &v.value) {
+            Some(ValueDef::Variant(variant)) => {
+                let amount = variant.values.values().next().and_then(number).unwrap_or(0);
+                match variant.name.as_str() {
+                    "Refund" => StorageDeposit::Refund(amount),
+                    _ => StorageDeposit::Charge(amount),
+                }
+            }
+            _ => return Err(missing("storage_deposit")),
+        };
+
+        let debug_message = field(value, "debug_message")
+            .and_then(bytes)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .unwrap_or_default();
+
+        let mut result = Self {
+            gas_consumed: weight("gas_consumed").ok_or_else(|| missing("gas_consumed"))?,
+            gas_required: weight("gas_required").ok_or_else(|| missing("gas_required"))?,
+            storage_deposit,
+            debug_message,
+            reverted: false,
+            data: Vec::new(),
+            contract_address: None,
+            error: None,
+        };
+
+        let Some(ValueDef::Variant(outcome)) = field(value, "result").map(|v| &v.value) else {
+            return Err(missing("result"));
+        };
+        let inner = outcome.values.values().next();
+
+        if outcome.name != "Ok" {
+            result.error = Some(inner.map_or_else(|| outcome.name.clone(), |e| e.to_string()));
+            return Ok(result);
+        }
+
+        // Instantiation wraps the `ExecReturnValue` with the new account
+        let inner = inner.ok_or_else(|| missing("return value"))?;
+        let exec = field(inner, "result").unwrap_or(inner);
+
+        // `ReturnFlags` bit 0 is REVERT
+        result.reverted = field(exec, "flags").and_then(number).unwrap_or(0) & 1 == 1;
+        result.data = field(exec, "data").and_then(bytes).unwrap_or_default();
+        // pallet-contracts returns `account_id`, pallet-revive an H160 `addr`
+        result.contract_address = match field(inner, "account_id").and_then(bytes) {
+            Some(account) => account.try_into().ok(),
+            None => field(inner, "addr")
+                .and_then(bytes)
+                .and_then(|addr| addr.try_into().ok())
+                .map(revive_account_id),
+        };
+
+        Ok(result)
+    }
+}
+
 /// Contract call builder
 #[allow(dead_code)]
 pub struct ContractCallBuilder {
@@ -305,23 +853,87 @@ impl ContractCallBuilder {
     }
 }
 
+/// Options for [`ContractClient::deploy_with_options`]
+#[derive(Debug, Clone)]
+pub struct DeployOptions {
+    salt: Option<Vec<u8>>,
+    value: u128,
+    gas_margin_percent: u64,
+}
+
+impl Default for DeployOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeployOptions {
+    /// Create deploy options with no salt, no value and the default gas margin
+    pub fn new() -> Self {
+        Self {
+            salt: None,
+            value: 0,
+            gas_margin_percent: DEFAULT_GAS_MARGIN_PERCENT,
+        }
+    }
+
+    /// Set the salt for deterministic address generation (32 bytes on
+    /// pallet-revive)
+    pub fn salt(mut self, salt: Vec<u8>) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Set the value to transfer to the new contract
+    pub fn value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the margin added to dry-run estimates, in percent
+    ///
+    /// The deployed contract's client keeps this margin for its calls.
+    pub fn gas_margin(mut self, percent: u64) -> Self {
+        self.gas_margin_percent = percent;
+        self
+    }
+}
+
 /// Contract client for interacting with deployed contracts
 pub struct ContractClient {
     client: OnlineClient<PolkadotConfig>,
     address: ContractAddress,
     metadata: Option<ContractMetadata>,
+    gas_margin_percent: u64,
+    pallet: ContractsPallet,
 }
 
 impl ContractClient {
     /// Create a new contract client for an existing contract
+    ///
+    /// On pallet-revive chains `address` is the contract's account ID; use
+    /// [`from_h160`](Self::from_h160) to start from its H160 address.
     pub fn new(client: OnlineClient<PolkadotConfig>, address: ContractAddress) -> Self {
         Self {
+            pallet: ContractsPallet::detect(&client).unwrap_or(ContractsPallet::Contracts),
             client,
             address,
             metadata: None,
+            gas_margin_percent: DEFAULT_GAS_MARGIN_PERCENT,
         }
     }
 
+    /// Create a contract client for a pallet-revive contract by H160 address
+    pub fn from_h160(
+        client: OnlineClient<PolkadotConfig>,
+        address: [u8; 20],
+        metadata: Option<ContractMetadata>,
+    ) -> Self {
+        let mut contract = Self::new(client, revive_account_id(address));
+        contract.metadata = metadata;
+        contract
+    }
+
     /// Create a contract client with metadata
     pub fn with_metadata(
         client: OnlineClient<PolkadotConfig>,
@@ -329,27 +941,39 @@ impl ContractClient {
         metadata: ContractMetadata,
     ) -> Self {
         Self {
+            pallet: ContractsPallet::detect(&client).unwrap_or(ContractsPallet::Contracts),
             client,
             address,
             metadata: Some(metadata),
+            gas_margin_percent: DEFAULT_GAS_MARGIN_PERCENT,
         }
     }
 
+    /// Set the margin added to dry-run estimates for calls, in percent
+    pub fn with_gas_margin(mut self, percent: u64) -> Self {
+        self.gas_margin_percent = percent;
+        self
+    }
+
     /// Deploy a new contract
     ///
     /// # Arguments
     ///
     /// * `client` - Subxt client
-    /// * `wasm_code` - Compiled Wasm code
+    /// * `wasm_code` - Compiled Wasm code, or a PolkaVM blob on pallet-revive
     /// * `metadata` - Contract metadata
     /// * `constructor_name` - Name of the constructor to call
     /// * `constructor_args` - SCALE-encoded constructor arguments
     /// * `wallet` - Wallet to sign the deployment transaction
-    /// * `salt` - Salt for deterministic address generation
+    /// * `salt` - Salt for deterministic address generation (32 bytes on
+    ///   pallet-revive)
     ///
     /// # Returns
     ///
     /// Contract client for the deployed contract
+    ///
+    /// On pallet-revive the wallet's account is mapped to an H160 address
+    /// first if needed (see [`ensure_account_mapped`](Self::ensure_account_mapped)).
     pub async fn deploy(
         client: OnlineClient<PolkadotConfig>,
         wasm_code: Vec<u8>,
@@ -358,44 +982,132 @@ impl ContractClient {
         constructor_args: &[u8],
         wallet: &Wallet,
         salt: Option<Vec<u8>>,
+    ) -> Result<Self> {
+        let mut options = DeployOptions::new();
+        options.salt = salt;
+        Self::deploy_with_options(
+            client,
+            wasm_code,
+            metadata,
+            constructor_name,
+            constructor_args,
+            wallet,
+            options,
+        )
+        .await
+    }
+
+    /// Deploy a new contract with a value transfer, salt and gas margin
+    ///
+    /// Same as [`deploy`](Self::deploy), with the settings taken from
+    /// `options`. The returned client uses the same gas margin for calls.
+    pub async fn deploy_with_options(
+        client: OnlineClient<PolkadotConfig>,
+        wasm_code: Vec<u8>,
+        metadata: ContractMetadata,
+        constructor_name: &str,
+        constructor_args: &[u8],
+        wallet: &Wallet,
+        options: DeployOptions,
+    ) -> Result<Self> {
+        let contract = Self::instantiate(
+            client,
+            wasm_code,
+            metadata,
+            constructor_name,
+            constructor_args,
+            wallet,
+            &options,
+        )
+        .await?;
+        Ok(contract.with_gas_margin(options.gas_margin_percent))
+    }
+
+    async fn instantiate(
+        client: OnlineClient<PolkadotConfig>,
+        wasm_code: Vec<u8>,
+        metadata: ContractMetadata,
+        constructor_name: &str,
+        constructor_args: &[u8],
+        wallet: &Wallet,
+        options: &DeployOptions,
     ) -> Result<Self> {
         info!("Deploying contract with constructor: {}", constructor_name);
 
+        let pallet = ContractsPallet::detect(&client).ok_or_else(|| {
+            Error::Metadata("Runtime has neither the Contracts nor the Revive pallet".to_string())
+        })?;
+        if pallet == ContractsPallet::Revive {
+            Self::ensure_account_mapped(&client, wallet).await?;
+        }
+
         // Find the constructor
-        let constructor = metadata
-            .spec
-            .constructors
-            .iter()
-            .find(|c| c.label == constructor_name)
-            .ok_or_else(|| {
-                Error::Transaction(format!("Constructor '{}' not found", constructor_name))
-            })?;
+        let constructor = metadata.constructor(constructor_name)?;
 
         // Build constructor call data
         let mut call_data = Vec::new();
         call_data.extend_from_slice(&constructor.selector);
         call_data.extend_from_slice(constructor_args);
 
-        // Prepare salt (use default if not provided)
-        let salt = salt.unwrap_or_else(|| vec![0u8; 32]);
+        // Prepare salt (use default if not provided; pallet-revive takes an optional salt)
+        let salt = match pallet {
+            ContractsPallet::Contracts => options.salt.clone().unwrap_or_else(|| vec![0u8; 32]),
+            ContractsPallet::Revive => options.salt.clone().unwrap_or_default(),
+        };
 
-        // Build the instantiate call
-        let gas_limit = GasLimit::default_deploy();
-        let storage_deposit = StorageDepositLimit::NoLimit;
+        // Dry-run the instantiation to size the gas and storage deposit limits
+        let dry_run = Self::dry_run_instantiate(
+            &client,
+            &wallet_account(wallet)?,
+            &wasm_code,
+            &call_data,
+            &salt,
+            options.value,
+        )
+        .await?;
+        if !dry_run.is_success() {
+            return Err(Error::Transaction(format!(
+                "Contract deployment would fail: {}",
+                dry_run.failure()
+            )));
+        }
 
-        let instantiate_call = subxt::dynamic::tx(
-            "Contracts",
-            "instantiate",
-            vec![
-                subxt::dynamic::Value::u128(0), // value
-                Self::encode_gas_limit(&gas_limit)?,
-                Self::encode_storage_deposit(&storage_deposit)?,
-                subxt::dynamic::Value::from_bytes(&wasm_code),
-                subxt::dynamic::Value::from_bytes(&call_data),
-                subxt::dynamic::Value::from_bytes(&salt),
-            ],
+        // Build the instantiate call
+        let gas_limit = dry_run.gas_limit(options.gas_margin_percent);
+        let storage_deposit = dry_run.storage_deposit_limit(options.gas_margin_percent);
+        debug!(
+            "Deploy gas limit {:?}, storage deposit limit {:?}",
+            gas_limit, storage_deposit
         );
 
+        let instantiate_call = match pallet {
+            // Upload the code and instantiate it in one call, as in the dry run
+            ContractsPallet::Contracts => subxt::dynamic::tx(
+                "Contracts",
+                "instantiate_with_code",
+                vec![
+                    subxt::dynamic::Value::u128(options.value),
+                    Self::encode_gas_limit(&gas_limit)?,
+                    Self::encode_storage_deposit(pallet, &storage_deposit)?,
+                    subxt::dynamic::Value::from_bytes(&wasm_code),
+                    subxt::dynamic::Value::from_bytes(&call_data),
+                    subxt::dynamic::Value::from_bytes(&salt),
+                ],
+            ),
+            ContractsPallet::Revive => subxt::dynamic::tx(
+                "Revive",
+                "instantiate_with_code",
+                vec![
+                    subxt::dynamic::Value::u128(options.value),
+                    Self::encode_gas_limit(&gas_limit)?,
+                    Self::encode_storage_deposit(pallet, &storage_deposit)?,
+                    subxt::dynamic::Value::from_bytes(&wasm_code),
+                    subxt::dynamic::Value::from_bytes(&call_data),
+                    revive_salt(&salt)?,
+                ],
+            ),
+        };
+
         // Submit the transaction
         let pair = wallet
             .sr25519_pair()
@@ -421,15 +1133,34 @@ impl ContractClient {
 
                 // Extract contract address from events
                 let events = finalized
-                    .fetch_events()
+                    .wait_for_success()
                     .await
-                    .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
+                    .map_err(|e| transaction_error("Contract deployment failed", e))?;
 
                 for evt in events.iter() {
                     let evt = evt.map_err(|e| {
                         Error::Transaction(format!("Failed to decode event: {}", e))
                     })?;
 
+                    // pallet-revive's `Instantiated { deployer, contract }` carries H160s
+                    if evt.pallet_name() == "Revive" && evt.variant_name() == "Instantiated" {
+                        let address: [u8; 20] = evt
+                            .field_bytes()
+                            .get(20..40)
+                            .and_then(|bytes| bytes.try_into().ok())
+                            .ok_or_else(|| {
+                                Error::Transaction(
+                                    "Could not extract contract address from Instantiated event"
+                                        .to_string(),
+                                )
+                            })?;
+                        return Ok(Self::with_metadata(
+                            client,
+                            revive_account_id(address),
+                            metadata,
+                        ));
+                    }
+
                     // Look for Contracts.Instantiated event
                     if evt.pallet_name() == "Contracts" && evt.variant_name() == "Instantiated" {
                         debug!("Contract instantiated event found");
@@ -486,41 +1217,52 @@ impl ContractClient {
     ///
     /// # Returns
     ///
-    /// Transaction hash of the call
-    pub async fn call(&self, method_name: &str, args: &[u8], wallet: &Wallet) -> Result<String> {
+    /// Transaction hash of the call and the events the contract emitted,
+    /// decoded if metadata is available
+    pub async fn call(
+        &self,
+        method_name: &str,
+        args: &[u8],
+        wallet: &Wallet,
+    ) -> Result<ContractCallResult> {
         info!("Calling contract method: {}", method_name);
 
         // Find the message in metadata
-        let message = if let Some(ref metadata) = self.metadata {
-            metadata
-                .spec
-                .messages
-                .iter()
-                .find(|m| m.label == method_name)
-                .ok_or_else(|| Error::Transaction(format!("Method '{}' not found", method_name)))?
-        } else {
-            return Err(Error::Transaction(
-                "Contract metadata not available".to_string(),
-            ));
-        };
+        let message = self.require_metadata()?.message(method_name)?;
 
         // Build call data
         let mut call_data = Vec::new();
         call_data.extend_from_slice(&message.selector);
         call_data.extend_from_slice(args);
 
+        if self.pallet == ContractsPallet::Revive {
+            Self::ensure_account_mapped(&self.client, wallet).await?;
+        }
+
+        // Dry-run the call to size the gas and storage deposit limits
+        let dry_run = self
+            .dry_run(&call_data, &wallet_account(wallet)?, 0)
+            .await?;
+        if !dry_run.is_success() {
+            return Err(Error::Transaction(format!(
+                "Contract call '{}' would fail: {}",
+                method_name,
+                self.describe_failure(method_name, &dry_run)
+            )));
+        }
+
         // Build the call transaction
-        let gas_limit = GasLimit::default_call();
-        let storage_deposit = StorageDepositLimit::NoLimit;
+        let gas_limit = dry_run.gas_limit(self.gas_margin_percent);
+        let storage_deposit = dry_run.storage_deposit_limit(self.gas_margin_percent);
 
         let call_tx = subxt::dynamic::tx(
-            "Contracts",
+            self.pallet.pallet_name(),
             "call",
             vec![
-                subxt::dynamic::Value::from_bytes(self.address),
+                self.dest(),
                 subxt::dynamic::Value::u128(0), // value
                 Self::encode_gas_limit(&gas_limit)?,
-                Self::encode_storage_deposit(&storage_deposit)?,
+                Self::encode_storage_deposit(self.pallet, &storage_deposit)?,
                 subxt::dynamic::Value::from_bytes(&call_data),
             ],
         );
@@ -548,12 +1290,15 @@ impl ContractClient {
                 let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
                 info!("Contract call finalized: {}", tx_hash);
 
-                finalized
+                let events = finalized
                     .wait_for_success()
                     .await
-                    .map_err(|e| Error::Transaction(format!("Contract call failed: {}", e)))?;
+                    .map_err(|e| transaction_error("Contract call failed", e))?;
 
-                return Ok(tx_hash);
+                return Ok(ContractCallResult {
+                    tx_hash,
+                    events: self.contract_events(events.iter()),
+                });
             }
         }
 
@@ -592,152 +1337,351 @@ impl ContractClient {
     /// This method:
     /// 1. Looks up the method selector from contract metadata
     /// 2. Encodes the call data (selector + arguments)
-    /// 3. Calls `ContractsApi_call` runtime API via `state_call`
-    /// 4. Decodes the `ContractExecResult` to extract the return data
+    /// 3. Dry-runs the call with the `ContractsApi_call` runtime API
+    /// 4. Returns the data from the decoded `ContractExecResult`
     ///
-    /// The runtime API call is made at the latest finalized block to ensure
-    /// consistent results.
+    /// The runtime API call is made at the latest best block, so it sees
+    /// state that may not be finalized yet.
     pub async fn read(&self, method_name: &str, args: &[u8], caller: &[u8; 32]) -> Result<Vec<u8>> {
         debug!("Reading contract state: {}", method_name);
 
         // Find the message in metadata
-        let message = if let Some(ref metadata) = self.metadata {
-            metadata
-                .spec
-                .messages
-                .iter()
-                .find(|m| m.label == method_name)
-                .ok_or_else(|| Error::Transaction(format!("Method '{}' not found", method_name)))?
-        } else {
-            return Err(Error::Transaction(
-                "Contract metadata not available".to_string(),
-            ));
-        };
+        let message = self.require_metadata()?.message(method_name)?;
 
         // Build call data
         let mut call_data = Vec::new();
         call_data.extend_from_slice(&message.selector);
         call_data.extend_from_slice(args);
 
-        // Prepare parameters for ContractsApi_call runtime API
-        // The ContractsApi_call expects: (origin, dest, value, gas_limit, storage_deposit_limit, input_data)
-        use parity_scale_codec::Encode;
+        let dry_run = self.dry_run(&call_data, caller, 0).await?;
+        if let Some(error) = &dry_run.error {
+            return Err(Error::Transaction(format!(
+                "Contract execution failed: {}",
+                error
+            )));
+        }
+        if !dry_run.debug_message.is_empty() {
+            debug!("Contract debug message: {}", dry_run.debug_message);
+        }
 
-        let gas_limit = GasLimit::default_call();
-        let value: u128 = 0; // No value transfer for reads
-        let storage_deposit_limit: Option<u128> = None; // No storage deposit for reads
-
-        // Encode the parameters according to the ContractsApi_call signature
-        let mut encoded_params = Vec::new();
-        caller.encode_to(&mut encoded_params); // origin
-        self.address.encode_to(&mut encoded_params); // dest
-        value.encode_to(&mut encoded_params); // value
-        gas_limit.encode_to(&mut encoded_params); // gas_limit
-        storage_deposit_limit.encode_to(&mut encoded_params); // storage_deposit_limit
-        call_data.encode_to(&mut encoded_params); // input_data
-
-        // Call the runtime API using state_call
-        let result_bytes = self
-            .client
-            .backend()
-            .call(
-                "ContractsApi_call",
-                Some(&encoded_params),
-                self.client
-                    .backend()
-                    .latest_finalized_block_ref()
-                    .await?
-                    .hash(),
-            )
-            .await
-            .map_err(|e| Error::Transaction(format!("ContractsApi_call failed: {}", e)))?;
+        // Reverted messages still return data, such as an encoded `Err`
+        Ok(dry_run.data)
+    }
+
+    /// Dry-run a message with `ContractsApi_call` or `ReviveApi_call`
+    ///
+    /// Nothing is submitted. The result carries the gas and storage deposit
+    /// the call needs, its return data and whether it reverted.
+    ///
+    /// # Arguments
+    ///
+    /// * `method_name` - Name of the method to call (from contract metadata)
+    /// * `args` - SCALE-encoded method arguments
+    /// * `caller` - Account the call is made from
+    /// * `value` - Balance transferred with the call
+    pub async fn dry_run_call(
+        &self,
+        method_name: &str,
+        args: &[u8],
+        caller: &[u8; 32],
+        value: u128,
+    ) -> Result<DryRunResult> {
+        let message = self.require_metadata()?.message(method_name)?;
+
+        let mut call_data = message.selector.to_vec();
+        call_data.extend_from_slice(args);
 
-        // Decode the result
-        // ContractsApi_call returns ContractExecResult which is SCALE encoded
-        // For simplicity, we'll try to decode the common structure:
-        // ContractExecResult { gas_consumed, gas_required, storage_deposit, debug_message, result }
+        self.dry_run(&call_data, caller, value).await
+    }
 
-        // The result is a SCALE-encoded ContractExecResult
-        // We need to decode it to get the actual return data
-        let decoded_result = Self::decode_contract_result(&result_bytes)?;
+    /// Dry-run an instantiation with `ContractsApi_instantiate` or
+    /// `ReviveApi_instantiate`
+    ///
+    /// Uploads `wasm_code` in the dry run only. `constructor_data` is the
+    /// constructor selector followed by its SCALE-encoded arguments. On
+    /// pallet-revive `salt` is either empty or 32 bytes.
+    pub async fn dry_run_instantiate(
+        client: &OnlineClient<PolkadotConfig>,
+        caller: &[u8; 32],
+        wasm_code: &[u8],
+        constructor_data: &[u8],
+        salt: &[u8],
+        value: u128,
+    ) -> Result<DryRunResult> {
+        let pallet = ContractsPallet::detect(client).unwrap_or(ContractsPallet::Contracts);
+        let salt = match pallet {
+            ContractsPallet::Contracts => Value::from_bytes(salt),
+            ContractsPallet::Revive => revive_salt(salt)?,
+        };
 
-        Ok(decoded_result)
+        contracts_api(
+            client,
+            pallet,
+            "instantiate",
+            vec![
+                Value::from_bytes(caller),
+                Value::u128(value),
+                Value::unnamed_variant("None", vec![]), // gas_limit: use the maximum
+                Value::unnamed_variant("None", vec![]), // storage_deposit_limit
+                Value::unnamed_variant("Upload", vec![Value::from_bytes(wasm_code)]),
+                Value::from_bytes(constructor_data),
+                salt,
+            ],
+        )
+        .await
     }
 
-    /// Decode ContractExecResult from SCALE-encoded bytes
-    fn decode_contract_result(bytes: &[u8]) -> Result<Vec<u8>> {
-        use parity_scale_codec::Decode;
+    async fn dry_run(
+        &self,
+        call_data: &[u8],
+        caller: &[u8; 32],
+        value: u128,
+    ) -> Result<DryRunResult> {
+        contracts_api(
+            &self.client,
+            self.pallet,
+            "call",
+            vec![
+                Value::from_bytes(caller),
+                self.dest(),
+                Value::u128(value),
+                Value::unnamed_variant("None", vec![]), // gas_limit: use the maximum
+                Value::unnamed_variant("None", vec![]), // storage_deposit_limit
+                Value::from_bytes(call_data),
+            ],
+        )
+        .await
+    }
 
-        // ContractExecResult structure (simplified):
-        // struct ContractExecResult {
-        //     gas_consumed: Weight,
-        //     gas_required: Weight,
-        //     storage_deposit: StorageDeposit,
-        //     debug_message: Vec<u8>,
-        //     result: Result<ExecReturnValue, DispatchError>
-        // }
+    /// Check whether an account is mapped to an H160 address in pallet-revive
+    ///
+    /// Accounts derived from an Ethereum key are always mapped.
+    pub async fn is_account_mapped(
+        client: &OnlineClient<PolkadotConfig>,
+        account: &[u8; 32],
+    ) -> Result<bool> {
+        if is_eth_derived(account) {
+            return Ok(true);
+        }
 
-        let mut input = bytes;
+        let query = subxt::dynamic::storage(
+            "Revive",
+            "OriginalAccount",
+            vec![Value::from_bytes(revive_address(account))],
+        );
+        let original = client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to get latest block: {}", e)))?
+            .fetch(&query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to fetch account mapping: {}", e)))?;
 
-        // Skip gas_consumed (Weight = {ref_time: u64, proof_size: u64})
-        let _gas_consumed_ref_time = u64::decode(&mut input).map_err(|e| {
-            Error::Transaction(format!("Failed to decode gas_consumed.ref_time: {}", e))
-        })?;
-        let _gas_consumed_proof_size = u64::decode(&mut input).map_err(|e| {
-            Error::Transaction(format!("Failed to decode gas_consumed.proof_size: {}", e))
-        })?;
+        Ok(original.is_some())
+    }
 
-        // Skip gas_required (Weight = {ref_time: u64, proof_size: u64})
-        let _gas_required_ref_time = u64::decode(&mut input).map_err(|e| {
-            Error::Transaction(format!("Failed to decode gas_required.ref_time: {}", e))
-        })?;
-        let _gas_required_proof_size = u64::decode(&mut input).map_err(|e| {
-            Error::Transaction(format!("Failed to decode gas_required.proof_size: {}", e))
-        })?;
+    /// Map the wallet's account to an H160 address with `Revive.map_account`
+    ///
+    /// pallet-revive only accepts calls from mapped accounts. Does nothing if
+    /// the account is already mapped or the runtime has no Revive pallet.
+    pub async fn ensure_account_mapped(
+        client: &OnlineClient<PolkadotConfig>,
+        wallet: &Wallet,
+    ) -> Result<()> {
+        if client.metadata().pallet_by_name("Revive").is_none() {
+            return Ok(());
+        }
 
-        // Skip storage_deposit (enum with variants)
-        // StorageDeposit is an enum: Charge(u128) | Refund(u128)
-        let storage_deposit_variant = u8::decode(&mut input).map_err(|e| {
-            Error::Transaction(format!("Failed to decode storage_deposit variant: {}", e))
-        })?;
-        if storage_deposit_variant <= 1 {
-            // Has a u128 value
-            let _deposit_amount = u128::decode(&mut input).map_err(|e| {
-                Error::Transaction(format!("Failed to decode storage_deposit amount: {}", e))
-            })?;
+        let account = wallet_account(wallet)?;
+        if Self::is_account_mapped(client, &account).await? {
+            return Ok(());
         }
 
-        // Skip debug_message (Vec<u8>)
-        let debug_msg = Vec::<u8>::decode(&mut input)
-            .map_err(|e| Error::Transaction(format!("Failed to decode debug_message: {}", e)))?;
+        info!(
+            "Mapping account to H160 0x{}",
+            hex::encode(revive_address(&account))
+        );
+        let map_account = subxt::dynamic::tx("Revive", "map_account", Vec::<Value>::new());
+        submit_and_wait(client, &map_account, wallet).await?;
+        Ok(())
+    }
 
-        if !debug_msg.is_empty() {
-            debug!(
-                "Contract debug message: {}",
-                String::from_utf8_lossy(&debug_msg)
-            );
+    /// The contracts pallet this client targets
+    pub fn pallet(&self) -> ContractsPallet {
+        self.pallet
+    }
+
+    /// H160 address of the contract on pallet-revive
+    pub fn h160_address(&self) -> [u8; 20] {
+        revive_address(&self.address)
+    }
+
+    /// Contract address argument for calls and dry runs
+    fn dest(&self) -> Value {
+        match self.pallet {
+            ContractsPallet::Contracts => Value::from_bytes(self.address),
+            ContractsPallet::Revive => Value::from_bytes(self.h160_address()),
         }
+    }
 
-        // Decode result: Result<ExecReturnValue, DispatchError>
-        let result_variant = u8::decode(&mut input)
-            .map_err(|e| Error::Transaction(format!("Failed to decode result variant: {}", e)))?;
+    /// Describe a failed dry run, decoding a reverted `Err` from metadata
+    fn describe_failure(&self, method_name: &str, dry_run: &DryRunResult) -> String {
+        if dry_run.error.is_none() {
+            if let Some(Ok(decoded)) = self
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.decode_message_return(method_name, &dry_run.data))
+            {
+                if let Some(error) = decoded.error {
+                    return format!("contract returned error {}", error);
+                }
+            }
+        }
+        dry_run.failure()
+    }
 
-        if result_variant == 0 {
-            // Ok variant - contains ExecReturnValue
-            // ExecReturnValue { flags: u32, data: Vec<u8> }
-            let _flags = u32::decode(&mut input)
-                .map_err(|e| Error::Transaction(format!("Failed to decode flags: {}", e)))?;
+    /// Call a contract method with arguments encoded from dynamic values
+    ///
+    /// Arguments are SCALE-encoded using the metadata type registry. See
+    /// [`call`](Self::call) for the submission.
+    pub async fn call_values(
+        &self,
+        method_name: &str,
+        args: &[Value],
+        wallet: &Wallet,
+    ) -> Result<ContractCallResult> {
+        let args = self
+            .require_metadata()?
+            .encode_message_args(method_name, args)?;
+        self.call(method_name, &args, wallet).await
+    }
 
-            let data = Vec::<u8>::decode(&mut input)
-                .map_err(|e| Error::Transaction(format!("Failed to decode return data: {}", e)))?;
+    /// Call a contract method with arguments encoded from JSON
+    ///
+    /// `args` is an array in argument order, an object keyed by argument
+    /// label, or `null` for messages without arguments.
+    pub async fn call_json(
+        &self,
+        method_name: &str,
+        args: &serde_json::Value,
+        wallet: &Wallet,
+    ) -> Result<ContractCallResult> {
+        let args = self
+            .require_metadata()?
+            .encode_message_json(method_name, args)?;
+        self.call(method_name, &args, wallet).await
+    }
 
-            Ok(data)
-        } else {
-            // Err variant - contains DispatchError
-            Err(Error::Transaction(
-                "Contract execution failed with DispatchError".to_string(),
-            ))
-        }
+    /// Read contract state with dynamic-value arguments and a decoded result
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let balance = contract
+    ///     .read_values("balance_of", &[Value::from_bytes(owner)], &caller)
+    ///     .await?;
+    /// println!("{}", balance.json);
+    /// ```
+    pub async fn read_values(
+        &self,
+        method_name: &str,
+        args: &[Value],
+        caller: &[u8; 32],
+    ) -> Result<MessageReturn> {
+        let metadata = self.require_metadata()?;
+        let args = metadata.encode_message_args(method_name, args)?;
+        let data = self.read(method_name, &args, caller).await?;
+        metadata.decode_message_return(method_name, &data)
+    }
+
+    /// Read contract state with JSON arguments and a decoded result
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let balance = contract
+    ///     .read_json("balance_of", &json!({ "owner": "5GrwvaEF..." }), &caller)
+    ///     .await?;
+    /// ```
+    pub async fn read_json(
+        &self,
+        method_name: &str,
+        args: &serde_json::Value,
+        caller: &[u8; 32],
+    ) -> Result<MessageReturn> {
+        let metadata = self.require_metadata()?;
+        let args = metadata.encode_message_json(method_name, args)?;
+        let data = self.read(method_name, &args, caller).await?;
+        metadata.decode_message_return(method_name, &data)
+    }
+
+    fn require_metadata(&self) -> Result<&ContractMetadata> {
+        self.metadata
+            .as_ref()
+            .ok_or_else(|| Error::Transaction("Contract metadata not available".to_string()))
+    }
+
+    /// Decode the events this contract emitted in a block
+    pub async fn events_at(&self, block_hash: &str) -> Result<Vec<ContractEvent>> {
+        let hash_bytes = hex::decode(block_hash.trim_start_matches("0x"))
+            .map_err(|e| Error::Transaction(format!("Invalid block hash: {}", e)))?;
+        let hash: [u8; 32] = hash_bytes
+            .try_into()
+            .map_err(|_| Error::Transaction("Block hash must be 32 bytes".to_string()))?;
+
+        let events = self
+            .client
+            .blocks()
+            .at(subxt::utils::H256::from(hash))
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get block: {}", e)))?
+            .events()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get events: {}", e)))?;
+
+        Ok(self.contract_events(events.iter()))
+    }
+
+    /// Decode this contract's `ContractEmitted` events
+    ///
+    /// Events that cannot be decoded are logged and skipped.
+    fn contract_events<E: std::fmt::Display>(
+        &self,
+        events: impl Iterator<Item = std::result::Result<EventDetails<PolkadotConfig>, E>>,
+    ) -> Vec<ContractEvent> {
+        let Some(metadata) = &self.metadata else {
+            return Vec::new();
+        };
+
+        events
+            .filter_map(|event| event.ok())
+            .filter(|event| {
+                event.pallet_name() == self.pallet.pallet_name()
+                    && event.variant_name() == "ContractEmitted"
+            })
+            .filter_map(|event| {
+                // pallet-revive carries the topics in the event itself
+                let (data, topics) = match self.pallet {
+                    ContractsPallet::Contracts => {
+                        let (contract, data) = emitted_fields(event.field_bytes())?;
+                        let topics = event.topics().iter().map(|topic| topic.0).collect();
+                        (contract == self.address).then_some((data, topics))?
+                    }
+                    ContractsPallet::Revive => {
+                        let (contract, data, topics) = revive_emitted_fields(event.field_bytes())?;
+                        (contract == self.h160_address()).then_some((data, topics))?
+                    }
+                };
+                match metadata.decode_event(&data, &topics) {
+                    Ok(decoded) => Some(decoded),
+                    Err(e) => {
+                        warn!("Skipping undecodable contract event: {}", e);
+                        None
+                    }
+                }
+            })
+            .collect()
     }
 
     /// Get the contract address
@@ -765,7 +1709,19 @@ impl ContractClient {
         ]))
     }
 
-    fn encode_storage_deposit(limit: &StorageDepositLimit) -> Result<subxt::dynamic::Value> {
+    fn encode_storage_deposit(
+        pallet: ContractsPallet,
+        limit: &StorageDepositLimit,
+    ) -> Result<subxt::dynamic::Value> {
+        // pallet-revive takes a plain balance rather than an `Option`
+        if pallet == ContractsPallet::Revive {
+            let amount = match limit {
+                StorageDepositLimit::NoLimit => u128::MAX,
+                StorageDepositLimit::Limited(amount) => *amount,
+            };
+            return Ok(subxt::dynamic::Value::u128(amount));
+        }
+
         match limit {
             StorageDepositLimit::NoLimit => {
                 Ok(subxt::dynamic::Value::unnamed_variant("None", vec![]))
@@ -778,6 +1734,165 @@ impl ContractClient {
     }
 }
 
+/// Call a `ContractsApi` or `ReviveApi` runtime API method and parse the result
+async fn contracts_api(
+    client: &OnlineClient<PolkadotConfig>,
+    pallet: ContractsPallet,
+    method: &str,
+    args: Vec<Value>,
+) -> Result<DryRunResult> {
+    let api = pallet.runtime_api();
+    let payload = subxt::dynamic::runtime_api_call(api, method, args);
+    let value = client
+        .runtime_api()
+        .at_latest()
+        .await
+        .map_err(|e| Error::Connection(format!("Failed to get latest block: {}", e)))?
+        .call(payload)
+        .await
+        .map_err(|e| Error::Transaction(format!("{}_{} failed: {}", api, method, e)))?
+        .to_value()
+        .map_err(|e| {
+            Error::Metadata(format!("Failed to decode {}_{} result: {}", api, method, e))
+        })?;
+
+    DryRunResult::from_value(&value)
+}
+
+/// Split `ContractEmitted { contract, data }` field bytes
+fn emitted_fields(field_bytes: &[u8]) -> Option<(ContractAddress, Vec<u8>)> {
+    use parity_scale_codec::Decode;
+
+    let (contract, mut rest) = field_bytes.split_first_chunk::<32>()?;
+    let data = Vec::<u8>::decode(&mut rest).ok()?;
+    Some((*contract, data))
+}
+
+/// Contract H160, data and topics of a `Revive.ContractEmitted` event
+type ReviveEmitted = ([u8; 20], Vec<u8>, Vec<[u8; 32]>);
+
+/// Split `Revive.ContractEmitted { contract, data, topics }` field bytes
+fn revive_emitted_fields(field_bytes: &[u8]) -> Option<ReviveEmitted> {
+    use parity_scale_codec::Decode;
+
+    let (contract, mut rest) = field_bytes.split_first_chunk::<20>()?;
+    let data = Vec::<u8>::decode(&mut rest).ok()?;
+    let topics = Vec::<[u8; 32]>::decode(&mut rest).ok()?;
+    Some((*contract, data, topics))
+}
+
+/// Encode a pallet-revive `Option<[u8; 32]>` salt, empty meaning `None`
+fn revive_salt(salt: &[u8]) -> Result<Value> {
+    if salt.is_empty() {
+        return Ok(Value::unnamed_variant("None", vec![]));
+    }
+    let salt: [u8; 32] = salt
+        .try_into()
+        .map_err(|_| Error::Transaction("pallet-revive salts must be 32 bytes".to_string()))?;
+    Ok(Value::unnamed_variant(
+        "Some",
+        vec![Value::from_bytes(salt)],
+    ))
+}
+
+/// Sign and submit a call, waiting for it to succeed in a finalized block
+async fn submit_and_wait<Call: subxt::tx::Payload>(
+    client: &OnlineClient<PolkadotConfig>,
+    call: &Call,
+    wallet: &Wallet,
+) -> Result<String> {
+    let pair = wallet
+        .sr25519_pair()
+        .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+    let signer = Sr25519Signer::new(pair.clone());
+
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch_default(call, &signer)
+        .await
+        .map_err(|e| Error::Transaction(format!("Failed to submit transaction: {}", e)))?
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| transaction_error("Transaction failed", e))?;
+
+    Ok(format!("0x{}", hex::encode(events.extrinsic_hash())))
+}
+
+/// Parse a `0x`-prefixed 32-byte signature topic
+fn parse_topic(topic: &str) -> Option<[u8; 32]> {
+    hex::decode(topic.trim_start_matches("0x"))
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+/// Account ID of a wallet, used as the dry-run origin
+fn wallet_account(wallet: &Wallet) -> Result<[u8; 32]> {
+    wallet
+        .public_key()
+        .try_into()
+        .map_err(|_| Error::Transaction("Wallet public key is not 32 bytes".to_string()))
+}
+
+fn with_margin(amount: u128, margin_percent: u64) -> u128 {
+    amount.saturating_add(amount.saturating_mul(margin_percent as u128) / 100)
+}
+
+fn field<'a, T>(value: &'a Value<T>, name: &str) -> Option<&'a Value<T>> {
+    match &value.value {
+        ValueDef::Composite(Composite::Named(fields)) => fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// Read a number, looking through newtypes such as `ReturnFlags { bits }`
+fn number<T>(value: &Value<T>) -> Option<u128> {
+    match &value.value {
+        ValueDef::Composite(composite) if composite.len() == 1 => {
+            number(composite.values().next()?)
+        }
+        _ => value.as_u128(),
+    }
+}
+
+fn bytes<T>(value: &Value<T>) -> Option<Vec<u8>> {
+    match &value.value {
+        ValueDef::Composite(Composite::Unnamed(values)) => values
+            .iter()
+            .map(|byte| byte.as_u128().map(|b| b as u8))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Read the metadata version, written as `"4"` by ink! v4 and `5` by ink! v5
+fn deserialize_version<'de, D>(deserializer: D) -> std::result::Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error as _;
+
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(serde_json::Value::Number(n)) => n
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .map(Some)
+            .ok_or_else(|| D::Error::custom(format!("invalid metadata version {}", n))),
+        Some(serde_json::Value::String(s)) => s
+            .parse()
+            .map(Some)
+            .map_err(|_| D::Error::custom(format!("invalid metadata version {:?}", s))),
+        Some(other) => Err(D::Error::custom(format!(
+            "invalid metadata version {}",
+            other
+        ))),
+    }
+}
+
 /// Parse contract metadata from JSON
 pub fn parse_metadata(json: &str) -> Result<ContractMetadata> {
     serde_json::from_str(json)
@@ -788,6 +1903,22 @@ pub fn parse_metadata(json: &str) -> Result<ContractMetadata> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deploy_options() {
+        let options = DeployOptions::default();
+        assert_eq!(options.salt, None);
+        assert_eq!(options.value, 0);
+        assert_eq!(options.gas_margin_percent, DEFAULT_GAS_MARGIN_PERCENT);
+
+        let options = DeployOptions::new()
+            .salt(vec![1; 32])
+            .value(500)
+            .gas_margin(25);
+        assert_eq!(options.salt, Some(vec![1; 32]));
+        assert_eq!(options.value, 500);
+        assert_eq!(options.gas_margin_percent, 25);
+    }
+
     #[test]
     fn test_gas_limit() {
         let limit = GasLimit::default_call();
@@ -795,6 +1926,156 @@ mod tests {
         assert!(limit.proof_size > 0);
     }
 
+    fn exec_result(result: Value) -> Value {
+        let weight = |ref_time: u128, proof_size: u128| {
+            Value::named_composite([
+                ("ref_time", Value::u128(ref_time)),
+                ("proof_size", Value::u128(proof_size)),
+            ])
+        };
+        Value::named_composite([
+            ("gas_consumed", weight(900, 40)),
+            ("gas_required", weight(1_000, 50)),
+            (
+                "storage_deposit",
+                Value::unnamed_variant("Charge", vec![Value::u128(200)]),
+            ),
+            ("debug_message", Value::from_bytes(b"hello")),
+            ("result", result),
+            ("events", Value::unnamed_variant("None", vec![])),
+        ])
+    }
+
+    fn exec_return(flags: u128, data: &[u8]) -> Value {
+        Value::named_composite([
+            (
+                "flags",
+                Value::named_composite([("bits", Value::u128(flags))]),
+            ),
+            ("data", Value::from_bytes(data)),
+        ])
+    }
+
+    #[test]
+    fn test_dry_run_call_result() {
+        let value = exec_result(Value::unnamed_variant("Ok", vec![exec_return(0, &[1, 2])]));
+        let result = DryRunResult::from_value(&value).unwrap();
+
+        assert_eq!(result.gas_consumed, GasLimit::new(900, 40));
+        assert_eq!(result.gas_required, GasLimit::new(1_000, 50));
+        assert_eq!(result.storage_deposit, StorageDeposit::Charge(200));
+        assert_eq!(result.debug_message, "hello");
+        assert_eq!(result.data, vec![1, 2]);
+        assert!(result.is_success());
+        assert!(result.contract_address.is_none());
+
+        let reverted = exec_result(Value::unnamed_variant("Ok", vec![exec_return(1, &[])]));
+        assert!(!DryRunResult::from_value(&reverted).unwrap().is_success());
+    }
+
+    #[test]
+    fn test_dry_run_instantiate_result() {
+        let value = exec_result(Value::unnamed_variant(
+            "Ok",
+            vec![Value::named_composite([
+                ("result", exec_return(0, &[])),
+                ("account_id", Value::from_bytes([7u8; 32])),
+            ])],
+        ));
+        let result = DryRunResult::from_value(&value).unwrap();
+
+        assert_eq!(result.contract_address, Some([7u8; 32]));
+        assert!(result.is_success());
+    }
+
+    #[test]
+    fn test_dry_run_dispatch_error() {
+        let value = exec_result(Value::unnamed_variant(
+            "Err",
+            vec![Value::unnamed_variant(
+                "Module",
+                vec![Value::named_composite([
+                    ("index", Value::u128(8)),
+                    ("error", Value::from_bytes([3, 0, 0, 0])),
+                ])],
+            )],
+        ));
+        let result = DryRunResult::from_value(&value).unwrap();
+
+        assert!(!result.is_success());
+        assert!(result.failure().contains("Module"));
+        assert!(result.failure().contains("hello"));
+    }
+
+    #[test]
+    fn test_emitted_fields() {
+        use parity_scale_codec::Encode;
+
+        let mut bytes = [9u8; 32].to_vec();
+        vec![1u8, 2, 3].encode_to(&mut bytes);
+
+        assert_eq!(emitted_fields(&bytes), Some(([9u8; 32], vec![1, 2, 3])));
+        assert_eq!(emitted_fields(&[0u8; 16]), None);
+    }
+
+    #[test]
+    fn test_revive_dry_run_instantiate_result() {
+        let value = exec_result(Value::unnamed_variant(
+            "Ok",
+            vec![Value::named_composite([
+                ("result", exec_return(0, &[])),
+                ("addr", Value::from_bytes([7u8; 20])),
+            ])],
+        ));
+        let result = DryRunResult::from_value(&value).unwrap();
+
+        assert_eq!(result.contract_address, Some(revive_account_id([7u8; 20])));
+    }
+
+    #[test]
+    fn test_revive_address_mapping() {
+        let account = revive_account_id([0x42; 20]);
+        assert_eq!(&account[20..], &[0xEE; 12]);
+        assert_eq!(revive_address(&account), [0x42; 20]);
+
+        let account = [1u8; 32];
+        assert_eq!(
+            revive_address(&account),
+            sp_core::keccak_256(&account)[12..]
+        );
+    }
+
+    #[test]
+    fn test_revive_emitted_fields() {
+        use parity_scale_codec::Encode;
+
+        let mut bytes = [9u8; 20].to_vec();
+        vec![1u8, 2, 3].encode_to(&mut bytes);
+        vec![[5u8; 32]].encode_to(&mut bytes);
+
+        assert_eq!(
+            revive_emitted_fields(&bytes),
+            Some(([9u8; 20], vec![1, 2, 3], vec![[5u8; 32]]))
+        );
+        assert_eq!(revive_emitted_fields(&[0u8; 16]), None);
+    }
+
+    #[test]
+    fn test_revive_salt() {
+        assert!(revive_salt(&[]).is_ok());
+        assert!(revive_salt(&[0u8; 32]).is_ok());
+        assert!(revive_salt(&[0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn test_parse_topic() {
+        assert_eq!(
+            parse_topic(&format!("0x{}", "ab".repeat(32))),
+            Some([0xab; 32])
+        );
+        assert_eq!(parse_topic("0x1234"), None);
+    }
+
     #[test]
     fn test_contract_call_builder() {
         let address = [1u8; 32];
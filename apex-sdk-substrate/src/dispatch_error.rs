@@ -0,0 +1,273 @@
+//! Decoding of Substrate `DispatchError`s
+//!
+//! Failed extrinsics report a `DispatchError`; module errors only carry a
+//! pallet and error index. This module resolves them with runtime metadata
+//! into [`DispatchError`] values such as `Balances::InsufficientBalance`,
+//! including the error documentation.
+
+use crate::Error;
+use apex_sdk_types::{DispatchError, ModuleError};
+use subxt::dynamic::At;
+use subxt::ext::scale_value::{Composite, Value, ValueDef};
+use subxt::Metadata;
+
+/// Decode a dynamic `DispatchError` value, resolving module errors with `metadata`
+pub fn decode_dispatch_error<T>(value: &Value<T>, metadata: &Metadata) -> DispatchError {
+    decode_with(value, |pallet_index, error_index| {
+        let pallet = metadata.pallet_by_index(pallet_index)?;
+        let variant = pallet.error_variant_by_index(error_index)?;
+        Some((
+            pallet.name().to_string(),
+            variant.name.clone(),
+            join_docs(&variant.docs),
+        ))
+    })
+}
+
+/// Convert a dispatch error returned by subxt
+pub fn from_subxt_dispatch_error(error: &subxt::error::DispatchError) -> DispatchError {
+    use subxt::error::DispatchError as Subxt;
+
+    match error {
+        Subxt::Module(module) => {
+            let (pallet, name, docs) = match module.details() {
+                Ok(details) => (
+                    details.pallet.name().to_string(),
+                    details.variant.name.clone(),
+                    join_docs(&details.variant.docs),
+                ),
+                Err(_) => unresolved(module.pallet_index(), module.error_index()),
+            };
+            DispatchError::Module(ModuleError {
+                pallet,
+                pallet_index: module.pallet_index(),
+                error: name,
+                error_index: module.error_index(),
+                docs,
+            })
+        }
+        // The inner errors' Debug output is their variant name
+        Subxt::Token(error) => DispatchError::Token(format!("{:?}", error)),
+        Subxt::Arithmetic(error) => DispatchError::Arithmetic(format!("{:?}", error)),
+        Subxt::Transactional(error) => DispatchError::Transactional(format!("{:?}", error)),
+        other => DispatchError::Other(format!("{:?}", other)),
+    }
+}
+
+/// Map an error from submitting or watching a transaction
+///
+/// Runtime dispatch errors become [`Error::Dispatch`]; anything else is
+/// reported as a transaction error with `context`.
+pub(crate) fn transaction_error(context: &str, error: subxt::Error) -> Error {
+    match error {
+        subxt::Error::Runtime(dispatch) => Error::Dispatch(from_subxt_dispatch_error(&dispatch)),
+        other => Error::Transaction(format!("{}: {}", context, other)),
+    }
+}
+
+/// Decode the dispatch error of a `System.ExtrinsicFailed` event
+pub(crate) fn extrinsic_failed_error(
+    fields: &Composite<u32>,
+    metadata: &Metadata,
+) -> Option<DispatchError> {
+    dispatch_error_field(fields).map(|value| decode_dispatch_error(value, metadata))
+}
+
+/// Decode a `DispatchResult` reported in an event, such as `Proxy.ProxyExecuted`
+pub(crate) fn dispatch_result<T>(
+    value: &Value<T>,
+    decode: impl Fn(&Value<T>) -> DispatchError,
+) -> Option<std::result::Result<(), DispatchError>> {
+    let ValueDef::Variant(result) = &value.value else {
+        return None;
+    };
+    match result.name.as_str() {
+        "Ok" => Some(Ok(())),
+        "Err" => Some(Err(result
+            .values
+            .values()
+            .next()
+            .map(decode)
+            .unwrap_or_else(|| DispatchError::Other("Unknown".to_string())))),
+        _ => None,
+    }
+}
+
+fn dispatch_error_field<T>(fields: &Composite<T>) -> Option<&Value<T>> {
+    match fields {
+        Composite::Named(fields) => fields
+            .iter()
+            .find(|(name, _)| name == "dispatch_error")
+            .map(|(_, value)| value),
+        Composite::Unnamed(fields) => fields.first(),
+    }
+}
+
+fn decode_with<T>(
+    value: &Value<T>,
+    resolve: impl Fn(u8, u8) -> Option<(String, String, String)>,
+) -> DispatchError {
+    let ValueDef::Variant(variant) = &value.value else {
+        return DispatchError::Other("Unknown".to_string());
+    };
+    let inner = variant.values.values().next();
+    let inner_name = || inner.and_then(variant_name).unwrap_or_default();
+
+    match variant.name.as_str() {
+        "Module" => {
+            let pallet_index = inner.and_then(|module| module.at("index")).and_then(as_u8);
+            // Older runtimes encode the error as a single byte
+            let error_index = inner
+                .and_then(|module| module.at("error"))
+                .and_then(|error| as_u8(error).or_else(|| error.at(0).and_then(as_u8)));
+
+            let (pallet_index, error_index) = (pallet_index.unwrap_or(0), error_index.unwrap_or(0));
+            let (pallet, error, docs) = resolve(pallet_index, error_index)
+                .unwrap_or_else(|| unresolved(pallet_index, error_index));
+            DispatchError::Module(ModuleError {
+                pallet,
+                pallet_index,
+                error,
+                error_index,
+                docs,
+            })
+        }
+        "Token" => DispatchError::Token(inner_name()),
+        "Arithmetic" => DispatchError::Arithmetic(inner_name()),
+        "Transactional" => DispatchError::Transactional(inner_name()),
+        name => match inner.and_then(variant_name) {
+            // e.g. `Trie(TrieError)` on newer runtimes
+            Some(inner) => DispatchError::Other(format!("{}::{}", name, inner)),
+            None => DispatchError::Other(name.to_string()),
+        },
+    }
+}
+
+fn variant_name<T>(value: &Value<T>) -> Option<String> {
+    match &value.value {
+        ValueDef::Variant(variant) => Some(variant.name.clone()),
+        _ => None,
+    }
+}
+
+fn as_u8<T>(value: &Value<T>) -> Option<u8> {
+    value.as_u128().and_then(|n| u8::try_from(n).ok())
+}
+
+fn unresolved(pallet_index: u8, error_index: u8) -> (String, String, String) {
+    (
+        format!("Pallet{}", pallet_index),
+        format!("Error{}", error_index),
+        String::new(),
+    )
+}
+
+fn join_docs(docs: &[String]) -> String {
+    docs.iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balances(pallet_index: u8, error_index: u8) -> Option<(String, String, String)> {
+        (pallet_index == 5 && error_index == 2).then(|| {
+            (
+                "Balances".to_string(),
+                "InsufficientBalance".to_string(),
+                "Balance too low to send value.".to_string(),
+            )
+        })
+    }
+
+    fn module_error(error: Value) -> Value {
+        Value::unnamed_variant(
+            "Module",
+            [Value::named_composite([
+                ("index", Value::u128(5)),
+                ("error", error),
+            ])],
+        )
+    }
+
+    #[test]
+    fn test_decode_module_error() {
+        let value = module_error(Value::unnamed_composite([
+            Value::u128(2),
+            Value::u128(0),
+            Value::u128(0),
+            Value::u128(0),
+        ]));
+
+        let error = decode_with(&value, balances);
+        assert_eq!(error.to_string(), "Balances::InsufficientBalance");
+        match error {
+            DispatchError::Module(module) => {
+                assert_eq!(module.pallet_index, 5);
+                assert_eq!(module.error_index, 2);
+                assert_eq!(module.docs, "Balance too low to send value.");
+            }
+            other => panic!("expected a module error, got {:?}", other),
+        }
+
+        // Legacy single-byte encoding
+        let legacy = decode_with(&module_error(Value::u128(2)), balances);
+        assert_eq!(legacy.to_string(), "Balances::InsufficientBalance");
+    }
+
+    #[test]
+    fn test_decode_unresolved_module_error() {
+        let error = decode_with(&module_error(Value::u128(9)), balances);
+        assert_eq!(error.to_string(), "Pallet5::Error9");
+    }
+
+    #[test]
+    fn test_decode_other_errors() {
+        let nested = |outer: &str, inner: &str| {
+            Value::unnamed_variant(outer, [Value::unnamed_variant(inner, [])])
+        };
+
+        assert_eq!(
+            decode_with(&nested("Token", "FundsUnavailable"), balances),
+            DispatchError::Token("FundsUnavailable".to_string())
+        );
+        assert_eq!(
+            decode_with(&nested("Arithmetic", "Overflow"), balances),
+            DispatchError::Arithmetic("Overflow".to_string())
+        );
+        assert_eq!(
+            decode_with(&nested("Transactional", "LimitReached"), balances),
+            DispatchError::Transactional("LimitReached".to_string())
+        );
+        assert_eq!(
+            decode_with(&Value::unnamed_variant("BadOrigin", []), balances),
+            DispatchError::Other("BadOrigin".to_string())
+        );
+        assert_eq!(
+            decode_with(&nested("Trie", "InvalidStateRoot"), balances).to_string(),
+            "Trie::InvalidStateRoot"
+        );
+    }
+
+    #[test]
+    fn test_dispatch_error_field() {
+        let fields = Composite::Named(vec![
+            ("dispatch_info".to_string(), Value::unnamed_composite([])),
+            (
+                "dispatch_error".to_string(),
+                Value::unnamed_variant("BadOrigin", []),
+            ),
+        ]);
+        let value = dispatch_error_field(&fields).unwrap();
+        assert_eq!(
+            decode_with(value, balances),
+            DispatchError::Other("BadOrigin".to_string())
+        );
+
+        assert!(dispatch_error_field(&Composite::<()>::Named(vec![])).is_none());
+    }
+}
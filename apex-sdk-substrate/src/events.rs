@@ -0,0 +1,429 @@
+//! Runtime event watching for finalized blocks
+//!
+//! This module reads `System.Events` from finalized blocks and maps them onto
+//! the chain-agnostic [`Event`] type:
+//! - Event names use the `Pallet.Variant` form (e.g. `Balances.Transfer`)
+//! - Event fields are decoded via metadata into JSON
+//! - Address filters match events with a decoded field equal to the given
+//!   account
+
+use crate::Error;
+use apex_sdk_types::{Address, Event, EventFilter};
+use serde_json::{json, Map, Value as JsonValue};
+use subxt::events::Phase;
+use subxt::ext::scale_value::{Composite, Primitive, Value, ValueDef};
+use subxt::{OnlineClient, PolkadotConfig};
+use tracing::debug;
+
+type SubxtBlock = subxt::blocks::Block<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+/// Default maximum number of blocks backfilled behind the finalized head
+/// (about 100 minutes of 6 second blocks)
+pub const DEFAULT_MAX_BACKFILL_BLOCKS: u64 = 1_000;
+
+/// Watcher for runtime events in finalized blocks
+pub struct EventWatcher {
+    client: OnlineClient<PolkadotConfig>,
+    max_backfill_blocks: u64,
+}
+
+impl EventWatcher {
+    /// Create a new EventWatcher instance
+    pub fn new(client: OnlineClient<PolkadotConfig>) -> Self {
+        Self {
+            client,
+            max_backfill_blocks: DEFAULT_MAX_BACKFILL_BLOCKS,
+        }
+    }
+
+    /// Set the maximum number of blocks backfilled behind the finalized head
+    pub fn with_max_backfill_blocks(mut self, blocks: u64) -> Self {
+        self.max_backfill_blocks = blocks;
+        self
+    }
+
+    /// Get the events matching the filter from a specific block
+    pub async fn get_block_events(
+        &self,
+        block_hash: &str,
+        filter: &EventFilter,
+    ) -> Result<Vec<Event>, Error> {
+        let hash_bytes = hex::decode(block_hash.trim_start_matches("0x"))
+            .map_err(|e| Error::Transaction(format!("Invalid block hash: {}", e)))?;
+
+        if hash_bytes.len() != 32 {
+            return Err(Error::Transaction(
+                "Block hash must be 32 bytes".to_string(),
+            ));
+        }
+
+        let mut hash_array = [0u8; 32];
+        hash_array.copy_from_slice(&hash_bytes);
+
+        let block = self
+            .client
+            .blocks()
+            .at(subxt::utils::H256::from(hash_array))
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get block: {}", e)))?;
+
+        let accounts = filter_accounts(filter)?;
+        self.extract_events(&block, filter, &accounts).await
+    }
+
+    /// Watch finalized blocks for events matching the filter
+    ///
+    /// Starts at `filter.from_block` (or the next finalized block) and passes
+    /// every matching event to `on_event` in block order. Blocks between
+    /// `from_block` and the current finalized head are walked backwards from
+    /// the head, as are any blocks skipped by the finalized stream. Returns once
+    /// `filter.to_block` has been processed or `on_event` returns `false`.
+    ///
+    /// Walking back is limited to the backfill limit
+    /// ([`DEFAULT_MAX_BACKFILL_BLOCKS`] unless set with
+    /// [`with_max_backfill_blocks`](Self::with_max_backfill_blocks)); a
+    /// `from_block` further behind the finalized head is an error.
+    pub async fn watch<F>(&self, filter: EventFilter, mut on_event: F) -> Result<(), Error>
+    where
+        F: FnMut(Event) -> bool,
+    {
+        let accounts = filter_accounts(&filter)?;
+
+        let mut subscription = self
+            .client
+            .blocks()
+            .subscribe_finalized()
+            .await
+            .map_err(|e| {
+                Error::Connection(format!("Failed to subscribe to finalized blocks: {}", e))
+            })?;
+
+        let mut next_block = filter.from_block;
+
+        while let Some(block) = subscription.next().await {
+            let block = block
+                .map_err(|e| Error::Connection(format!("Finalized block stream error: {}", e)))?;
+            let number = block.number() as u64;
+            let start = *next_block.get_or_insert(number);
+
+            if number < start {
+                continue;
+            }
+
+            check_backfill_depth(start, number, self.max_backfill_blocks)?;
+
+            // Collect any blocks between the last processed one and this head
+            let mut pending = vec![block];
+            while let Some(oldest) = pending.last() {
+                if oldest.number() as u64 <= start {
+                    break;
+                }
+                let parent_hash = oldest.header().parent_hash;
+                let parent =
+                    self.client.blocks().at(parent_hash).await.map_err(|e| {
+                        Error::Connection(format!("Failed to traverse blocks: {}", e))
+                    })?;
+                pending.push(parent);
+            }
+
+            if pending.len() > 1 {
+                debug!("Backfilling events for blocks {}..={}", start, number);
+            }
+
+            for block in pending.iter().rev() {
+                let block_number = block.number() as u64;
+                if !filter.matches_block(block_number) {
+                    continue;
+                }
+                for event in self.extract_events(block, &filter, &accounts).await? {
+                    if !on_event(event) {
+                        return Ok(());
+                    }
+                }
+            }
+
+            next_block = Some(number + 1);
+
+            if filter.to_block.is_some_and(|to| number >= to) {
+                debug!("Event watcher reached end of block range");
+                return Ok(());
+            }
+        }
+
+        Err(Error::Connection(
+            "Finalized block subscription ended".to_string(),
+        ))
+    }
+
+    /// Extract the events matching the filter from a block
+    async fn extract_events(
+        &self,
+        block: &SubxtBlock,
+        filter: &EventFilter,
+        accounts: &[Vec<u8>],
+    ) -> Result<Vec<Event>, Error> {
+        let block_number = block.number() as u64;
+        let events = block
+            .events()
+            .await
+            .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
+
+        let mut matched = Vec::new();
+        for event in events.iter() {
+            let event =
+                event.map_err(|e| Error::Transaction(format!("Failed to decode event: {}", e)))?;
+
+            let name = format!("{}.{}", event.pallet_name(), event.variant_name());
+            if !filter.matches_name(&name) {
+                continue;
+            }
+
+            let fields = event
+                .field_values()
+                .map_err(|e| Error::Transaction(format!("Failed to decode {}: {}", name, e)))?;
+            if !involves_any(&fields, accounts) {
+                continue;
+            }
+            let data = composite_to_json(&fields);
+
+            matched.push((
+                event.phase(),
+                Event {
+                    name,
+                    data,
+                    block_number: Some(block_number),
+                    tx_hash: None,
+                    index: Some(event.index()),
+                },
+            ));
+        }
+
+        if matched.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Attach extrinsic hashes for events emitted while applying an extrinsic
+        let extrinsics = block
+            .extrinsics()
+            .await
+            .map_err(|e| Error::Transaction(format!("Failed to get extrinsics: {}", e)))?;
+        let hashes: Vec<String> = extrinsics
+            .iter()
+            .map(|ext| format!("0x{}", hex::encode(sp_core::blake2_256(ext.bytes()))))
+            .collect();
+
+        Ok(matched
+            .into_iter()
+            .map(|(phase, mut event)| {
+                if let Phase::ApplyExtrinsic(index) = phase {
+                    event.tx_hash = hashes.get(index as usize).cloned();
+                }
+                event
+            })
+            .collect())
+    }
+}
+
+/// Decode the filter addresses into raw account bytes
+fn filter_accounts(filter: &EventFilter) -> Result<Vec<Vec<u8>>, Error> {
+    use sp_core::crypto::Ss58Codec;
+
+    filter
+        .addresses
+        .iter()
+        .flatten()
+        .map(|address| match address {
+            Address::Substrate(addr) => sp_core::crypto::AccountId32::from_ss58check(addr)
+                .map(|account| AsRef::<[u8]>::as_ref(&account).to_vec())
+                .map_err(|e| Error::Other(format!("Invalid SS58 address {}: {:?}", addr, e))),
+            Address::Evm(addr) => hex::decode(addr.trim_start_matches("0x"))
+                .map_err(|e| Error::Other(format!("Invalid EVM address {}: {}", addr, e))),
+        })
+        .collect()
+}
+
+/// Check whether any decoded event field is one of the accounts
+///
+/// Fields are compared as whole byte arrays, so an account only matches an
+/// account-sized field holding exactly its bytes, at any nesting depth.
+fn involves_any<T>(fields: &Composite<T>, accounts: &[Vec<u8>]) -> bool {
+    accounts.is_empty() || composite_involves(fields, accounts)
+}
+
+fn composite_involves<T>(composite: &Composite<T>, accounts: &[Vec<u8>]) -> bool {
+    match composite {
+        Composite::Named(fields) => fields
+            .iter()
+            .any(|(_, value)| value_involves(value, accounts)),
+        Composite::Unnamed(values) => match as_byte_array(values) {
+            Some(bytes) => accounts.contains(&bytes),
+            None => values.iter().any(|value| value_involves(value, accounts)),
+        },
+    }
+}
+
+fn value_involves<T>(value: &Value<T>, accounts: &[Vec<u8>]) -> bool {
+    match &value.value {
+        ValueDef::Composite(composite) => composite_involves(composite, accounts),
+        ValueDef::Variant(variant) => composite_involves(&variant.values, accounts),
+        _ => false,
+    }
+}
+
+/// Convert decoded event fields into JSON
+///
+/// Byte arrays (such as account ids and hashes) become `0x` hex strings and
+/// integers that do not fit in a `u64`/`i64` become decimal strings.
+fn composite_to_json<T>(composite: &Composite<T>) -> JsonValue {
+    match composite {
+        Composite::Named(fields) => JsonValue::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), value_to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        Composite::Unnamed(values) => {
+            if let Some(bytes) = as_byte_array(values) {
+                return JsonValue::String(format!("0x{}", hex::encode(bytes)));
+            }
+            match values.as_slice() {
+                [single] => value_to_json(single),
+                _ => JsonValue::Array(values.iter().map(value_to_json).collect()),
+            }
+        }
+    }
+}
+
+fn value_to_json<T>(value: &Value<T>) -> JsonValue {
+    match &value.value {
+        ValueDef::Composite(composite) => composite_to_json(composite),
+        ValueDef::Variant(variant) => {
+            if variant.values.is_empty() {
+                JsonValue::String(variant.name.clone())
+            } else {
+                json!({ variant.name.clone(): composite_to_json(&variant.values) })
+            }
+        }
+        ValueDef::BitSequence(bits) => JsonValue::Array(bits.iter().map(JsonValue::Bool).collect()),
+        ValueDef::Primitive(primitive) => match primitive {
+            Primitive::Bool(b) => JsonValue::Bool(*b),
+            Primitive::Char(c) => JsonValue::String(c.to_string()),
+            Primitive::String(s) => JsonValue::String(s.clone()),
+            Primitive::U128(n) => u64::try_from(*n)
+                .map(JsonValue::from)
+                .unwrap_or_else(|_| JsonValue::String(n.to_string())),
+            Primitive::I128(n) => i64::try_from(*n)
+                .map(JsonValue::from)
+                .unwrap_or_else(|_| JsonValue::String(n.to_string())),
+            Primitive::U256(bytes) | Primitive::I256(bytes) => {
+                JsonValue::String(format!("0x{}", hex::encode(bytes)))
+            }
+        },
+    }
+}
+
+/// Interpret an unnamed composite as a byte array if every element is a `u8`
+fn as_byte_array<T>(values: &[Value<T>]) -> Option<Vec<u8>> {
+    if values.len() < 2 {
+        return None;
+    }
+    values
+        .iter()
+        .map(|value| match &value.value {
+            ValueDef::Primitive(Primitive::U128(n)) => u8::try_from(*n).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check that catching up from `start` to the finalized block `head` stays
+/// within the backfill limit
+fn check_backfill_depth(start: u64, head: u64, max_blocks: u64) -> Result<(), Error> {
+    let depth = head.saturating_sub(start);
+    if depth > max_blocks {
+        return Err(Error::Transaction(format!(
+            "Block {} is {} blocks behind finalized block {}, beyond the backfill limit of {}",
+            start, depth, head, max_blocks
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_backfill_depth() {
+        assert!(check_backfill_depth(100, 100, 0).is_ok());
+        assert!(check_backfill_depth(100, 1_100, 1_000).is_ok());
+        assert!(check_backfill_depth(100, 1_101, 1_000).is_err());
+        // Blocks ahead of the head are not backfilled
+        assert!(check_backfill_depth(200, 100, 0).is_ok());
+    }
+
+    #[test]
+    fn test_involves_any() {
+        let account: Vec<u8> = (0..32).collect();
+        let account_value =
+            || Value::unnamed_composite(account.iter().map(|b| Value::u128(*b as u128)));
+        let fields = Composite::named([
+            ("who", Value::unnamed_composite([account_value()])),
+            ("amount", Value::u128(1)),
+        ]);
+        assert!(involves_any(&fields, std::slice::from_ref(&account)));
+        assert!(involves_any(&fields, &[]));
+        assert!(!involves_any(&fields, &[vec![7; 32]]));
+
+        // Accounts inside variants are found too
+        let fields = Composite::named([("dest", Value::unnamed_variant("Id", [account_value()]))]);
+        assert!(involves_any(&fields, std::slice::from_ref(&account)));
+
+        // Bytes that merely contain the account do not match
+        let mut longer = account.clone();
+        longer.extend([0u8; 8]);
+        let fields = Composite::named([(
+            "data",
+            Value::unnamed_composite(longer.iter().map(|b| Value::u128(*b as u128))),
+        )]);
+        assert!(!involves_any(&fields, &[account]));
+    }
+
+    #[test]
+    fn test_composite_to_json() {
+        let account = Value::unnamed_composite((0..32u128).map(Value::u128));
+        let fields = Composite::named([
+            ("from", Value::unnamed_composite([account])),
+            ("amount", Value::u128(u128::MAX)),
+            ("fee", Value::u128(42)),
+            ("kind", Value::unnamed_variant("Free", [])),
+        ]);
+
+        let json = composite_to_json(&fields);
+        assert_eq!(
+            json["from"],
+            format!("0x{}", hex::encode((0..32u8).collect::<Vec<_>>()))
+        );
+        assert_eq!(json["amount"], u128::MAX.to_string());
+        assert_eq!(json["fee"], 42);
+        assert_eq!(json["kind"], "Free");
+    }
+
+    #[test]
+    fn test_filter_accounts() {
+        let filter = EventFilter {
+            event_names: None,
+            addresses: Some(vec![
+                Address::substrate("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+                Address::evm("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb7"),
+            ]),
+            from_block: None,
+            to_block: None,
+        };
+
+        let accounts = filter_accounts(&filter).unwrap();
+        assert_eq!(accounts[0].len(), 32);
+        assert_eq!(accounts[1].len(), 20);
+        assert_eq!(accounts[0][..2], [0xd4, 0x35]);
+    }
+}
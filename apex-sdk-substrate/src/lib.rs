@@ -15,6 +15,7 @@ use apex_sdk_core::{
     ReceiptWatcher, SdkError,
 };
 use apex_sdk_types::{Address, TransactionStatus, TxStatus};
+pub use apex_sdk_types::{DispatchError, ModuleError};
 use async_trait::async_trait;
 use subxt::{OnlineClient, PolkadotConfig};
 use thiserror::Error;
@@ -22,38 +23,64 @@ use tracing::{debug, info};
 
 pub mod block;
 pub mod cache;
+mod contract_codec;
 pub mod contracts;
+pub mod dispatch_error;
+pub mod events;
 pub mod metrics;
+pub mod multisig;
 pub mod nonce_manager;
 pub mod pool;
+pub mod proxy;
 pub mod signer;
 pub mod storage;
 pub mod transaction;
+pub mod tx_index;
 pub mod wallet;
+pub mod weight;
 pub mod xcm;
+pub mod xcm_program;
 
 #[cfg(feature = "typed")]
 pub mod metadata;
 
-pub use block::BlockQuery;
+pub use block::{BlockQuery, BlockWatcher};
 pub use cache::{Cache, CacheConfig};
 pub use contracts::{
-    parse_metadata, ContractCallBuilder, ContractClient, ContractMetadata, GasLimit,
-    StorageDepositLimit,
+    parse_metadata, revive_account_id, revive_address, ContractCallBuilder, ContractCallResult,
+    ContractClient, ContractEvent, ContractMetadata, ContractsPallet, DeployOptions, DryRunResult,
+    GasLimit, MessageReturn, StorageDeposit, StorageDepositLimit,
 };
+pub use dispatch_error::{decode_dispatch_error, from_subxt_dispatch_error};
+pub use events::EventWatcher;
 pub use metrics::{Metrics, MetricsSnapshot};
+pub use multisig::{
+    MultisigAccount, MultisigApproval, MultisigExecutor, MultisigOutcome, MultisigSubmission,
+    PendingMultisig, Timepoint,
+};
 pub use nonce_manager::SubstrateNonceManager;
 pub use pool::{ConnectionPool, PoolConfig};
+pub use proxy::{ProxyConfig, ProxyDefinition, ProxyList, ProxyManager, ProxyType, PureProxy};
 pub use signer::{ApexSigner, Ed25519Signer, Sr25519Signer};
 pub use storage::{AccountInfo, StorageClient, StorageQuery};
-pub use transaction::{BatchCall, BatchMode, FeeConfig, RetryConfig, TransactionExecutor};
+pub use transaction::{
+    BatchCall, BatchCallOutcome, BatchMode, BatchResult, FeeConfig, RetryConfig,
+    TransactionExecutor,
+};
+pub use tx_index::{IndexedExtrinsic, TxIndex, TxIndexConfig, TxIndexStore, TxIndexer};
 pub use wallet::{KeyPairType, Wallet, WalletManager};
+pub use weight::Weight;
 pub use xcm::{
-    AssetId, Fungibility, Junction, MultiLocation, NetworkId, WeightLimit, XcmAsset, XcmConfig,
-    XcmExecutor, XcmTransferType, XcmVersion,
+    AssetId, ForwardedXcm, Fungibility, Junction, Location, MultiLocation, NetworkId, WeightLimit,
+    XcmAsset, XcmConfig, XcmDeliveryStatus, XcmDeliveryTracker, XcmExecutor, XcmFeeEstimate,
+    XcmMessageOutcome, XcmMessageWatcher, XcmSubmission, XcmTransferType, XcmVersion,
+};
+pub use xcm_program::{
+    AssetFilter, AssetTransferType, OriginKind, TransferAssetsUsingType, XcmInstruction,
+    XcmProgram, XcmWeight,
 };
 
-/// Maximum number of blocks to search when looking up transaction history
+/// Maximum number of blocks to search when looking up transactions without an index
 const MAX_BLOCK_SEARCH_DEPTH: u32 = 100;
 
 /// Substrate adapter error
@@ -80,6 +107,9 @@ pub enum Error {
     #[error("Encoding error: {0}")]
     Encoding(String),
 
+    #[error("Dispatch error: {0}")]
+    Dispatch(DispatchError),
+
     #[error("Subxt error: {0}")]
     Subxt(Box<subxt::Error>),
 
@@ -89,7 +119,12 @@ pub enum Error {
 
 impl From<subxt::Error> for Error {
     fn from(err: subxt::Error) -> Self {
-        Error::Subxt(Box::new(err))
+        match err {
+            subxt::Error::Runtime(dispatch) => {
+                Error::Dispatch(from_subxt_dispatch_error(&dispatch))
+            }
+            err => Error::Subxt(Box::new(err)),
+        }
     }
 }
 
@@ -103,6 +138,7 @@ impl From<Error> for SdkError {
             Error::Wallet(msg) => SdkError::SignerError(msg),
             Error::Signature(msg) => SdkError::SignerError(msg),
             Error::Encoding(msg) => SdkError::TransactionError(msg),
+            Error::Dispatch(err) => SdkError::TransactionError(err.to_string()),
             Error::Subxt(err) => SdkError::ProviderError(err.to_string()),
             Error::Other(msg) => SdkError::ProviderError(msg),
         }
@@ -196,6 +232,8 @@ pub struct SubstrateAdapter {
     connected: bool,
     /// Metrics collector
     metrics: Metrics,
+    /// Extrinsic hash index, if enabled
+    tx_index: Option<TxIndex>,
 }
 
 impl SubstrateAdapter {
@@ -223,9 +261,26 @@ impl SubstrateAdapter {
             config,
             connected: true,
             metrics: Metrics::new(),
+            tx_index: None,
         })
     }
 
+    /// Index finalized extrinsics for transaction lookups at any depth
+    ///
+    /// Starts a background follower that backfills `config.backfill_blocks`
+    /// finalized blocks and then indexes each new one. Without an index,
+    /// [`get_transaction_status`](Self::get_transaction_status) only searches
+    /// the most recent blocks.
+    pub fn with_tx_index(mut self, config: TxIndexConfig) -> Result<Self> {
+        self.tx_index = Some(TxIndex::start(self.client.clone(), &config)?);
+        Ok(self)
+    }
+
+    /// Get the transaction index, if enabled
+    pub fn tx_index(&self) -> Option<&TxIndex> {
+        self.tx_index.as_ref()
+    }
+
     /// Get reference to the subxt client
     pub fn client(&self) -> &OnlineClient<PolkadotConfig> {
         &self.client
@@ -281,7 +336,21 @@ impl SubstrateAdapter {
         Ok(detailed.events)
     }
 
+    /// Get a block watcher for best or finalized blocks
+    pub fn block_watcher(&self) -> crate::block::BlockWatcher {
+        crate::block::BlockWatcher::new(self.client.clone())
+    }
+
+    /// Get an event watcher for finalized blocks
+    pub fn event_watcher(&self) -> crate::events::EventWatcher {
+        crate::events::EventWatcher::new(self.client.clone())
+    }
+
     /// Get transaction status by extrinsic hash
+    ///
+    /// Looks the hash up in the transaction index if enabled with
+    /// [`with_tx_index`](Self::with_tx_index), then searches the most recent
+    /// blocks, which also covers extrinsics that are not yet finalized.
     pub async fn get_transaction_status(&self, tx_hash: &str) -> Result<TransactionStatus> {
         if !self.connected {
             return Err(Error::Connection("Not connected".to_string()));
@@ -303,10 +372,6 @@ impl SubstrateAdapter {
         let mut hash_array = [0u8; 32];
         hash_array.copy_from_slice(&hash_bytes);
 
-        // Try to subscribe to finalized blocks and check recent history
-        // Note: This is a simplified implementation that checks recent finalized blocks
-        // For production, consider maintaining a transaction pool and using event subscriptions
-
         // Get the latest finalized block
         let latest_block = self
             .client
@@ -317,11 +382,17 @@ impl SubstrateAdapter {
 
         let latest_number = latest_block.number();
 
-        // Search backwards through recent blocks
+        // Indexed extrinsics are in finalized blocks at any depth
+        if let Some(index) = &self.tx_index {
+            if let Some(entry) = index.store().get(&hash_array)? {
+                return Ok(entry.to_status(tx_hash, latest_number, true));
+            }
+        }
+
+        // Otherwise search backwards through recent blocks
         let mut blocks_to_check = vec![];
         let start_num = latest_number.saturating_sub(MAX_BLOCK_SEARCH_DEPTH);
 
-        // Subscribe to finalized blocks and iterate backwards
         let mut current_block = latest_block;
         for _ in 0..MAX_BLOCK_SEARCH_DEPTH {
             blocks_to_check.push((current_block.number(), current_block.hash()));
@@ -355,59 +426,19 @@ impl SubstrateAdapter {
 
             // Compute hash for each extrinsic and compare
             for ext_details in extrinsics.iter() {
-                // ext_details is already an ExtrinsicDetails, no need for map_err
-                // Compute the hash from the extrinsic bytes
-                let ext_bytes = ext_details.bytes();
-                let computed_hash = sp_core::blake2_256(ext_bytes);
+                let computed_hash = sp_core::blake2_256(ext_details.bytes());
 
                 if computed_hash == hash_array {
-                    // Found the transaction! Get the extrinsic index
-                    let ext_index = ext_details.index();
-
-                    // Check events for this extrinsic
-                    let events = ext_details
-                        .events()
-                        .await
-                        .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
-
-                    let mut success = false;
-                    let mut error_msg = None;
-
-                    for event in events.iter() {
-                        let event = event.map_err(|e| {
-                            Error::Transaction(format!("Failed to decode event: {}", e))
-                        })?;
-
-                        // Check for ExtrinsicSuccess or ExtrinsicFailed
-                        if event.pallet_name() == "System" {
-                            if event.variant_name() == "ExtrinsicSuccess" {
-                                success = true;
-                            } else if event.variant_name() == "ExtrinsicFailed" {
-                                // Try to extract error details from event
-                                error_msg = Some(format!("Extrinsic {} failed", ext_index));
-                            }
-                        }
-                    }
-
-                    let confirmations = latest_number - block_num;
-
-                    return if success {
-                        // For substrate, we consider a transaction confirmed once it's included in a block
-                        // The confirmation threshold is mainly for documentation purposes
-                        Ok(TransactionStatus::confirmed(
-                            tx_hash.to_string(),
-                            block_num as u64,
-                            block_hash.to_string(),
-                            None,
-                            None,
-                            Some(confirmations),
-                        ))
-                    } else if let Some(error) = error_msg {
-                        Ok(TransactionStatus::failed(tx_hash.to_string(), error))
-                    } else {
-                        // Transaction found but status unclear
-                        Ok(TransactionStatus::unknown(tx_hash.to_string()))
-                    };
+                    let entry = IndexedExtrinsic::from_extrinsic(
+                        &ext_details,
+                        block_num,
+                        block_hash.0,
+                        &self.client.metadata(),
+                    )
+                    .await?;
+
+                    // For substrate, we consider a transaction confirmed once it's included in a block
+                    return Ok(entry.to_status(tx_hash, latest_number, false));
                 }
             }
         }
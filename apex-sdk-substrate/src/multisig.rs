@@ -0,0 +1,1079 @@
+//! Multisig accounts (pallet-multisig)
+//!
+//! A multisig account is derived from its signatories and an approval
+//! threshold. Any signatory can propose a call, which is dispatched from the
+//! multisig account once `threshold` signatories have approved it.
+//!
+//! - [`MultisigAccount`] derives the account and builds `as_multi`,
+//!   `approve_as_multi` and `cancel_as_multi` calls
+//! - [`MultisigExecutor`] resolves the timepoint and call weight from the
+//!   chain, submits approvals and queries pending `Multisig.Multisigs`
+//! - [`MultisigApproval`] exports a pending approval as a JSON file, so
+//!   cosigners can review and approve it on their own machines
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use apex_sdk_substrate::multisig::{MultisigAccount, MultisigApproval, MultisigExecutor};
+//!
+//! // 2-of-3 treasury
+//! let treasury = MultisigAccount::new(&[alice, bob, charlie], 2)?;
+//! let executor = MultisigExecutor::new(client, treasury);
+//!
+//! // Alice proposes a transfer and shares it with the cosigners
+//! let transfer = subxt::dynamic::tx("Balances", "transfer_keep_alive", args);
+//! executor.approve(&alice_wallet, &transfer).await?;
+//! executor.export_approval(&transfer).await?.save("transfer.json")?;
+//!
+//! // Bob imports the file; his approval reaches the threshold and dispatches it
+//! let approval = MultisigApproval::load("transfer.json")?;
+//! let submission = executor.approve_imported(&bob_wallet, &approval).await?;
+//! ```
+
+use crate::dispatch_error::{decode_dispatch_error, dispatch_result, transaction_error};
+use crate::storage::accounts_of;
+use crate::transaction::{decode_call, event_field};
+use crate::weight::Weight;
+use crate::{Error, Result, Sr25519Signer, Wallet};
+use apex_sdk_types::DispatchError;
+use parity_scale_codec::Encode;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use sp_core::{Bytes, H256};
+use std::path::Path;
+use subxt::dynamic::{At, Value};
+use subxt::ext::scale_value::Composite;
+use subxt::tx::{DynamicPayload, Payload};
+use subxt::{OnlineClient, PolkadotConfig};
+use tracing::{debug, info};
+
+/// Prefix hashed with the signatories and threshold to derive the account
+const MULTISIG_PREFIX: &[u8; 16] = b"modlpy/utilisuba";
+
+/// Block number and extrinsic index of the first approval of a multisig call
+///
+/// Every later approval or cancellation must reference it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timepoint {
+    /// Block number
+    pub height: u32,
+    /// Extrinsic index within the block
+    pub index: u32,
+}
+
+impl Timepoint {
+    fn encode(&self) -> Value {
+        Value::named_composite([
+            ("height", Value::u128(self.height as u128)),
+            ("index", Value::u128(self.index as u128)),
+        ])
+    }
+}
+
+/// A multisig account defined by its signatories and approval threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigAccount {
+    signatories: Vec<AccountId32>,
+    threshold: u16,
+}
+
+impl MultisigAccount {
+    /// Create a multisig account from SS58 signatory addresses
+    pub fn new<S: AsRef<str>>(signatories: &[S], threshold: u16) -> Result<Self> {
+        let signatories = signatories
+            .iter()
+            .map(|address| {
+                AccountId32::from_ss58check(address.as_ref()).map_err(|e| {
+                    Error::Transaction(format!("Invalid signatory {}: {}", address.as_ref(), e))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_accounts(signatories, threshold)
+    }
+
+    /// Create a multisig account from signatory account IDs
+    pub fn from_accounts(mut signatories: Vec<AccountId32>, threshold: u16) -> Result<Self> {
+        signatories.sort();
+        signatories.dedup();
+
+        if signatories.len() < 2 {
+            return Err(Error::Transaction(
+                "A multisig needs at least 2 distinct signatories".to_string(),
+            ));
+        }
+        if threshold < 2 || threshold as usize > signatories.len() {
+            return Err(Error::Transaction(format!(
+                "Multisig threshold must be between 2 and {}, got {}",
+                signatories.len(),
+                threshold
+            )));
+        }
+
+        Ok(Self {
+            signatories,
+            threshold,
+        })
+    }
+
+    /// The signatories, sorted
+    pub fn signatories(&self) -> &[AccountId32] {
+        &self.signatories
+    }
+
+    /// Number of approvals needed to dispatch a call
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// The multisig account ID, derived the same way as pallet-multisig
+    pub fn account_id(&self) -> AccountId32 {
+        let entropy =
+            (MULTISIG_PREFIX, &self.signatories, self.threshold).using_encoded(sp_core::blake2_256);
+        AccountId32::from(entropy)
+    }
+
+    /// SS58 address of the multisig account
+    pub fn address(&self) -> String {
+        self.account_id().to_ss58check()
+    }
+
+    /// Whether `account` is one of the signatories
+    pub fn is_signatory(&self, account: &AccountId32) -> bool {
+        self.signatories.binary_search(account).is_ok()
+    }
+
+    /// All signatories except `signer`, sorted as the pallet requires
+    pub fn other_signatories(&self, signer: &AccountId32) -> Result<Vec<AccountId32>> {
+        if !self.is_signatory(signer) {
+            return Err(Error::Transaction(format!(
+                "{} is not a signatory of multisig {}",
+                signer.to_ss58check(),
+                self.address()
+            )));
+        }
+
+        Ok(self
+            .signatories
+            .iter()
+            .filter(|account| *account != signer)
+            .cloned()
+            .collect())
+    }
+
+    /// Build a `Multisig.as_multi` call approving and, at the threshold, dispatching `call`
+    ///
+    /// `timepoint` must be `None` for the first approval and the pending
+    /// operation's timepoint afterwards; `max_weight` must cover the call's
+    /// weight for the approval that dispatches it.
+    pub fn as_multi_call(
+        &self,
+        signer: &AccountId32,
+        call: Value,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+    ) -> Result<DynamicPayload> {
+        Ok(subxt::dynamic::tx(
+            "Multisig",
+            "as_multi",
+            vec![
+                Value::u128(self.threshold as u128),
+                self.encode_other_signatories(signer)?,
+                encode_timepoint(timepoint),
+                call,
+                max_weight.encode(),
+            ],
+        ))
+    }
+
+    /// Build a `Multisig.approve_as_multi` call approving a call by its hash
+    pub fn approve_as_multi_call(
+        &self,
+        signer: &AccountId32,
+        call_hash: H256,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+    ) -> Result<DynamicPayload> {
+        Ok(subxt::dynamic::tx(
+            "Multisig",
+            "approve_as_multi",
+            vec![
+                Value::u128(self.threshold as u128),
+                self.encode_other_signatories(signer)?,
+                encode_timepoint(timepoint),
+                Value::from_bytes(call_hash.as_bytes()),
+                max_weight.encode(),
+            ],
+        ))
+    }
+
+    /// Build a `Multisig.cancel_as_multi` call; only the depositor can cancel
+    pub fn cancel_as_multi_call(
+        &self,
+        signer: &AccountId32,
+        call_hash: H256,
+        timepoint: Timepoint,
+    ) -> Result<DynamicPayload> {
+        Ok(subxt::dynamic::tx(
+            "Multisig",
+            "cancel_as_multi",
+            vec![
+                Value::u128(self.threshold as u128),
+                self.encode_other_signatories(signer)?,
+                timepoint.encode(),
+                Value::from_bytes(call_hash.as_bytes()),
+            ],
+        ))
+    }
+
+    fn encode_other_signatories(&self, signer: &AccountId32) -> Result<Value> {
+        Ok(Value::unnamed_composite(
+            self.other_signatories(signer)?
+                .iter()
+                .map(|account| Value::from_bytes(<AccountId32 as AsRef<[u8]>>::as_ref(account))),
+        ))
+    }
+}
+
+/// A multisig operation awaiting approvals, from `Multisig.Multisigs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingMultisig {
+    /// Hash of the call being approved
+    pub call_hash: H256,
+    /// Timepoint of the first approval
+    pub when: Timepoint,
+    /// Deposit reserved from the depositor
+    pub deposit: u128,
+    /// Signatory who opened the operation and can cancel it
+    pub depositor: AccountId32,
+    /// Signatories who have approved so far
+    pub approvals: Vec<AccountId32>,
+}
+
+impl PendingMultisig {
+    /// Whether `account` has already approved
+    pub fn is_approved_by(&self, account: &AccountId32) -> bool {
+        self.approvals.contains(account)
+    }
+
+    fn decode<T>(call_hash: H256, value: &Value<T>) -> Option<Self> {
+        let when = value.at("when")?;
+        Some(Self {
+            call_hash,
+            when: Timepoint {
+                height: when.at("height")?.as_u128()? as u32,
+                index: when.at("index")?.as_u128()? as u32,
+            },
+            deposit: value.at("deposit")?.as_u128()?,
+            depositor: accounts_of(value.at("depositor")?)?.pop()?,
+            approvals: accounts_of(value.at("approvals")?)?,
+        })
+    }
+}
+
+/// Result of a multisig submission, from the `Multisig` events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultisigOutcome {
+    /// The first approval opened a new operation
+    Created,
+    /// The approval was recorded; more are needed
+    Approved,
+    /// The threshold was reached and the call was dispatched with this result
+    Executed(std::result::Result<(), DispatchError>),
+    /// The operation was cancelled and the deposit returned
+    Cancelled,
+}
+
+/// A submitted multisig extrinsic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigSubmission {
+    /// Hash of the extrinsic
+    pub tx_hash: String,
+    /// Hash of the finalized block containing the extrinsic
+    pub block_hash: String,
+    /// Hash of the multisig call
+    pub call_hash: H256,
+    /// Outcome reported by the pallet
+    pub outcome: Option<MultisigOutcome>,
+}
+
+/// A pending approval exported for cosigners
+///
+/// Contains everything a cosigner needs to review and approve the call. The
+/// call data is optional; without it cosigners can approve by hash but not
+/// dispatch the call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigApproval {
+    /// SS58 address of the multisig account
+    pub multisig: String,
+    /// SS58 addresses of all signatories
+    pub signatories: Vec<String>,
+    /// Number of approvals needed
+    pub threshold: u16,
+    /// Hash of the call
+    pub call_hash: H256,
+    /// SCALE-encoded call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_data: Option<Bytes>,
+    /// Timepoint of the first approval, if the operation is already open
+    pub timepoint: Option<Timepoint>,
+    /// Weight limit for dispatching the call
+    pub max_weight: Weight,
+    /// SS58 addresses of the signatories who have approved so far
+    pub approvals: Vec<String>,
+}
+
+impl MultisigApproval {
+    /// The multisig account, checked against the exported address
+    pub fn account(&self) -> Result<MultisigAccount> {
+        let account = MultisigAccount::new(&self.signatories, self.threshold)?;
+        let expected = AccountId32::from_ss58check(&self.multisig)
+            .map_err(|e| Error::Transaction(format!("Invalid multisig address: {}", e)))?;
+
+        if account.account_id() != expected {
+            return Err(Error::Transaction(format!(
+                "Signatories and threshold do not match multisig {}",
+                self.multisig
+            )));
+        }
+        Ok(account)
+    }
+
+    /// Check the multisig address and that the call data matches the call hash
+    pub fn verify(&self) -> Result<()> {
+        self.account()?;
+
+        if let Some(call_data) = &self.call_data {
+            if H256(sp_core::blake2_256(call_data)) != self.call_hash {
+                return Err(Error::Transaction(
+                    "Call data does not match the call hash".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Serialize to JSON
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Encoding(format!("Failed to serialize approval: {}", e)))
+    }
+
+    /// Parse and verify an approval from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        let approval: Self = serde_json::from_str(json)
+            .map_err(|e| Error::Encoding(format!("Failed to parse approval: {}", e)))?;
+        approval.verify()?;
+        Ok(approval)
+    }
+
+    /// Write the approval to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json()?).map_err(|e| {
+            Error::Other(format!(
+                "Failed to write approval file {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Read and verify an approval from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            Error::Other(format!(
+                "Failed to read approval file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_json(&json)
+    }
+}
+
+/// Submits and tracks approvals for one multisig account
+pub struct MultisigExecutor {
+    client: OnlineClient<PolkadotConfig>,
+    account: MultisigAccount,
+}
+
+impl MultisigExecutor {
+    /// Create an executor for `account`
+    pub fn new(client: OnlineClient<PolkadotConfig>, account: MultisigAccount) -> Self {
+        Self { client, account }
+    }
+
+    /// The multisig account
+    pub fn account(&self) -> &MultisigAccount {
+        &self.account
+    }
+
+    /// Query the pending operation for a call hash
+    pub async fn pending(&self, call_hash: H256) -> Result<Option<PendingMultisig>> {
+        let account_id = self.account.account_id();
+        let query = subxt::dynamic::storage(
+            "Multisig",
+            "Multisigs",
+            vec![
+                Value::from_bytes(<AccountId32 as AsRef<[u8]>>::as_ref(&account_id)),
+                Value::from_bytes(call_hash.as_bytes()),
+            ],
+        );
+
+        let value = self
+            .client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to fetch latest block: {}", e)))?
+            .fetch(&query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to query Multisig::Multisigs: {}", e)))?;
+
+        match value {
+            Some(value) => {
+                let value = value
+                    .to_value()
+                    .map_err(|e| Error::Storage(format!("Failed to decode multisig: {}", e)))?;
+                PendingMultisig::decode(call_hash, &value)
+                    .map(Some)
+                    .ok_or_else(|| Error::Storage("Unexpected Multisig::Multisigs layout".into()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Query all pending operations of the multisig account
+    pub async fn pending_all(&self) -> Result<Vec<PendingMultisig>> {
+        let account_id = self.account.account_id();
+        let query = subxt::dynamic::storage(
+            "Multisig",
+            "Multisigs",
+            vec![Value::from_bytes(<AccountId32 as AsRef<[u8]>>::as_ref(
+                &account_id,
+            ))],
+        );
+
+        let mut iter = self
+            .client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to fetch latest block: {}", e)))?
+            .iter(query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to iterate Multisig::Multisigs: {}", e)))?;
+
+        let mut pending = Vec::new();
+        while let Some(entry) = iter.next().await {
+            let entry = entry
+                .map_err(|e| Error::Storage(format!("Failed to fetch storage entry: {}", e)))?;
+
+            // The call hash is the last key, hashed with Blake2_128Concat
+            let Some(call_hash) = entry
+                .key_bytes
+                .len()
+                .checked_sub(32)
+                .map(|start| H256::from_slice(&entry.key_bytes[start..]))
+            else {
+                continue;
+            };
+
+            let value = entry
+                .value
+                .to_value()
+                .map_err(|e| Error::Storage(format!("Failed to decode multisig: {}", e)))?;
+            if let Some(multisig) = PendingMultisig::decode(call_hash, &value) {
+                pending.push(multisig);
+            }
+        }
+
+        debug!(
+            "Found {} pending operations for multisig {}",
+            pending.len(),
+            self.account.address()
+        );
+        Ok(pending)
+    }
+
+    /// Query the dispatch weight of a call from `TransactionPaymentCallApi`
+    pub async fn call_weight(&self, call: &impl Payload) -> Result<Weight> {
+        let call_data = self.encode(call)?;
+        self.weight_of(&call_data).await
+    }
+
+    /// Approve `call`, dispatching it if this approval reaches the threshold
+    ///
+    /// Non-final approvals only submit the call hash (`approve_as_multi`);
+    /// the final one submits the full call (`as_multi`).
+    pub async fn approve(
+        &self,
+        wallet: &Wallet,
+        call: &impl Payload,
+    ) -> Result<MultisigSubmission> {
+        let call_data = self.encode(call)?;
+        let max_weight = self.weight_of(&call_data).await?;
+        self.submit_approval(wallet, &call_data, None, max_weight)
+            .await
+    }
+
+    /// Submit `as_multi` for `call`, with the timepoint resolved from the chain
+    pub async fn as_multi(
+        &self,
+        wallet: &Wallet,
+        call: &impl Payload,
+    ) -> Result<MultisigSubmission> {
+        let call_data = self.encode(call)?;
+        let call_hash = H256(sp_core::blake2_256(&call_data));
+        let signer = signer_account(wallet)?;
+
+        let timepoint = self.pending(call_hash).await?.map(|pending| pending.when);
+        let max_weight = self.weight_of(&call_data).await?;
+        let payload = self.account.as_multi_call(
+            &signer,
+            decode_call(&self.client.metadata(), &call_data)?,
+            timepoint,
+            max_weight,
+        )?;
+
+        self.submit(&payload, wallet, call_hash).await
+    }
+
+    /// Submit `approve_as_multi` for a call hash, with the timepoint resolved from the chain
+    pub async fn approve_as_multi(
+        &self,
+        wallet: &Wallet,
+        call_hash: H256,
+        max_weight: Weight,
+    ) -> Result<MultisigSubmission> {
+        let signer = signer_account(wallet)?;
+        let timepoint = self.pending(call_hash).await?.map(|pending| pending.when);
+        let payload = self
+            .account
+            .approve_as_multi_call(&signer, call_hash, timepoint, max_weight)?;
+
+        self.submit(&payload, wallet, call_hash).await
+    }
+
+    /// Cancel a pending operation; only its depositor can do this
+    pub async fn cancel_as_multi(
+        &self,
+        wallet: &Wallet,
+        call_hash: H256,
+    ) -> Result<MultisigSubmission> {
+        let signer = signer_account(wallet)?;
+        let pending = self.pending(call_hash).await?.ok_or_else(|| {
+            Error::Transaction(format!("No pending multisig operation for {:?}", call_hash))
+        })?;
+
+        let payload = self
+            .account
+            .cancel_as_multi_call(&signer, call_hash, pending.when)?;
+        self.submit(&payload, wallet, call_hash).await
+    }
+
+    /// Export the current state of `call` for cosigners
+    pub async fn export_approval(&self, call: &impl Payload) -> Result<MultisigApproval> {
+        let call_data = self.encode(call)?;
+        let call_hash = H256(sp_core::blake2_256(&call_data));
+        let max_weight = self.weight_of(&call_data).await?;
+        let pending = self.pending(call_hash).await?;
+
+        Ok(MultisigApproval {
+            multisig: self.account.address(),
+            signatories: self
+                .account
+                .signatories()
+                .iter()
+                .map(|account| account.to_ss58check())
+                .collect(),
+            threshold: self.account.threshold(),
+            call_hash,
+            call_data: Some(Bytes(call_data)),
+            timepoint: pending.as_ref().map(|pending| pending.when),
+            max_weight,
+            approvals: pending
+                .map(|pending| {
+                    pending
+                        .approvals
+                        .iter()
+                        .map(|account| account.to_ss58check())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Approve an imported [`MultisigApproval`]
+    ///
+    /// Dispatches the call if this approval reaches the threshold, which
+    /// requires the approval to include the call data.
+    pub async fn approve_imported(
+        &self,
+        wallet: &Wallet,
+        approval: &MultisigApproval,
+    ) -> Result<MultisigSubmission> {
+        approval.verify()?;
+        if approval.account()? != self.account {
+            return Err(Error::Transaction(format!(
+                "Approval is for multisig {}, not {}",
+                approval.multisig,
+                self.account.address()
+            )));
+        }
+
+        match &approval.call_data {
+            Some(call_data) => {
+                self.submit_approval(wallet, call_data, approval.timepoint, approval.max_weight)
+                    .await
+            }
+            None => {
+                let pending = self.pending(approval.call_hash).await?;
+                check_timepoint(approval.timepoint, pending.as_ref())?;
+                if self.is_final_approval(pending.as_ref()) {
+                    return Err(Error::Transaction(
+                        "The final approval needs the call data, which the approval does not include"
+                            .to_string(),
+                    ));
+                }
+                self.approve_as_multi(wallet, approval.call_hash, approval.max_weight)
+                    .await
+            }
+        }
+    }
+
+    /// Approve encoded call data, submitting the full call only when it will dispatch
+    async fn submit_approval(
+        &self,
+        wallet: &Wallet,
+        call_data: &[u8],
+        expected_timepoint: Option<Timepoint>,
+        max_weight: Weight,
+    ) -> Result<MultisigSubmission> {
+        let signer = signer_account(wallet)?;
+        let call_hash = H256(sp_core::blake2_256(call_data));
+        let pending = self.pending(call_hash).await?;
+        check_timepoint(expected_timepoint, pending.as_ref())?;
+
+        if pending
+            .as_ref()
+            .is_some_and(|pending| pending.is_approved_by(&signer))
+        {
+            return Err(Error::Transaction(format!(
+                "{} has already approved {:?}",
+                signer.to_ss58check(),
+                call_hash
+            )));
+        }
+
+        let timepoint = pending.as_ref().map(|pending| pending.when);
+        let payload = if self.is_final_approval(pending.as_ref()) {
+            let call = decode_call(&self.client.metadata(), call_data)?;
+            self.account
+                .as_multi_call(&signer, call, timepoint, max_weight)?
+        } else {
+            self.account
+                .approve_as_multi_call(&signer, call_hash, timepoint, max_weight)?
+        };
+
+        self.submit(&payload, wallet, call_hash).await
+    }
+
+    fn is_final_approval(&self, pending: Option<&PendingMultisig>) -> bool {
+        let approvals = pending.map_or(0, |pending| pending.approvals.len());
+        approvals + 1 >= self.account.threshold() as usize
+    }
+
+    fn encode(&self, call: &impl Payload) -> Result<Vec<u8>> {
+        call.encode_call_data(&self.client.metadata())
+            .map_err(|e| Error::Encoding(format!("Failed to encode call: {}", e)))
+    }
+
+    async fn weight_of(&self, call_data: &[u8]) -> Result<Weight> {
+        let call = decode_call(&self.client.metadata(), call_data)?;
+        let payload = subxt::dynamic::runtime_api_call(
+            "TransactionPaymentCallApi",
+            "query_call_info",
+            vec![call, Value::u128(call_data.len() as u128)],
+        );
+
+        let info = self
+            .client
+            .runtime_api()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get latest block: {}", e)))?
+            .call(payload)
+            .await
+            .map_err(|e| Error::Transaction(format!("Failed to query call weight: {}", e)))?
+            .to_value()
+            .map_err(|e| Error::Metadata(format!("Failed to decode call info: {}", e)))?;
+
+        let weight = info.at("weight");
+        match (
+            weight.at("ref_time").and_then(|v| v.as_u128()),
+            weight.at("proof_size").and_then(|v| v.as_u128()),
+        ) {
+            (Some(ref_time), Some(proof_size)) => {
+                Ok(Weight::new(ref_time as u64, proof_size as u64))
+            }
+            _ => Err(Error::Metadata(
+                "Unexpected TransactionPaymentCallApi response".to_string(),
+            )),
+        }
+    }
+
+    async fn submit(
+        &self,
+        payload: &DynamicPayload,
+        wallet: &Wallet,
+        call_hash: H256,
+    ) -> Result<MultisigSubmission> {
+        debug!(
+            "Submitting Multisig.{} for {}",
+            payload.call_name(),
+            self.account.address()
+        );
+
+        let pair = wallet
+            .sr25519_pair()
+            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+        let signer = Sr25519Signer::new(pair.clone());
+
+        let mut progress = self
+            .client
+            .tx()
+            .sign_and_submit_then_watch_default(payload, &signer)
+            .await
+            .map_err(|e| {
+                Error::Transaction(format!("Failed to submit multisig transaction: {}", e))
+            })?;
+
+        while let Some(event) = progress.next().await {
+            let event = event
+                .map_err(|e| Error::Transaction(format!("Multisig transaction error: {}", e)))?;
+
+            if event.as_in_block().is_some() {
+                info!("Multisig transaction included in block");
+            }
+
+            if let Some(finalized) = event.as_finalized() {
+                let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
+                info!("Multisig transaction finalized: {}", tx_hash);
+
+                let events = finalized
+                    .wait_for_success()
+                    .await
+                    .map_err(|e| transaction_error("Multisig transaction failed", e))?;
+
+                let metadata = self.client.metadata();
+                let mut outcome = None;
+                for event in events.iter() {
+                    let event = event?;
+                    if event.pallet_name() == "Multisig" {
+                        outcome = multisig_outcome(
+                            event.variant_name(),
+                            &event.field_values()?,
+                            |error| decode_dispatch_error(error, &metadata),
+                        )
+                        .or(outcome);
+                    }
+                }
+
+                return Ok(MultisigSubmission {
+                    tx_hash,
+                    block_hash: format!("0x{}", hex::encode(finalized.block_hash())),
+                    call_hash,
+                    outcome,
+                });
+            }
+        }
+
+        Err(Error::Transaction(
+            "Multisig transaction stream ended without finalization".to_string(),
+        ))
+    }
+}
+
+fn signer_account(wallet: &Wallet) -> Result<AccountId32> {
+    let pair = wallet
+        .sr25519_pair()
+        .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+    Ok(AccountId32::from(sp_core::Pair::public(pair)))
+}
+
+/// Check that an exported timepoint still refers to the pending operation
+fn check_timepoint(expected: Option<Timepoint>, pending: Option<&PendingMultisig>) -> Result<()> {
+    match (expected, pending) {
+        (Some(expected), Some(pending)) if expected != pending.when => {
+            Err(Error::Transaction(format!(
+                "Approval timepoint {}:{} does not match the pending operation at {}:{}",
+                expected.height, expected.index, pending.when.height, pending.when.index
+            )))
+        }
+        (Some(_), None) => Err(Error::Transaction(
+            "The multisig operation is no longer pending".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn encode_timepoint(timepoint: Option<Timepoint>) -> Value {
+    match timepoint {
+        Some(timepoint) => Value::unnamed_variant("Some", [timepoint.encode()]),
+        None => Value::unnamed_variant("None", []),
+    }
+}
+
+/// Map a `Multisig` event to the outcome it reports
+fn multisig_outcome(
+    variant: &str,
+    fields: &Composite<u32>,
+    decode: impl Fn(&Value<u32>) -> DispatchError,
+) -> Option<MultisigOutcome> {
+    match variant {
+        "NewMultisig" => Some(MultisigOutcome::Created),
+        "MultisigApproval" => Some(MultisigOutcome::Approved),
+        "MultisigCancelled" => Some(MultisigOutcome::Cancelled),
+        "MultisigExecuted" => event_field(fields, "result", 4)
+            .and_then(|result| dispatch_result(result, decode))
+            .map(MultisigOutcome::Executed),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::Pair as _;
+    use sp_core::sr25519;
+
+    fn account(seed: &str) -> AccountId32 {
+        AccountId32::from(sr25519::Pair::from_string(seed, None).unwrap().public())
+    }
+
+    fn treasury() -> MultisigAccount {
+        MultisigAccount::from_accounts(
+            vec![account("//Charlie"), account("//Alice"), account("//Bob")],
+            2,
+        )
+        .unwrap()
+    }
+
+    fn bytes_value(bytes: &[u8]) -> Value<u32> {
+        Value::from_bytes(bytes).map_context(|_| 0)
+    }
+
+    #[test]
+    fn test_multisig_account_id() {
+        // Same address as polkadot.js `createKeyMulti` for Alice, Bob and Charlie at 2-of-3
+        assert_eq!(
+            treasury().address(),
+            "5DjYJStmdZ2rcqXbXGX7TW85JsrW6uG4y9MUcLq2BoPMpRA7"
+        );
+
+        let reordered = MultisigAccount::new(
+            &[
+                account("//Bob").to_ss58check(),
+                account("//Charlie").to_ss58check(),
+                account("//Alice").to_ss58check(),
+            ],
+            2,
+        )
+        .unwrap();
+        assert_eq!(reordered.account_id(), treasury().account_id());
+
+        let three_of_three = MultisigAccount::from_accounts(treasury().signatories().to_vec(), 3);
+        assert_ne!(
+            three_of_three.unwrap().account_id(),
+            treasury().account_id()
+        );
+    }
+
+    #[test]
+    fn test_multisig_account_validation() {
+        let alice = account("//Alice");
+        assert!(MultisigAccount::from_accounts(vec![alice.clone(), alice.clone()], 2).is_err());
+        assert!(MultisigAccount::from_accounts(treasury().signatories().to_vec(), 1).is_err());
+        assert!(MultisigAccount::from_accounts(treasury().signatories().to_vec(), 4).is_err());
+        assert!(MultisigAccount::new(&["not an address"], 2).is_err());
+
+        let others = treasury().other_signatories(&alice).unwrap();
+        assert_eq!(others.len(), 2);
+        assert!(others.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(treasury().other_signatories(&account("//Dave")).is_err());
+    }
+
+    #[test]
+    fn test_multisig_calls() {
+        let alice = account("//Alice");
+        let timepoint = Timepoint {
+            height: 100,
+            index: 2,
+        };
+        let weight = Weight::new(1_000_000, 10_000);
+        let remark = subxt::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+
+        let as_multi = treasury()
+            .as_multi_call(&alice, remark.into_value(), None, weight)
+            .unwrap();
+        assert_eq!(as_multi.call_name(), "as_multi");
+        assert_eq!(as_multi.call_data().len(), 5);
+
+        let approve = treasury()
+            .approve_as_multi_call(&alice, H256::repeat_byte(1), Some(timepoint), weight)
+            .unwrap();
+        assert_eq!(approve.call_name(), "approve_as_multi");
+
+        let cancel = treasury()
+            .cancel_as_multi_call(&alice, H256::repeat_byte(1), timepoint)
+            .unwrap();
+        assert_eq!(cancel.pallet_name(), "Multisig");
+        assert_eq!(cancel.call_data().len(), 4);
+
+        assert!(treasury()
+            .cancel_as_multi_call(&account("//Dave"), H256::repeat_byte(1), timepoint)
+            .is_err());
+    }
+
+    #[test]
+    fn test_decode_pending_multisig() {
+        let (alice, bob) = (account("//Alice"), account("//Bob"));
+        let value = Value::named_composite([
+            (
+                "when",
+                Value::named_composite([("height", Value::u128(100)), ("index", Value::u128(2))]),
+            ),
+            ("deposit", Value::u128(5_000)),
+            ("depositor", Value::from_bytes(alice.as_ref() as &[u8])),
+            (
+                "approvals",
+                Value::unnamed_composite([Value::unnamed_composite([Value::from_bytes(
+                    alice.as_ref() as &[u8],
+                )])]),
+            ),
+        ]);
+
+        let pending = PendingMultisig::decode(H256::repeat_byte(1), &value).unwrap();
+        assert_eq!(
+            pending.when,
+            Timepoint {
+                height: 100,
+                index: 2
+            }
+        );
+        assert_eq!(pending.deposit, 5_000);
+        assert_eq!(pending.depositor, alice);
+        assert!(pending.is_approved_by(&alice));
+        assert!(!pending.is_approved_by(&bob));
+    }
+
+    #[test]
+    fn test_multisig_outcome() {
+        let empty = Composite::Named(vec![]);
+        let decode = |_: &Value<u32>| DispatchError::Other("BadOrigin".to_string());
+        assert_eq!(
+            multisig_outcome("NewMultisig", &empty, decode),
+            Some(MultisigOutcome::Created)
+        );
+        assert_eq!(
+            multisig_outcome("MultisigApproval", &empty, decode),
+            Some(MultisigOutcome::Approved)
+        );
+        assert_eq!(multisig_outcome("Deposit", &empty, decode), None);
+
+        let executed = |result: Value<u32>| {
+            Composite::Named(vec![
+                ("approving".to_string(), bytes_value(&[0; 32])),
+                ("result".to_string(), result),
+            ])
+        };
+        let ok = executed(Value::unnamed_variant("Ok", []).map_context(|_| 0));
+        assert_eq!(
+            multisig_outcome("MultisigExecuted", &ok, decode),
+            Some(MultisigOutcome::Executed(Ok(())))
+        );
+
+        let err = executed(
+            Value::unnamed_variant("Err", [Value::unnamed_variant("BadOrigin", [])])
+                .map_context(|_| 0),
+        );
+        assert_eq!(
+            multisig_outcome("MultisigExecuted", &err, decode),
+            Some(MultisigOutcome::Executed(Err(DispatchError::Other(
+                "BadOrigin".to_string()
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_approval_file_round_trip() {
+        let account = treasury();
+        let call_data = vec![0, 7, 8, 104, 105];
+        let approval = MultisigApproval {
+            multisig: account.address(),
+            signatories: account
+                .signatories()
+                .iter()
+                .map(|account| account.to_ss58check())
+                .collect(),
+            threshold: 2,
+            call_hash: H256(sp_core::blake2_256(&call_data)),
+            call_data: Some(Bytes(call_data)),
+            timepoint: Some(Timepoint {
+                height: 100,
+                index: 2,
+            }),
+            max_weight: Weight::new(1_000_000, 10_000),
+            approvals: vec![account.signatories()[0].to_ss58check()],
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("approval.json");
+        approval.save(&path).unwrap();
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert!(json.contains(&format!("{:?}", approval.call_hash)));
+
+        let loaded = MultisigApproval::load(&path).unwrap();
+        assert_eq!(loaded, approval);
+        assert_eq!(loaded.account().unwrap(), account);
+
+        let mut tampered = approval.clone();
+        tampered.call_data = Some(Bytes(vec![0, 7, 8, 104]));
+        assert!(MultisigApproval::from_json(&tampered.to_json().unwrap()).is_err());
+
+        let mut wrong_threshold = approval;
+        wrong_threshold.threshold = 3;
+        assert!(wrong_threshold.verify().is_err());
+    }
+
+    #[test]
+    fn test_check_timepoint() {
+        let timepoint = Timepoint {
+            height: 100,
+            index: 2,
+        };
+        let pending = PendingMultisig {
+            call_hash: H256::zero(),
+            when: timepoint,
+            deposit: 0,
+            depositor: account("//Alice"),
+            approvals: vec![account("//Alice")],
+        };
+
+        assert!(check_timepoint(None, None).is_ok());
+        assert!(check_timepoint(None, Some(&pending)).is_ok());
+        assert!(check_timepoint(Some(timepoint), Some(&pending)).is_ok());
+        assert!(check_timepoint(Some(timepoint), None).is_err());
+        assert!(check_timepoint(
+            Some(Timepoint {
+                height: 99,
+                index: 2
+            }),
+            Some(&pending)
+        )
+        .is_err());
+    }
+}
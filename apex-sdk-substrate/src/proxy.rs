@@ -0,0 +1,744 @@
+//! Proxy accounts (pallet-proxy)
+//!
+//! A proxy account signs calls on behalf of a "real" account, restricted by a
+//! proxy type such as `Staking` or `NonTransfer`:
+//! - [`ProxyConfig`] wraps calls in `Proxy.proxy`. Pass it to the `_as`
+//!   methods of [`TransactionExecutor`](crate::TransactionExecutor) and
+//!   [`XcmExecutor`](crate::xcm::XcmExecutor), such as `transfer_as`, to
+//!   dispatch a submission as the real account
+//! - [`ProxyManager`] adds and removes proxies, creates pure proxies and
+//!   lists `Proxy.Proxies`
+//!
+//! Proxy types are runtime-specific. The type is checked against the runtime
+//! metadata, and calls are checked against the filters the Polkadot and
+//! Kusama runtimes use for the standard types; the runtime has the final say.
+//! Proxies with an announcement delay are not supported for submissions.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use apex_sdk_substrate::proxy::{ProxyConfig, ProxyType};
+//!
+//! // The hot wallet signs; the transfer is dispatched from the cold stash
+//! let proxy = ProxyConfig::new(&stash_address)?.with_proxy_type(ProxyType::Any);
+//! let tx_hash = executor
+//!     .transfer_as(&hot_wallet, &proxy, &recipient, amount)
+//!     .await?;
+//! ```
+
+use crate::dispatch_error::{decode_dispatch_error, dispatch_result, transaction_error};
+use crate::storage::accounts_of;
+use crate::transaction::{decode_call, event_field};
+use crate::{Error, Result, Sr25519Signer, Wallet};
+use apex_sdk_types::DispatchError;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::fmt;
+use subxt::blocks::ExtrinsicEvents;
+use subxt::dynamic::{At, Value};
+use subxt::ext::scale_encode::EncodeAsType;
+use subxt::ext::scale_value::{Composite, ValueDef};
+use subxt::tx::{DynamicPayload, Payload};
+use subxt::utils::H256;
+use subxt::{Metadata, OnlineClient, PolkadotConfig};
+use tracing::{debug, info};
+
+/// Utility calls whose inner calls are checked against the proxy type
+const BATCH_CALLS: [&str; 3] = ["batch", "batch_all", "force_batch"];
+
+/// Proxy type restricting which calls a proxy can make
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProxyType {
+    /// All calls
+    Any,
+    /// All calls except balance transfers
+    NonTransfer,
+    /// Governance calls
+    Governance,
+    /// Staking calls
+    Staking,
+    /// Only rejecting announcements
+    CancelProxy,
+    /// Parachain auction and crowdloan calls
+    Auction,
+    /// Nomination pool calls
+    NominationPools,
+    /// A runtime-specific proxy type, by variant name
+    Other(String),
+}
+
+impl ProxyType {
+    /// The variant name in the runtime's `ProxyType` enum
+    pub fn name(&self) -> &str {
+        match self {
+            ProxyType::Any => "Any",
+            ProxyType::NonTransfer => "NonTransfer",
+            ProxyType::Governance => "Governance",
+            ProxyType::Staking => "Staking",
+            ProxyType::CancelProxy => "CancelProxy",
+            ProxyType::Auction => "Auction",
+            ProxyType::NominationPools => "NominationPools",
+            ProxyType::Other(name) => name,
+        }
+    }
+
+    /// Parse a variant name of the runtime's `ProxyType` enum
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "Any" => ProxyType::Any,
+            "NonTransfer" => ProxyType::NonTransfer,
+            "Governance" => ProxyType::Governance,
+            "Staking" => ProxyType::Staking,
+            "CancelProxy" => ProxyType::CancelProxy,
+            "Auction" => ProxyType::Auction,
+            "NominationPools" => ProxyType::NominationPools,
+            other => ProxyType::Other(other.to_string()),
+        }
+    }
+
+    /// Whether this proxy type's call filter allows `pallet.call`
+    ///
+    /// Follows the Polkadot and Kusama runtimes; returns `None` for
+    /// runtime-specific types, whose filters are unknown.
+    pub fn allows(&self, pallet: &str, call: &str) -> Option<bool> {
+        let allowed = match self {
+            ProxyType::Any => true,
+            // An allowlist, so pallets that move funds such as XcmPallet are
+            // rejected along with Balances and Assets
+            ProxyType::NonTransfer => matches!(
+                (pallet, call),
+                (
+                    "System"
+                        | "Scheduler"
+                        | "Preimage"
+                        | "Babe"
+                        | "Timestamp"
+                        | "Staking"
+                        | "Session"
+                        | "Grandpa"
+                        | "Treasury"
+                        | "Bounties"
+                        | "ChildBounties"
+                        | "ConvictionVoting"
+                        | "Referenda"
+                        | "Whitelist"
+                        | "Claims"
+                        | "Utility"
+                        | "Identity"
+                        | "Proxy"
+                        | "Multisig"
+                        | "Registrar"
+                        | "Slots"
+                        | "Auctions"
+                        | "Crowdloan"
+                        | "VoterList"
+                        | "NominationPools"
+                        | "FastUnstake",
+                    _
+                ) | ("Indices", "claim" | "free" | "freeze")
+                    | ("Vesting", "vest" | "vest_other")
+            ),
+            ProxyType::Governance => matches!(
+                pallet,
+                "Treasury"
+                    | "Bounties"
+                    | "ChildBounties"
+                    | "ConvictionVoting"
+                    | "Referenda"
+                    | "Whitelist"
+                    | "Utility"
+            ),
+            ProxyType::Staking => matches!(
+                pallet,
+                "Staking" | "Session" | "Utility" | "FastUnstake" | "VoterList" | "NominationPools"
+            ),
+            ProxyType::CancelProxy => {
+                matches!(
+                    (pallet, call),
+                    ("Proxy", "reject_announcement") | ("Utility", _)
+                )
+            }
+            ProxyType::Auction => matches!(
+                pallet,
+                "Auctions" | "Crowdloan" | "Registrar" | "Slots" | "Utility"
+            ),
+            ProxyType::NominationPools => matches!(pallet, "NominationPools" | "Utility"),
+            ProxyType::Other(_) => return None,
+        };
+        Some(allowed)
+    }
+
+    fn encode(&self) -> Value {
+        Value::unnamed_variant(self.name(), [])
+    }
+
+    /// Check that the runtime's `ProxyType` enum has this variant
+    fn validate(&self, metadata: &Metadata) -> Result<()> {
+        let type_id = metadata
+            .pallet_by_name("Proxy")
+            .and_then(|pallet| pallet.call_variant_by_name("add_proxy"))
+            .and_then(|call| {
+                call.fields
+                    .iter()
+                    .find(|field| field.name.as_deref() == Some("proxy_type"))
+            })
+            .map(|field| field.ty.id)
+            .ok_or_else(|| Error::Metadata("Runtime has no Proxy.add_proxy call".to_string()))?;
+
+        self.encode()
+            .encode_as_type(type_id, metadata.types())
+            .map(|_| ())
+            .map_err(|_| {
+                Error::Transaction(format!(
+                    "Proxy type {} is not supported by this runtime",
+                    self
+                ))
+            })
+    }
+}
+
+impl fmt::Display for ProxyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Dispatches calls through a proxy on behalf of a real account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// The account the calls are dispatched as
+    pub real: AccountId32,
+    /// Proxy type to use; `None` lets the runtime pick any matching proxy
+    pub proxy_type: Option<ProxyType>,
+}
+
+impl ProxyConfig {
+    /// Proxy for the account at SS58 address `real`
+    pub fn new(real: &str) -> Result<Self> {
+        let real = AccountId32::from_ss58check(real)
+            .map_err(|e| Error::Transaction(format!("Invalid real account address: {}", e)))?;
+        Ok(Self {
+            real,
+            proxy_type: None,
+        })
+    }
+
+    /// Force a specific proxy type
+    pub fn with_proxy_type(mut self, proxy_type: ProxyType) -> Self {
+        self.proxy_type = Some(proxy_type);
+        self
+    }
+
+    /// Wrap `call` in `Proxy.proxy`
+    ///
+    /// When a proxy type is set, it is checked against the runtime metadata
+    /// and its known call filter.
+    pub fn wrap(&self, metadata: &Metadata, call: &impl Payload) -> Result<DynamicPayload> {
+        let call_data = call
+            .encode_call_data(metadata)
+            .map_err(|e| Error::Encoding(format!("Failed to encode call: {}", e)))?;
+        let call = decode_call(metadata, &call_data)?;
+
+        let force_proxy_type = match &self.proxy_type {
+            Some(proxy_type) => {
+                proxy_type.validate(metadata)?;
+                check_call(proxy_type, &call)?;
+                Value::unnamed_variant("Some", [proxy_type.encode()])
+            }
+            None => Value::unnamed_variant("None", []),
+        };
+
+        Ok(subxt::dynamic::tx(
+            "Proxy",
+            "proxy",
+            vec![multi_address(&self.real), force_proxy_type, call],
+        ))
+    }
+}
+
+/// A proxy registered for an account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyDefinition {
+    /// The proxy account
+    pub delegate: AccountId32,
+    /// What the proxy is allowed to do
+    pub proxy_type: ProxyType,
+    /// Announcement delay in blocks
+    pub delay: u32,
+}
+
+/// Proxies of an account, from `Proxy.Proxies`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxyList {
+    /// Registered proxies
+    pub proxies: Vec<ProxyDefinition>,
+    /// Deposit reserved for them
+    pub deposit: u128,
+}
+
+impl ProxyList {
+    fn decode<T>(value: &Value<T>) -> Option<Self> {
+        let mut proxies = Vec::new();
+        collect_definitions(value.at(0)?, &mut proxies)?;
+        Some(Self {
+            proxies,
+            deposit: value.at(1)?.as_u128()?,
+        })
+    }
+}
+
+/// A pure proxy created with `Proxy.create_pure`
+///
+/// The block number and extrinsic index are needed to kill it later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PureProxy {
+    /// The keyless pure proxy account
+    pub account: AccountId32,
+    /// Proxy type the creator holds over it
+    pub proxy_type: ProxyType,
+    /// Disambiguation index it was created with
+    pub index: u16,
+    /// Hash of the creating extrinsic
+    pub tx_hash: String,
+    /// Block the creating extrinsic was included in
+    pub block_number: u32,
+    /// Index of the creating extrinsic in its block
+    pub extrinsic_index: u32,
+}
+
+/// Manages the proxies of accounts
+pub struct ProxyManager {
+    client: OnlineClient<PolkadotConfig>,
+}
+
+impl ProxyManager {
+    /// Create a proxy manager
+    pub fn new(client: OnlineClient<PolkadotConfig>) -> Self {
+        Self { client }
+    }
+
+    /// List the proxies of an account
+    pub async fn proxies(&self, address: &str) -> Result<ProxyList> {
+        let account = AccountId32::from_ss58check(address)
+            .map_err(|e| Error::Storage(format!("Invalid SS58 address: {}", e)))?;
+        let query = subxt::dynamic::storage(
+            "Proxy",
+            "Proxies",
+            vec![Value::from_bytes(<AccountId32 as AsRef<[u8]>>::as_ref(
+                &account,
+            ))],
+        );
+
+        let value = self
+            .client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to fetch latest block: {}", e)))?
+            .fetch(&query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to query Proxy::Proxies: {}", e)))?;
+
+        match value {
+            Some(value) => {
+                let value = value
+                    .to_value()
+                    .map_err(|e| Error::Storage(format!("Failed to decode proxies: {}", e)))?;
+                ProxyList::decode(&value)
+                    .ok_or_else(|| Error::Storage("Unexpected Proxy::Proxies layout".to_string()))
+            }
+            None => Ok(ProxyList::default()),
+        }
+    }
+
+    /// Register `delegate` as a proxy of the wallet's account
+    pub async fn add_proxy(
+        &self,
+        wallet: &Wallet,
+        delegate: &str,
+        proxy_type: ProxyType,
+        delay: u32,
+    ) -> Result<String> {
+        let call = self.proxy_call("add_proxy", delegate, &proxy_type, delay)?;
+        let (tx_hash, ..) = self.submit(&call, wallet).await?;
+        Ok(tx_hash)
+    }
+
+    /// Unregister a proxy of the wallet's account
+    pub async fn remove_proxy(
+        &self,
+        wallet: &Wallet,
+        delegate: &str,
+        proxy_type: ProxyType,
+        delay: u32,
+    ) -> Result<String> {
+        let call = self.proxy_call("remove_proxy", delegate, &proxy_type, delay)?;
+        let (tx_hash, ..) = self.submit(&call, wallet).await?;
+        Ok(tx_hash)
+    }
+
+    /// Create a pure proxy account controlled by the wallet's account
+    ///
+    /// `index` distinguishes pure proxies created in the same transaction;
+    /// use 0 otherwise.
+    pub async fn create_pure(
+        &self,
+        wallet: &Wallet,
+        proxy_type: ProxyType,
+        delay: u32,
+        index: u16,
+    ) -> Result<PureProxy> {
+        proxy_type.validate(&self.client.metadata())?;
+        let call = subxt::dynamic::tx(
+            "Proxy",
+            "create_pure",
+            vec![
+                proxy_type.encode(),
+                Value::u128(delay as u128),
+                Value::u128(index as u128),
+            ],
+        );
+
+        let (tx_hash, block_hash, events) = self.submit(&call, wallet).await?;
+
+        let mut pure = None;
+        for event in events.iter() {
+            let event = event?;
+            if event.pallet_name() == "Proxy" && event.variant_name() == "PureCreated" {
+                pure = event_field(&event.field_values()?, "pure", 0)
+                    .and_then(|pure| accounts_of(pure)?.pop());
+            }
+        }
+        let account =
+            pure.ok_or_else(|| Error::Transaction("No Proxy.PureCreated event found".to_string()))?;
+
+        let block_number = self
+            .client
+            .blocks()
+            .at(block_hash)
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to fetch block: {}", e)))?
+            .number();
+
+        info!("Created pure proxy {}", account.to_ss58check());
+        Ok(PureProxy {
+            account,
+            proxy_type,
+            index,
+            tx_hash,
+            block_number,
+            extrinsic_index: events.extrinsic_index(),
+        })
+    }
+
+    fn proxy_call(
+        &self,
+        call: &str,
+        delegate: &str,
+        proxy_type: &ProxyType,
+        delay: u32,
+    ) -> Result<DynamicPayload> {
+        let delegate = AccountId32::from_ss58check(delegate)
+            .map_err(|e| Error::Transaction(format!("Invalid delegate address: {}", e)))?;
+        proxy_type.validate(&self.client.metadata())?;
+
+        Ok(subxt::dynamic::tx(
+            "Proxy",
+            call,
+            vec![
+                multi_address(&delegate),
+                proxy_type.encode(),
+                Value::u128(delay as u128),
+            ],
+        ))
+    }
+
+    async fn submit(
+        &self,
+        call: &DynamicPayload,
+        wallet: &Wallet,
+    ) -> Result<(String, H256, ExtrinsicEvents<PolkadotConfig>)> {
+        debug!("Submitting Proxy.{}", call.call_name());
+
+        let pair = wallet
+            .sr25519_pair()
+            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+        let signer = Sr25519Signer::new(pair.clone());
+
+        let mut progress = self
+            .client
+            .tx()
+            .sign_and_submit_then_watch_default(call, &signer)
+            .await
+            .map_err(|e| {
+                Error::Transaction(format!("Failed to submit proxy transaction: {}", e))
+            })?;
+
+        while let Some(event) = progress.next().await {
+            let event =
+                event.map_err(|e| Error::Transaction(format!("Proxy transaction error: {}", e)))?;
+
+            if let Some(finalized) = event.as_finalized() {
+                let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
+                info!("Proxy transaction finalized: {}", tx_hash);
+
+                let events = finalized
+                    .wait_for_success()
+                    .await
+                    .map_err(|e| transaction_error("Proxy transaction failed", e))?;
+                return Ok((tx_hash, finalized.block_hash(), events));
+            }
+        }
+
+        Err(Error::Transaction(
+            "Proxy transaction stream ended without finalization".to_string(),
+        ))
+    }
+}
+
+/// Fail if the proxied call was dispatched with an error
+///
+/// `Proxy.proxy` itself succeeds when the inner call fails; the inner result
+/// is only reported by the `Proxy.ProxyExecuted` event.
+pub(crate) fn check_proxy_executed(
+    events: &ExtrinsicEvents<PolkadotConfig>,
+    metadata: &Metadata,
+) -> Result<()> {
+    for event in events.iter() {
+        let event = event?;
+        if event.pallet_name() == "Proxy" && event.variant_name() == "ProxyExecuted" {
+            let result = proxy_executed_result(&event.field_values()?, |error| {
+                decode_dispatch_error(error, metadata)
+            });
+            if let Some(Err(error)) = result {
+                return Err(Error::Dispatch(error));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn proxy_executed_result(
+    fields: &Composite<u32>,
+    decode: impl Fn(&Value<u32>) -> DispatchError,
+) -> Option<std::result::Result<(), DispatchError>> {
+    event_field(fields, "result", 0).and_then(|result| dispatch_result(result, decode))
+}
+
+/// Check a `RuntimeCall` value, including the calls of Utility batches
+fn check_call<T>(proxy_type: &ProxyType, call: &Value<T>) -> Result<()> {
+    let Some((pallet, name, args)) = call_parts(call) else {
+        return Ok(());
+    };
+
+    if proxy_type.allows(pallet, name) == Some(false) {
+        return Err(Error::Transaction(format!(
+            "{} proxies cannot call {}.{}",
+            proxy_type, pallet, name
+        )));
+    }
+
+    if pallet == "Utility" && BATCH_CALLS.contains(&name) {
+        if let Some(calls) = args.values().next() {
+            if let ValueDef::Composite(calls) = &calls.value {
+                for call in calls.values() {
+                    check_call(proxy_type, call)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Pallet name, call name and arguments of a `RuntimeCall` value
+fn call_parts<T>(call: &Value<T>) -> Option<(&str, &str, &Composite<T>)> {
+    let ValueDef::Variant(pallet) = &call.value else {
+        return None;
+    };
+    let ValueDef::Variant(inner) = &pallet.values.values().next()?.value else {
+        return None;
+    };
+    Some((&pallet.name, &inner.name, &inner.values))
+}
+
+fn collect_definitions<T>(value: &Value<T>, out: &mut Vec<ProxyDefinition>) -> Option<()> {
+    if let Some(delegate) = value.at("delegate") {
+        let ValueDef::Variant(proxy_type) = &value.at("proxy_type")?.value else {
+            return None;
+        };
+        out.push(ProxyDefinition {
+            delegate: accounts_of(delegate)?.pop()?,
+            proxy_type: ProxyType::from_name(&proxy_type.name),
+            delay: value.at("delay")?.as_u128()? as u32,
+        });
+        return Some(());
+    }
+
+    // Unwrap the `BoundedVec` around the definitions
+    if let ValueDef::Composite(items) = &value.value {
+        for item in items.values() {
+            collect_definitions(item, out)?;
+        }
+    }
+    Some(())
+}
+
+fn multi_address(account: &AccountId32) -> Value {
+    Value::unnamed_variant(
+        "Id",
+        [Value::from_bytes(<AccountId32 as AsRef<[u8]>>::as_ref(
+            account,
+        ))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(pallet: &str, call: &str, args: Vec<Value>) -> Value {
+        Value::unnamed_variant(pallet, [Value::unnamed_variant(call, args)])
+    }
+
+    #[test]
+    fn test_proxy_type_names() {
+        for proxy_type in [
+            ProxyType::Any,
+            ProxyType::NonTransfer,
+            ProxyType::Governance,
+            ProxyType::Staking,
+            ProxyType::CancelProxy,
+            ProxyType::Auction,
+            ProxyType::NominationPools,
+            ProxyType::Other("IdentityJudgement".to_string()),
+        ] {
+            assert_eq!(ProxyType::from_name(proxy_type.name()), proxy_type);
+        }
+        assert_eq!(ProxyType::Staking.to_string(), "Staking");
+    }
+
+    #[test]
+    fn test_proxy_type_filters() {
+        assert_eq!(
+            ProxyType::Any.allows("Balances", "transfer_keep_alive"),
+            Some(true)
+        );
+        assert_eq!(
+            ProxyType::NonTransfer.allows("Balances", "transfer_keep_alive"),
+            Some(false)
+        );
+        assert_eq!(ProxyType::NonTransfer.allows("Staking", "bond"), Some(true));
+        assert_eq!(
+            ProxyType::NonTransfer.allows("XcmPallet", "limited_reserve_transfer_assets"),
+            Some(false)
+        );
+        assert_eq!(
+            ProxyType::NonTransfer.allows("PolkadotXcm", "transfer_assets"),
+            Some(false)
+        );
+        assert_eq!(ProxyType::NonTransfer.allows("Vesting", "vest"), Some(true));
+        assert_eq!(
+            ProxyType::NonTransfer.allows("Vesting", "vested_transfer"),
+            Some(false)
+        );
+        assert_eq!(ProxyType::Staking.allows("Staking", "nominate"), Some(true));
+        assert_eq!(
+            ProxyType::Staking.allows("Referenda", "submit"),
+            Some(false)
+        );
+        assert_eq!(
+            ProxyType::CancelProxy.allows("Proxy", "reject_announcement"),
+            Some(true)
+        );
+        assert_eq!(ProxyType::CancelProxy.allows("Proxy", "proxy"), Some(false));
+        assert_eq!(
+            ProxyType::Other("Custom".to_string()).allows("Balances", "transfer_all"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_call_inspects_batches() {
+        let nominate = call("Staking", "nominate", vec![]);
+        let transfer = call("Balances", "transfer_keep_alive", vec![]);
+        let batch = |calls: Vec<Value>| {
+            call(
+                "Utility",
+                "batch_all",
+                vec![Value::unnamed_composite(calls)],
+            )
+        };
+
+        assert!(check_call(&ProxyType::Staking, &nominate).is_ok());
+        assert!(check_call(&ProxyType::Staking, &batch(vec![nominate.clone()])).is_ok());
+
+        let error = check_call(
+            &ProxyType::Staking,
+            &batch(vec![nominate, transfer.clone()]),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("Staking proxies cannot call Balances.transfer_keep_alive"));
+
+        assert!(check_call(&ProxyType::Other("Custom".to_string()), &transfer).is_ok());
+    }
+
+    #[test]
+    fn test_decode_proxy_list() {
+        let delegate = AccountId32::new([7; 32]);
+        let definition = Value::named_composite([
+            ("delegate", Value::from_bytes([7u8; 32])),
+            ("proxy_type", Value::unnamed_variant("Staking", [])),
+            ("delay", Value::u128(0)),
+        ]);
+        let value = Value::unnamed_composite([
+            Value::unnamed_composite([Value::unnamed_composite([definition])]),
+            Value::u128(1_000),
+        ]);
+
+        let list = ProxyList::decode(&value).unwrap();
+        assert_eq!(list.deposit, 1_000);
+        assert_eq!(
+            list.proxies,
+            vec![ProxyDefinition {
+                delegate,
+                proxy_type: ProxyType::Staking,
+                delay: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_proxy_executed_result() {
+        let decode = |_: &Value<u32>| DispatchError::Other("BadOrigin".to_string());
+        let executed = |result: Value| {
+            Composite::Named(vec![("result".to_string(), result.map_context(|_| 0))])
+        };
+
+        assert_eq!(
+            proxy_executed_result(&executed(Value::unnamed_variant("Ok", [])), decode),
+            Some(Ok(()))
+        );
+        assert_eq!(
+            proxy_executed_result(
+                &executed(Value::unnamed_variant(
+                    "Err",
+                    [Value::unnamed_variant("BadOrigin", [])]
+                )),
+                decode
+            ),
+            Some(Err(DispatchError::Other("BadOrigin".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        let real = AccountId32::new([1; 32]);
+        let config = ProxyConfig::new(&real.to_ss58check())
+            .unwrap()
+            .with_proxy_type(ProxyType::NonTransfer);
+        assert_eq!(config.real, real);
+        assert_eq!(config.proxy_type, Some(ProxyType::NonTransfer));
+
+        assert!(ProxyConfig::new("not an address").is_err());
+    }
+}
@@ -7,7 +7,9 @@
 //! - Metadata inspection
 
 use crate::{Error, Metrics, Result};
-use subxt::dynamic::At as _;
+use sp_core::crypto::AccountId32;
+use subxt::dynamic::{At as _, Value};
+use subxt::ext::scale_value::{Primitive, ValueDef};
 use subxt::{OnlineClient, PolkadotConfig};
 use tracing::debug;
 
@@ -30,7 +32,7 @@ impl StorageClient {
         self.metrics.record_storage_query();
 
         // Parse SS58 address to get AccountId32
-        use sp_core::crypto::{AccountId32, Ss58Codec};
+        use sp_core::crypto::Ss58Codec;
         let account_id = AccountId32::from_ss58check(address)
             .map_err(|e| Error::Storage(format!("Invalid SS58 address: {}", e)))?;
 
@@ -480,10 +482,66 @@ fn decode_u128_from_bytes(bytes: &[u8]) -> Option<u128> {
     }
 }
 
+/// Accounts in a value holding one or more 32-byte account IDs
+pub(crate) fn accounts_of<T>(value: &Value<T>) -> Option<Vec<AccountId32>> {
+    let mut bytes = Vec::new();
+    collect_bytes(value, &mut bytes)?;
+    if bytes.len() % 32 != 0 {
+        return None;
+    }
+
+    Some(
+        bytes
+            .chunks_exact(32)
+            .map(|chunk| AccountId32::new(chunk.try_into().expect("32-byte chunk")))
+            .collect(),
+    )
+}
+
+fn collect_bytes<T>(value: &Value<T>, out: &mut Vec<u8>) -> Option<()> {
+    match &value.value {
+        ValueDef::Composite(fields) => {
+            for field in fields.values() {
+                collect_bytes(field, out)?;
+            }
+        }
+        ValueDef::Primitive(Primitive::U128(n)) => out.push(u8::try_from(*n).ok()?),
+        _ => return None,
+    }
+    Some(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_accounts_of() {
+        let account = |byte: u8| Value::from_bytes([byte; 32]);
+
+        // A single account and a list of them, as in `Multisig.Multisigs`
+        assert_eq!(
+            accounts_of(&account(1)),
+            Some(vec![AccountId32::new([1; 32])])
+        );
+        assert_eq!(
+            accounts_of(&Value::unnamed_composite([account(1), account(2)])),
+            Some(vec![AccountId32::new([1; 32]), AccountId32::new([2; 32])])
+        );
+        assert_eq!(
+            accounts_of(&Value::unnamed_composite(Vec::<Value>::new())),
+            Some(vec![])
+        );
+
+        // Not whole accounts, or not bytes at all
+        assert_eq!(accounts_of(&Value::from_bytes([1u8; 31])), None);
+        assert_eq!(accounts_of(&Value::string("alice")), None);
+        assert_eq!(
+            accounts_of(&Value::unnamed_composite([Value::u128(256)])),
+            None
+        );
+    }
+
     #[test]
     fn test_account_info() {
         let info = AccountInfo {
@@ -502,8 +560,6 @@ mod tests {
 
     #[test]
     fn test_storage_query_builder() {
-        use subxt::dynamic::Value;
-
         let query = StorageQuery::new("System", "Account").key(Value::from_bytes([0u8; 32]));
 
         assert_eq!(query.pallet, "System");
@@ -6,12 +6,20 @@
 //! - Transaction signing
 //! - Retry logic with exponential backoff
 //! - Transaction confirmation tracking
+//! - Utility batches with per-call outcomes
+//! - Dispatching as another account through a proxy
 
+use crate::dispatch_error::{decode_dispatch_error, transaction_error};
+use crate::proxy::{check_proxy_executed, ProxyConfig};
 use crate::{Error, Metrics, Result, Sr25519Signer, Wallet};
 use apex_sdk_core::{FeeEstimator, SdkError};
+use apex_sdk_types::DispatchError;
 use async_trait::async_trait;
 use std::time::Duration;
-use subxt::{OnlineClient, PolkadotConfig};
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::Composite;
+use subxt::tx::{DynamicPayload, Payload, TxProgress};
+use subxt::{Metadata, OnlineClient, PolkadotConfig};
 use tokio::time::sleep;
 use tracing::{debug, info, warn};
 
@@ -31,6 +39,9 @@ pub enum BatchMode {
 }
 
 /// Represents a single call in a batch transaction
+///
+/// Raw calls from [`BatchCall::new`] depend on the runtime's pallet and call
+/// indices; [`BatchCall::from_metadata`] resolves them by name instead.
 #[derive(Debug, Clone)]
 pub struct BatchCall {
     /// Pallet index in the runtime
@@ -50,6 +61,112 @@ impl BatchCall {
             args_encoded,
         }
     }
+
+    /// Create a batch call from its pallet and call names
+    ///
+    /// Indices are resolved and `args` are encoded using `metadata`, so the
+    /// call stays valid across runtime upgrades that reorder pallets.
+    pub fn from_metadata(
+        metadata: &Metadata,
+        pallet: &str,
+        call: &str,
+        args: Vec<Value>,
+    ) -> Result<Self> {
+        let call_data = subxt::dynamic::tx(pallet, call, args)
+            .encode_call_data(metadata)
+            .map_err(|e| Error::Encoding(format!("Failed to encode {}.{}: {}", pallet, call, e)))?;
+
+        match call_data.as_slice() {
+            [pallet_index, call_index, args @ ..] => {
+                Ok(Self::new(*pallet_index, *call_index, args.to_vec()))
+            }
+            _ => Err(Error::Encoding(format!(
+                "Encoded call {}.{} is too short",
+                pallet, call
+            ))),
+        }
+    }
+
+    /// The full call data: pallet index, call index and encoded arguments
+    pub fn call_data(&self) -> Vec<u8> {
+        let mut call_data = Vec::with_capacity(self.args_encoded.len() + 2);
+        call_data.push(self.pallet_index);
+        call_data.push(self.call_index);
+        call_data.extend_from_slice(&self.args_encoded);
+        call_data
+    }
+
+    /// Decode the call into a dynamic `RuntimeCall` value
+    fn to_value(&self, metadata: &Metadata) -> Result<Value> {
+        decode_call(metadata, &self.call_data()).map_err(|e| {
+            Error::Encoding(format!(
+                "Invalid call {}.{}: {}",
+                self.pallet_index, self.call_index, e
+            ))
+        })
+    }
+}
+
+/// Decode SCALE-encoded call data into a dynamic `RuntimeCall` value
+pub(crate) fn decode_call(metadata: &Metadata, call_data: &[u8]) -> Result<Value> {
+    use subxt::ext::scale_value::scale::decode_as_type;
+
+    let mut bytes = call_data;
+    let value = decode_as_type(
+        &mut bytes,
+        metadata.outer_enums().call_enum_ty(),
+        metadata.types(),
+    )
+    .map_err(|e| Error::Encoding(format!("Failed to decode call: {}", e)))?;
+
+    if !bytes.is_empty() {
+        return Err(Error::Encoding(format!(
+            "Call data has {} trailing bytes",
+            bytes.len()
+        )));
+    }
+
+    Ok(value.remove_context())
+}
+
+/// Outcome of a single call in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchCallOutcome {
+    /// The call was dispatched successfully
+    Completed,
+    /// The call failed with a dispatch error
+    Failed(DispatchError),
+    /// The call did not run because an earlier call interrupted the batch
+    NotExecuted,
+}
+
+/// Result of an executed batch
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    /// Hash of the batch extrinsic
+    pub tx_hash: String,
+    /// Outcome of each call, in submission order
+    pub outcomes: Vec<BatchCallOutcome>,
+}
+
+impl BatchResult {
+    /// Whether every call completed
+    pub fn is_success(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|outcome| *outcome == BatchCallOutcome::Completed)
+    }
+
+    /// Indices and errors of the calls that failed
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &DispatchError)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, outcome)| match outcome {
+                BatchCallOutcome::Failed(error) => Some((i, error)),
+                _ => None,
+            })
+    }
 }
 
 /// Fee estimation configuration
@@ -181,45 +298,101 @@ impl TransactionExecutor {
             amount
         );
 
+        let transfer_call = Self::transfer_call(to, amount)?;
+        self.submit_extrinsic_with_retry(&transfer_call, from, None)
+            .await
+    }
+
+    /// Submit a balance transfer from a proxy's real account
+    ///
+    /// `from` signs as the proxy; the transfer is dispatched as `proxy.real`.
+    pub async fn transfer_as(
+        &self,
+        from: &Wallet,
+        proxy: &ProxyConfig,
+        to: &str,
+        amount: u128,
+    ) -> Result<String> {
+        info!(
+            "Submitting transfer from {} via proxy {} to {} of {} units",
+            proxy.real,
+            from.address(),
+            to,
+            amount
+        );
+
+        let transfer_call = Self::transfer_call(to, amount)?;
+        self.submit_extrinsic_with_retry(&transfer_call, from, Some(proxy))
+            .await
+    }
+
+    fn transfer_call(to: &str, amount: u128) -> Result<DynamicPayload> {
         use sp_core::crypto::Ss58Codec;
         let dest = sp_core::sr25519::Public::from_ss58check(to)
             .map_err(|e| Error::Transaction(format!("Invalid destination address: {}", e)))?;
 
-        use subxt::dynamic::Value;
-
         let dest_value = Value::unnamed_variant("Id", vec![Value::from_bytes(dest.0)]);
 
-        let transfer_call = subxt::dynamic::tx(
+        Ok(subxt::dynamic::tx(
             "Balances",
             "transfer_keep_alive",
             vec![dest_value, Value::u128(amount)],
-        );
+        ))
+    }
 
-        self.submit_extrinsic_with_retry(&transfer_call, from).await
+    /// Create a batch call by name, resolved against the live runtime metadata
+    pub fn batch_call(&self, pallet: &str, call: &str, args: Vec<Value>) -> Result<BatchCall> {
+        BatchCall::from_metadata(&self.client.metadata(), pallet, call, args)
     }
 
     /// Submit an extrinsic with retry logic
+    ///
+    /// Only the submission is retried. Once the node has accepted the
+    /// extrinsic, failures are returned as they are: resubmitting could
+    /// execute the call twice.
     async fn submit_extrinsic_with_retry<Call>(
         &self,
         call: &Call,
         signer: &Wallet,
+        proxy: Option<&ProxyConfig>,
     ) -> Result<String>
     where
         Call: subxt::tx::Payload,
     {
+        debug!("Submitting extrinsic");
+
+        let pair = signer
+            .sr25519_pair()
+            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+
+        let apex_signer = Sr25519Signer::new(pair.clone());
+
+        let metadata = self.client.metadata();
+        let proxied = proxy.map(|proxy| proxy.wrap(&metadata, call)).transpose()?;
+
         let mut attempts = 0;
         let mut delay = self.retry_config.initial_delay;
 
-        loop {
+        let progress = loop {
             attempts += 1;
             self.metrics.record_transaction_attempt();
 
-            match self.submit_extrinsic(call, signer).await {
-                Ok(hash) => {
-                    self.metrics.record_transaction_success();
-                    return Ok(hash);
+            let mut tx = self.client.tx();
+            let submitted = match &proxied {
+                Some(proxied) => {
+                    tx.sign_and_submit_then_watch_default(proxied, &apex_signer)
+                        .await
                 }
+                None => {
+                    tx.sign_and_submit_then_watch_default(call, &apex_signer)
+                        .await
+                }
+            };
+
+            match submitted {
+                Ok(progress) => break progress,
                 Err(e) => {
+                    let e = Error::Transaction(format!("Failed to submit transaction: {}", e));
                     if attempts >= self.retry_config.max_retries {
                         warn!("Transaction failed after {} attempts: {}", attempts, e);
                         self.metrics.record_transaction_failure();
@@ -238,29 +411,27 @@ impl TransactionExecutor {
                     );
                 }
             }
+        };
+
+        match Self::wait_for_finalized(progress, proxied.is_some(), &metadata).await {
+            Ok(hash) => {
+                self.metrics.record_transaction_success();
+                Ok(hash)
+            }
+            Err(e) => {
+                warn!("Transaction failed: {}", e);
+                self.metrics.record_transaction_failure();
+                Err(e)
+            }
         }
     }
 
-    /// Submit an extrinsic and wait for it to be included in a block
-    async fn submit_extrinsic<Call>(&self, call: &Call, signer: &Wallet) -> Result<String>
-    where
-        Call: subxt::tx::Payload,
-    {
-        debug!("Submitting extrinsic");
-
-        let pair = signer
-            .sr25519_pair()
-            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
-
-        let apex_signer = Sr25519Signer::new(pair.clone());
-
-        let mut progress = self
-            .client
-            .tx()
-            .sign_and_submit_then_watch_default(call, &apex_signer)
-            .await
-            .map_err(|e| Error::Transaction(format!("Failed to submit transaction: {}", e)))?;
-
+    /// Wait for a submitted extrinsic to succeed in a finalized block
+    async fn wait_for_finalized(
+        mut progress: TxProgress<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+        proxied: bool,
+        metadata: &Metadata,
+    ) -> Result<String> {
         while let Some(event) = progress.next().await {
             let event =
                 event.map_err(|e| Error::Transaction(format!("Transaction error: {}", e)))?;
@@ -273,10 +444,14 @@ impl TransactionExecutor {
                 let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
                 info!("Transaction finalized: {}", tx_hash);
 
-                finalized
+                let events = finalized
                     .wait_for_success()
                     .await
-                    .map_err(|e| Error::Transaction(format!("Transaction failed: {}", e)))?;
+                    .map_err(|e| transaction_error("Transaction failed", e))?;
+
+                if proxied {
+                    check_proxy_executed(&events, metadata)?;
+                }
 
                 return Ok(tx_hash);
             }
@@ -439,13 +614,43 @@ impl TransactionExecutor {
     /// * `wallet` - The wallet to sign the batch transaction
     /// * `batch_mode` - The batch execution mode (see BatchMode)
     ///
-    /// Returns the transaction hash of the batch extrinsic
+    /// Raw and name-based calls can be mixed; all are checked against the
+    /// runtime metadata before submission. Returns the batch transaction hash
+    /// and the outcome of each call, parsed from the `Utility` events. With
+    /// [`BatchMode::AllOrNothing`] a failing call fails the whole extrinsic
+    /// and is returned as an error instead.
     pub async fn execute_batch(
         &self,
         calls: Vec<BatchCall>,
         wallet: &Wallet,
         batch_mode: BatchMode,
-    ) -> Result<String> {
+    ) -> Result<BatchResult> {
+        self.submit_batch(calls, wallet, None, batch_mode).await
+    }
+
+    /// Execute a batch from a proxy's real account
+    ///
+    /// Same as [`execute_batch`](Self::execute_batch), but `wallet` signs as
+    /// the proxy and the batch is dispatched as `proxy.real`. With a proxy
+    /// type set, every call in the batch is checked against its filter.
+    pub async fn execute_batch_as(
+        &self,
+        calls: Vec<BatchCall>,
+        wallet: &Wallet,
+        proxy: &ProxyConfig,
+        batch_mode: BatchMode,
+    ) -> Result<BatchResult> {
+        self.submit_batch(calls, wallet, Some(proxy), batch_mode)
+            .await
+    }
+
+    async fn submit_batch(
+        &self,
+        calls: Vec<BatchCall>,
+        wallet: &Wallet,
+        proxy: Option<&ProxyConfig>,
+        batch_mode: BatchMode,
+    ) -> Result<BatchResult> {
         debug!(
             "Executing batch of {} calls with mode {:?}",
             calls.len(),
@@ -457,20 +662,14 @@ impl TransactionExecutor {
             return Err(Error::Transaction("Cannot execute empty batch".to_string()));
         }
 
-        // generate typed metadata using `subxt codegen` for better type safety
-        let call_values: Vec<subxt::dynamic::Value> = calls
-            .into_iter()
-            .map(|call| {
-                let mut call_bytes = Vec::new();
-                call_bytes.push(call.pallet_index);
-                call_bytes.push(call.call_index);
-                call_bytes.extend_from_slice(&call.args_encoded);
-
-                subxt::dynamic::Value::from_bytes(&call_bytes)
-            })
-            .collect();
+        let metadata = self.client.metadata();
+        let call_count = calls.len();
+        let call_values = calls
+            .iter()
+            .map(|call| call.to_value(&metadata))
+            .collect::<Result<Vec<_>>>()?;
 
-        let calls_value = subxt::dynamic::Value::unnamed_composite(call_values);
+        let calls_value = Value::unnamed_composite(call_values);
 
         // Determine which batch call to use
         let batch_call_name = match batch_mode {
@@ -483,6 +682,10 @@ impl TransactionExecutor {
 
         // Create the batch transaction
         let tx = subxt::dynamic::tx("Utility", batch_call_name, vec![calls_value]);
+        let tx = match proxy {
+            Some(proxy) => proxy.wrap(&metadata, &tx)?,
+            None => tx,
+        };
 
         let pair = wallet
             .sr25519_pair()
@@ -510,13 +713,34 @@ impl TransactionExecutor {
                 let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
                 info!("Batch transaction finalized: {}", tx_hash);
 
-                finalized
+                let events = finalized
                     .wait_for_success()
                     .await
-                    .map_err(|e| Error::Transaction(format!("Batch transaction failed: {}", e)))?;
+                    .map_err(|e| transaction_error("Batch transaction failed", e))?;
+
+                if proxy.is_some() {
+                    check_proxy_executed(&events, &metadata)?;
+                }
+
+                let mut utility_events = Vec::new();
+                for event in events.iter() {
+                    let event = event?;
+                    if event.pallet_name() == "Utility" {
+                        utility_events
+                            .push((event.variant_name().to_string(), event.field_values()?));
+                    }
+                }
+
+                let outcomes = collect_batch_outcomes(
+                    utility_events
+                        .iter()
+                        .map(|(variant, fields)| (variant.as_str(), fields)),
+                    call_count,
+                    |error| decode_dispatch_error(error, &metadata),
+                );
 
                 self.metrics.record_transaction_success();
-                return Ok(tx_hash);
+                return Ok(BatchResult { tx_hash, outcomes });
             }
         }
 
@@ -533,10 +757,10 @@ impl TransactionExecutor {
         transfers: Vec<(String, u128)>, // (recipient, amount) pairs
         wallet: &Wallet,
         batch_mode: BatchMode,
-    ) -> Result<String> {
+    ) -> Result<BatchResult> {
         use sp_core::crypto::{AccountId32, Ss58Codec};
 
-        // Convert transfers to BatchCalls
+        let metadata = self.client.metadata();
         let mut calls = Vec::new();
 
         for (recipient, amount) in transfers {
@@ -545,19 +769,69 @@ impl TransactionExecutor {
             })?;
 
             let to_bytes: &[u8] = to_account.as_ref();
+            let dest = Value::unnamed_variant("Id", vec![Value::from_bytes(to_bytes)]);
+
+            calls.push(BatchCall::from_metadata(
+                &metadata,
+                "Balances",
+                "transfer_keep_alive",
+                vec![dest, Value::u128(amount)],
+            )?);
+        }
 
-            // Encode the transfer call arguments
-            use parity_scale_codec::Encode;
-            let args = (to_bytes, amount).encode();
+        self.execute_batch(calls, wallet, batch_mode).await
+    }
+}
 
-            calls.push(BatchCall {
-                pallet_index: 5, // Balances pallet (typical index, may vary by chain)
-                call_index: 3,   // transfer_keep_alive (typical index, may vary by chain)
-                args_encoded: args,
-            });
+/// Build per-call outcomes from a batch's `Utility` events, in emission order
+///
+/// `ItemCompleted` and `ItemFailed` are emitted once per executed call;
+/// `BatchInterrupted` stops an optimistic batch at the failing call's index.
+/// Nested batches are not distinguished from top-level calls.
+fn collect_batch_outcomes<'a>(
+    events: impl IntoIterator<Item = (&'a str, &'a Composite<u32>)>,
+    call_count: usize,
+    decode: impl Fn(&Value<u32>) -> DispatchError,
+) -> Vec<BatchCallOutcome> {
+    let decode_error = |fields: &Composite<u32>, position: usize| {
+        event_field(fields, "error", position)
+            .map(&decode)
+            .unwrap_or_else(|| DispatchError::Other("Unknown".to_string()))
+    };
+
+    let mut outcomes = Vec::with_capacity(call_count);
+    for (variant, fields) in events {
+        match variant {
+            "ItemCompleted" => outcomes.push(BatchCallOutcome::Completed),
+            "ItemFailed" => outcomes.push(BatchCallOutcome::Failed(decode_error(fields, 0))),
+            "BatchInterrupted" => {
+                let index = event_field(fields, "index", 0)
+                    .and_then(|index| index.as_u128())
+                    .map_or(outcomes.len(), |index| index as usize);
+                outcomes.truncate(index);
+                outcomes.resize(index, BatchCallOutcome::Completed);
+                outcomes.push(BatchCallOutcome::Failed(decode_error(fields, 1)));
+                break;
+            }
+            _ => {}
         }
+    }
 
-        self.execute_batch(calls, wallet, batch_mode).await
+    outcomes.resize(call_count, BatchCallOutcome::NotExecuted);
+    outcomes
+}
+
+pub(crate) fn event_field<'a>(
+    fields: &'a Composite<u32>,
+    name: &str,
+    position: usize,
+) -> Option<&'a Value<u32>> {
+    match fields {
+        Composite::Named(fields) => fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value),
+        Composite::Unnamed(fields) => fields.get(position),
     }
 }
 
@@ -596,4 +870,95 @@ mod tests {
         assert_eq!(config.max_retries, 5);
         assert_eq!(config.initial_delay, Duration::from_secs(1));
     }
+
+    fn failed(name: &str) -> Value<u32> {
+        Value::unnamed_variant(name, []).map_context(|_| 0)
+    }
+
+    fn decode(error: &Value<u32>) -> DispatchError {
+        match &error.value {
+            subxt::ext::scale_value::ValueDef::Variant(variant) => {
+                DispatchError::Other(variant.name.clone())
+            }
+            _ => DispatchError::Other("Unknown".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_batch_call_data() {
+        let call = BatchCall::new(5, 3, vec![1, 2]);
+        assert_eq!(call.call_data(), vec![5, 3, 1, 2]);
+    }
+
+    #[test]
+    fn test_force_batch_outcomes() {
+        let completed = Composite::Named(vec![]);
+        let item_failed = Composite::Named(vec![("error".to_string(), failed("BadOrigin"))]);
+        let events = [
+            ("ItemCompleted", &completed),
+            ("ItemFailed", &item_failed),
+            ("ItemCompleted", &completed),
+            ("BatchCompletedWithErrors", &completed),
+        ];
+
+        let outcomes = collect_batch_outcomes(events, 3, decode);
+        assert_eq!(
+            outcomes,
+            vec![
+                BatchCallOutcome::Completed,
+                BatchCallOutcome::Failed(DispatchError::Other("BadOrigin".to_string())),
+                BatchCallOutcome::Completed,
+            ]
+        );
+
+        let result = BatchResult {
+            tx_hash: "0x00".to_string(),
+            outcomes,
+        };
+        assert!(!result.is_success());
+        assert_eq!(
+            result.failures().map(|(i, _)| i).collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_interrupted_batch_outcomes() {
+        let completed = Composite::Named(vec![]);
+        let interrupted = Composite::Named(vec![
+            ("index".to_string(), Value::u128(1).map_context(|_| 0)),
+            ("error".to_string(), failed("CallFiltered")),
+        ]);
+        let events = [
+            ("ItemCompleted", &completed),
+            ("BatchInterrupted", &interrupted),
+        ];
+
+        assert_eq!(
+            collect_batch_outcomes(events, 4, decode),
+            vec![
+                BatchCallOutcome::Completed,
+                BatchCallOutcome::Failed(DispatchError::Other("CallFiltered".to_string())),
+                BatchCallOutcome::NotExecuted,
+                BatchCallOutcome::NotExecuted,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_completed_batch_outcomes() {
+        let completed = Composite::Unnamed(vec![]);
+        let events = [
+            ("ItemCompleted", &completed),
+            ("ItemCompleted", &completed),
+            ("BatchCompleted", &completed),
+        ];
+
+        let result = BatchResult {
+            tx_hash: "0x00".to_string(),
+            outcomes: collect_batch_outcomes(events, 2, decode),
+        };
+        assert!(result.is_success());
+        assert_eq!(result.failures().count(), 0);
+    }
 }
@@ -0,0 +1,588 @@
+//! Extrinsic hash index for transaction lookups
+//!
+//! This module keeps a local index from extrinsic hash to inclusion block in an
+//! embedded [redb](https://docs.rs/redb) store, so transaction status lookups
+//! work at any depth without scanning blocks:
+//! - [`TxIndexer`] follows finalized blocks and indexes every extrinsic
+//! - Cold starts backfill a configurable number of finalized blocks
+//! - Gaps left by restarts or dropped subscriptions are filled via parent hashes
+//! - The store lives on disk, surviving restarts, or in memory
+
+use crate::dispatch_error::extrinsic_failed_error;
+use crate::Error;
+use apex_sdk_types::{TransactionStatus, TxError};
+use redb::{Database, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use subxt::{Metadata, OnlineClient, PolkadotConfig};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Default number of finalized blocks indexed on a cold start (about a day of 6 second blocks)
+pub const DEFAULT_BACKFILL_BLOCKS: u32 = 14_400;
+
+/// Number of blocks written per store transaction while catching up
+const FLUSH_INTERVAL: usize = 256;
+
+/// Delay before the follower resubscribes after an error
+const RESTART_DELAY: Duration = Duration::from_secs(5);
+
+const EXTRINSICS: TableDefinition<&[u8; 32], &[u8]> = TableDefinition::new("extrinsics");
+const META: TableDefinition<&str, u32> = TableDefinition::new("meta");
+const FLOOR_KEY: &str = "floor";
+const TIP_KEY: &str = "tip";
+
+type SubxtBlock = subxt::blocks::Block<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+type SubxtExtrinsic = subxt::blocks::ExtrinsicDetails<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+/// Configuration for the transaction index
+#[derive(Debug, Clone)]
+pub struct TxIndexConfig {
+    /// Database file, or `None` for an in-memory index
+    pub path: Option<PathBuf>,
+    /// Number of finalized blocks indexed on a cold start
+    pub backfill_blocks: u32,
+}
+
+impl TxIndexConfig {
+    /// Index into a database file, creating it if needed
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            backfill_blocks: DEFAULT_BACKFILL_BLOCKS,
+        }
+    }
+
+    /// Index in memory; the index is rebuilt on every start
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            backfill_blocks: DEFAULT_BACKFILL_BLOCKS,
+        }
+    }
+
+    /// Set the number of finalized blocks indexed on a cold start
+    ///
+    /// Gaps longer than this after a restart are also skipped, leaving older
+    /// entries in place.
+    pub fn with_backfill_blocks(mut self, blocks: u32) -> Self {
+        self.backfill_blocks = blocks.max(1);
+        self
+    }
+}
+
+/// Where an extrinsic was included and how it executed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedExtrinsic {
+    /// Inclusion block number
+    pub block_number: u32,
+    /// Inclusion block hash
+    pub block_hash: [u8; 32],
+    /// Index of the extrinsic in the block
+    pub index: u32,
+    /// Whether `System.ExtrinsicSuccess` was emitted
+    pub success: bool,
+    /// Decoded error if `System.ExtrinsicFailed` was emitted
+    pub error: Option<TxError>,
+}
+
+impl IndexedExtrinsic {
+    /// Index entry for an extrinsic, reading its outcome from the block events
+    pub(crate) async fn from_extrinsic(
+        ext: &SubxtExtrinsic,
+        block_number: u32,
+        block_hash: [u8; 32],
+        metadata: &Metadata,
+    ) -> Result<Self, Error> {
+        let events = ext
+            .events()
+            .await
+            .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
+
+        let mut success = false;
+        let mut error = None;
+        for event in events.iter() {
+            let event =
+                event.map_err(|e| Error::Transaction(format!("Failed to decode event: {}", e)))?;
+
+            if event.pallet_name() == "System" {
+                if event.variant_name() == "ExtrinsicSuccess" {
+                    success = true;
+                } else if event.variant_name() == "ExtrinsicFailed" {
+                    let dispatch = event
+                        .field_values()
+                        .ok()
+                        .and_then(|fields| extrinsic_failed_error(&fields, metadata));
+                    error = Some(match dispatch {
+                        Some(dispatch) => TxError::from(dispatch),
+                        None => TxError::new(format!("Extrinsic {} failed", ext.index())),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            block_number,
+            block_hash,
+            index: ext.index(),
+            success,
+            error,
+        })
+    }
+
+    /// Hex-encoded inclusion block hash
+    pub fn block_hash_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.block_hash))
+    }
+
+    /// Transaction status given the latest block number
+    ///
+    /// Successful extrinsics are reported as confirmed, or finalized when
+    /// `finalized` is set.
+    pub fn to_status(
+        &self,
+        tx_hash: &str,
+        latest_block: u32,
+        finalized: bool,
+    ) -> TransactionStatus {
+        let confirmations = latest_block.saturating_sub(self.block_number);
+
+        match (&self.error, self.success) {
+            (_, true) if finalized => TransactionStatus::finalized(
+                tx_hash.to_string(),
+                self.block_number as u64,
+                self.block_hash_hex(),
+                None,
+                None,
+                Some(confirmations),
+            ),
+            (_, true) => TransactionStatus::confirmed(
+                tx_hash.to_string(),
+                self.block_number as u64,
+                self.block_hash_hex(),
+                None,
+                None,
+                Some(confirmations),
+            ),
+            (Some(error), false) => TransactionStatus::failed(tx_hash.to_string(), error.clone()),
+            // Included but no outcome event was found
+            (None, false) => TransactionStatus::unknown(tx_hash.to_string()),
+        }
+    }
+}
+
+/// Embedded store mapping extrinsic hashes to [`IndexedExtrinsic`] entries
+#[derive(Clone)]
+pub struct TxIndexStore {
+    db: Arc<Database>,
+}
+
+impl TxIndexStore {
+    /// Open or create an index database file
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let db = Database::create(path.as_ref()).map_err(|e| {
+            Error::Storage(format!(
+                "Failed to open transaction index {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+        Self::init(db)
+    }
+
+    /// Create an in-memory index
+    pub fn in_memory() -> Result<Self, Error> {
+        let db = Database::builder()
+            .create_with_backend(redb::backends::InMemoryBackend::new())
+            .map_err(|e| Error::Storage(format!("Failed to create transaction index: {}", e)))?;
+        Self::init(db)
+    }
+
+    /// Open the store described by `config`
+    pub fn from_config(config: &TxIndexConfig) -> Result<Self, Error> {
+        match &config.path {
+            Some(path) => Self::open(path),
+            None => Self::in_memory(),
+        }
+    }
+
+    /// Create the tables so reads never see a missing table
+    fn init(db: Database) -> Result<Self, Error> {
+        let tx = db.begin_write().map_err(store_error)?;
+        tx.open_table(EXTRINSICS).map_err(store_error)?;
+        tx.open_table(META).map_err(store_error)?;
+        tx.commit().map_err(store_error)?;
+
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    /// Look up an extrinsic by hash
+    pub fn get(&self, hash: &[u8; 32]) -> Result<Option<IndexedExtrinsic>, Error> {
+        let tx = self.db.begin_read().map_err(store_error)?;
+        let table = tx.open_table(EXTRINSICS).map_err(store_error)?;
+
+        table
+            .get(hash)
+            .map_err(store_error)?
+            .map(|entry| {
+                serde_json::from_slice::<IndexedExtrinsic>(entry.value())
+                    .map_err(|e| Error::Storage(format!("Corrupt transaction index entry: {}", e)))
+            })
+            .transpose()
+    }
+
+    /// Contiguous range of fully indexed blocks, as `(first, last)`
+    pub fn indexed_range(&self) -> Result<Option<(u32, u32)>, Error> {
+        let tx = self.db.begin_read().map_err(store_error)?;
+        let table = tx.open_table(META).map_err(store_error)?;
+
+        let floor = table.get(FLOOR_KEY).map_err(store_error)?;
+        let tip = table.get(TIP_KEY).map_err(store_error)?;
+        Ok(floor
+            .zip(tip)
+            .map(|(floor, tip)| (floor.value(), tip.value())))
+    }
+
+    /// Write entries, and optionally the new indexed range, in one transaction
+    pub fn insert(
+        &self,
+        entries: &[([u8; 32], IndexedExtrinsic)],
+        range: Option<(u32, u32)>,
+    ) -> Result<(), Error> {
+        let tx = self.db.begin_write().map_err(store_error)?;
+        {
+            let mut table = tx.open_table(EXTRINSICS).map_err(store_error)?;
+            for (hash, entry) in entries {
+                let entry = serde_json::to_vec(entry).map_err(store_error)?;
+                table.insert(hash, entry.as_slice()).map_err(store_error)?;
+            }
+
+            if let Some((floor, tip)) = range {
+                let mut meta = tx.open_table(META).map_err(store_error)?;
+                meta.insert(FLOOR_KEY, floor).map_err(store_error)?;
+                meta.insert(TIP_KEY, tip).map_err(store_error)?;
+            }
+        }
+        tx.commit().map_err(store_error)
+    }
+}
+
+fn store_error(e: impl std::fmt::Display) -> Error {
+    Error::Storage(format!("Transaction index error: {}", e))
+}
+
+/// Plan the blocks to index for a new finalized head
+///
+/// Returns the lowest block to index and the range covered once `head` is
+/// indexed. Returns `None` if `head` is already indexed.
+fn plan_sync(
+    range: Option<(u32, u32)>,
+    head: u32,
+    backfill_blocks: u32,
+) -> Option<(u32, (u32, u32))> {
+    let cold_start_floor = head.saturating_sub(backfill_blocks - 1);
+
+    match range {
+        Some((_, tip)) if head <= tip => None,
+        // Extend the indexed range up to the head
+        Some((floor, tip)) if tip + 1 >= cold_start_floor => Some((tip + 1, (floor, head))),
+        // No index yet, or the gap is too long to fill
+        _ => Some((cold_start_floor, (cold_start_floor, head))),
+    }
+}
+
+/// Background follower that indexes finalized blocks
+pub struct TxIndexer {
+    client: OnlineClient<PolkadotConfig>,
+    store: TxIndexStore,
+    backfill_blocks: u32,
+}
+
+impl TxIndexer {
+    /// Create an indexer writing to `store`
+    pub fn new(client: OnlineClient<PolkadotConfig>, store: TxIndexStore) -> Self {
+        Self {
+            client,
+            store,
+            backfill_blocks: DEFAULT_BACKFILL_BLOCKS,
+        }
+    }
+
+    /// Set the number of finalized blocks indexed on a cold start
+    pub fn with_backfill_blocks(mut self, blocks: u32) -> Self {
+        self.backfill_blocks = blocks.max(1);
+        self
+    }
+
+    /// Index up to the latest finalized block, then follow new finalized blocks
+    ///
+    /// Only returns on error.
+    pub async fn run(&self) -> Result<(), Error> {
+        let latest = self
+            .client
+            .blocks()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get latest block: {}", e)))?;
+        self.sync_to(latest).await?;
+
+        let mut subscription = self
+            .client
+            .blocks()
+            .subscribe_finalized()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to subscribe to blocks: {}", e)))?;
+
+        while let Some(block) = subscription.next().await {
+            let block =
+                block.map_err(|e| Error::Connection(format!("Block stream error: {}", e)))?;
+            self.sync_to(block).await?;
+        }
+
+        Err(Error::Connection(
+            "Finalized block subscription ended".to_string(),
+        ))
+    }
+
+    /// Run in a background task, restarting after errors
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = self.run().await {
+                    warn!("Transaction indexer stopped: {}; restarting", e);
+                }
+                tokio::time::sleep(RESTART_DELAY).await;
+            }
+        })
+    }
+
+    /// Index a finalized head and its unindexed ancestors
+    ///
+    /// Blocks are indexed newest first, so recent transactions become
+    /// searchable before the backfill completes. The indexed range is only
+    /// extended once every block in it is written.
+    pub async fn sync_to(&self, head: SubxtBlock) -> Result<(), Error> {
+        let head_number = head.number();
+        let Some((lowest, range)) = plan_sync(
+            self.store.indexed_range()?,
+            head_number,
+            self.backfill_blocks,
+        ) else {
+            return Ok(());
+        };
+
+        if head_number > lowest {
+            info!("Indexing finalized blocks {} to {}", lowest, head_number);
+        }
+
+        let metadata = self.client.metadata();
+        let mut entries = Vec::new();
+        let mut pending_blocks = 0;
+        let mut block = head;
+        loop {
+            entries.extend(index_block(&block, &metadata).await?);
+            pending_blocks += 1;
+
+            if block.number() <= lowest {
+                break;
+            }
+            if pending_blocks >= FLUSH_INTERVAL {
+                self.store.insert(&entries, None)?;
+                entries.clear();
+                pending_blocks = 0;
+            }
+
+            block = self
+                .client
+                .blocks()
+                .at(block.header().parent_hash)
+                .await
+                .map_err(|e| Error::Connection(format!("Failed to traverse blocks: {}", e)))?;
+        }
+
+        self.store.insert(&entries, Some(range))?;
+        debug!("Indexed finalized blocks up to {}", head_number);
+        Ok(())
+    }
+}
+
+/// Index entries for every extrinsic in a block
+async fn index_block(
+    block: &SubxtBlock,
+    metadata: &Metadata,
+) -> Result<Vec<([u8; 32], IndexedExtrinsic)>, Error> {
+    let extrinsics = block
+        .extrinsics()
+        .await
+        .map_err(|e| Error::Transaction(format!("Failed to get extrinsics: {}", e)))?;
+    let block_hash: [u8; 32] = block.hash().0;
+
+    let mut entries = Vec::new();
+    for ext in extrinsics.iter() {
+        let hash = sp_core::blake2_256(ext.bytes());
+        let entry =
+            IndexedExtrinsic::from_extrinsic(&ext, block.number(), block_hash, metadata).await?;
+        entries.push((hash, entry));
+    }
+    Ok(entries)
+}
+
+/// A running transaction index: the store and its background follower
+///
+/// The follower stops when this is dropped.
+pub struct TxIndex {
+    store: TxIndexStore,
+    task: JoinHandle<()>,
+}
+
+impl TxIndex {
+    /// Open the store and start following finalized blocks
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn start(
+        client: OnlineClient<PolkadotConfig>,
+        config: &TxIndexConfig,
+    ) -> Result<Self, Error> {
+        let store = TxIndexStore::from_config(config)?;
+        let task = TxIndexer::new(client, store.clone())
+            .with_backfill_blocks(config.backfill_blocks)
+            .spawn();
+
+        Ok(Self { store, task })
+    }
+
+    /// The underlying store
+    pub fn store(&self) -> &TxIndexStore {
+        &self.store
+    }
+}
+
+impl Drop for TxIndex {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apex_sdk_types::{DispatchError, ModuleError};
+
+    fn entry(block_number: u32, success: bool) -> IndexedExtrinsic {
+        IndexedExtrinsic {
+            block_number,
+            block_hash: [block_number as u8; 32],
+            index: 1,
+            success,
+            error: (!success).then(|| {
+                TxError::from(DispatchError::Module(ModuleError {
+                    pallet: "Balances".to_string(),
+                    pallet_index: 5,
+                    error: "InsufficientBalance".to_string(),
+                    error_index: 2,
+                    docs: "Balance too low to send value.".to_string(),
+                }))
+            }),
+        }
+    }
+
+    #[test]
+    fn test_store_roundtrip() {
+        let store = TxIndexStore::in_memory().unwrap();
+        assert_eq!(store.get(&[1; 32]).unwrap(), None);
+        assert_eq!(store.indexed_range().unwrap(), None);
+
+        store
+            .insert(
+                &[([1; 32], entry(10, true)), ([2; 32], entry(11, false))],
+                None,
+            )
+            .unwrap();
+        assert_eq!(store.get(&[1; 32]).unwrap(), Some(entry(10, true)));
+        assert_eq!(store.get(&[2; 32]).unwrap(), Some(entry(11, false)));
+        assert_eq!(store.indexed_range().unwrap(), None);
+
+        store.insert(&[], Some((10, 11))).unwrap();
+        assert_eq!(store.indexed_range().unwrap(), Some((10, 11)));
+    }
+
+    #[test]
+    fn test_store_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tx-index.redb");
+
+        {
+            let store = TxIndexStore::open(&path).unwrap();
+            store
+                .insert(&[([7; 32], entry(500, true))], Some((1, 500)))
+                .unwrap();
+        }
+
+        let store = TxIndexStore::open(&path).unwrap();
+        assert_eq!(store.get(&[7; 32]).unwrap(), Some(entry(500, true)));
+        assert_eq!(store.indexed_range().unwrap(), Some((1, 500)));
+    }
+
+    #[test]
+    fn test_plan_sync() {
+        // Cold start backfills the configured number of blocks
+        assert_eq!(plan_sync(None, 1000, 100), Some((901, (901, 1000))));
+        assert_eq!(plan_sync(None, 50, 100), Some((0, (0, 50))));
+
+        // Already indexed
+        assert_eq!(plan_sync(Some((901, 1000)), 1000, 100), None);
+        assert_eq!(plan_sync(Some((901, 1000)), 990, 100), None);
+
+        // Following and short gaps extend the range
+        assert_eq!(
+            plan_sync(Some((901, 1000)), 1001, 100),
+            Some((1001, (901, 1001)))
+        );
+        assert_eq!(
+            plan_sync(Some((901, 1000)), 1100, 100),
+            Some((1001, (901, 1100)))
+        );
+
+        // Long gaps restart the range
+        assert_eq!(
+            plan_sync(Some((901, 1000)), 5000, 100),
+            Some((4901, (4901, 5000)))
+        );
+    }
+
+    #[test]
+    fn test_to_status() {
+        let success = entry(10, true);
+        let status = success.to_status("0xabc", 25, true);
+        assert!(status.is_finalized());
+        assert_eq!(status.block_number, Some(10));
+        assert_eq!(status.confirmations, Some(15));
+        assert_eq!(status.block_hash, Some(format!("0x{}", "0a".repeat(32))));
+
+        assert_eq!(
+            success.to_status("0xabc", 25, false).status,
+            apex_sdk_types::TxStatus::Confirmed
+        );
+
+        let failed = entry(10, false).to_status("0xabc", 25, true);
+        let error = failed.error.unwrap();
+        assert_eq!(
+            error.message,
+            "Balances::InsufficientBalance: Balance too low to send value."
+        );
+        assert_eq!(
+            error.dispatch.unwrap().to_string(),
+            "Balances::InsufficientBalance"
+        );
+
+        let mut unclear = entry(10, true);
+        unclear.success = false;
+        unclear.error = None;
+        assert_eq!(
+            unclear.to_status("0xabc", 25, true).status,
+            apex_sdk_types::TxStatus::Unknown
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! Two-dimensional runtime weight
+//!
+//! Shared by the pallets that take a `Weight` argument, such as the XCM
+//! `execute` limit and the multisig `max_weight`.
+
+use serde::{Deserialize, Serialize};
+use subxt::dynamic::Value;
+
+/// Two-dimensional weight (`ref_time` and `proof_size`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Weight {
+    /// Computation time in picoseconds
+    pub ref_time: u64,
+    /// Proof size in bytes
+    pub proof_size: u64,
+}
+
+impl Weight {
+    /// Create a weight from its parts
+    pub fn new(ref_time: u64, proof_size: u64) -> Self {
+        Self {
+            ref_time,
+            proof_size,
+        }
+    }
+
+    pub(crate) fn encode(&self) -> Value {
+        Value::named_composite([
+            ("ref_time", Value::u128(self.ref_time as u128)),
+            ("proof_size", Value::u128(self.proof_size as u128)),
+        ])
+    }
+}
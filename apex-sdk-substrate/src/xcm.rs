@@ -8,42 +8,108 @@
 //! - Reserve transfers (transfer assets via reserve chain)
 //! - Teleport transfers (burn and mint across chains)
 //! - Multi-location address handling
-//! - XCM v3/v4 support
+//! - XCM v3/v4/v5 encoding, negotiated per destination
 //! - Parachain-to-parachain transfers
 //! - Parachain-to-relay transfers
+//! - Delivery tracking on the destination chain
+//! - Fee estimation by dry-running transfers on the origin and destination
 //!
 //! ## Example
 //!
 //! ```rust,ignore
-//! use apex_sdk_substrate::xcm::{XcmExecutor, XcmTransferType, MultiLocation};
+//! use apex_sdk_substrate::xcm::{MultiLocation, XcmAsset, XcmExecutor, XcmTransferType};
 //!
 //! let executor = XcmExecutor::new(client);
 //!
-//! // Transfer from parachain to relay chain
-//! let tx_hash = executor
+//! // Transfer from parachain to relay chain, encoded in the highest XCM
+//! // version both chains support
+//! let submission = executor
 //!     .transfer(
-//!         beneficiary,
-//!         amount,
+//!         &wallet,
 //!         XcmTransferType::ReserveTransfer,
 //!         MultiLocation::parent(),
+//!         MultiLocation::account(beneficiary),
+//!         vec![XcmAsset::native(amount)],
 //!     )
 //!     .await?;
 //! ```
 
-use crate::{Error, Result, Sr25519Signer, Wallet};
+use crate::dispatch_error::transaction_error;
+use crate::proxy::{check_proxy_executed, ProxyConfig};
+use crate::xcm_program::{TransferAssetsUsingType, XcmProgram, XcmWeight};
+use crate::{Error, Result, Sr25519Signer, SubstrateAdapter, Wallet};
+use std::time::Duration;
+use subxt::ext::scale_value::{Composite, Primitive, Value, ValueDef};
+use subxt::tx::DynamicPayload;
 use subxt::{OnlineClient, PolkadotConfig};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// Default time to wait for an XCM message to be processed on the destination
+pub const DEFAULT_XCM_DELIVERY_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Proof size paired with the ref time of [`WeightLimit::Limited`]
+const WEIGHT_LIMIT_PROOF_SIZE: u64 = 256 * 1024;
+
+/// Westend genesis hash, which replaces `NetworkId::Westend` from XCM v5
+const WESTEND_GENESIS: [u8; 32] = [
+    0xe1, 0x43, 0xf2, 0x38, 0x03, 0xac, 0x50, 0xe8, 0xf6, 0xf8, 0xe6, 0x26, 0x95, 0xd1, 0xce, 0x9e,
+    0x4e, 0x1d, 0x68, 0xaa, 0x36, 0xc1, 0xcd, 0x2c, 0xfd, 0x15, 0x34, 0x02, 0x13, 0xf3, 0x42, 0x3e,
+];
+
+/// Rococo genesis hash, which replaces `NetworkId::Rococo` from XCM v5
+const ROCOCO_GENESIS: [u8; 32] = [
+    0x64, 0x08, 0xde, 0x77, 0x37, 0xc5, 0x9c, 0x23, 0x88, 0x90, 0x53, 0x3a, 0xf2, 0x58, 0x96, 0xa2,
+    0xc2, 0x06, 0x08, 0xd8, 0xb3, 0x80, 0xbb, 0x01, 0x02, 0x9a, 0xcb, 0x39, 0x27, 0x81, 0x06, 0x3e,
+];
 
 /// XCM version to use for message construction
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum XcmVersion {
     /// XCM version 2
     V2,
     /// XCM version 3 (recommended)
     #[default]
     V3,
-    /// XCM version 4 (latest)
+    /// XCM version 4
     V4,
+    /// XCM version 5 (latest)
+    V5,
+}
+
+impl XcmVersion {
+    /// Latest version this SDK can encode
+    pub const LATEST: Self = Self::V5;
+
+    /// Numeric version, as stored by `XcmPallet.SupportedVersion`
+    pub fn number(self) -> u32 {
+        match self {
+            Self::V2 => 2,
+            Self::V3 => 3,
+            Self::V4 => 4,
+            Self::V5 => 5,
+        }
+    }
+
+    /// Get a version from its number
+    pub fn from_number(version: u32) -> Option<Self> {
+        match version {
+            2 => Some(Self::V2),
+            3 => Some(Self::V3),
+            4 => Some(Self::V4),
+            5 => Some(Self::V5),
+            _ => None,
+        }
+    }
+
+    /// Name of the versioned enum variant, e.g. `V4` in `VersionedLocation::V4`
+    pub fn variant_name(self) -> &'static str {
+        match self {
+            Self::V2 => "V2",
+            Self::V3 => "V3",
+            Self::V4 => "V4",
+            Self::V5 => "V5",
+        }
+    }
 }
 
 /// Type of XCM transfer to perform
@@ -65,6 +131,8 @@ pub enum XcmTransferType {
 }
 
 /// Multi-location representation for XCM addressing
+///
+/// Encoded as `MultiLocation` for XCM v3 and `Location` for v4 and later.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MultiLocation {
     /// Number of parent levels to traverse
@@ -101,6 +169,22 @@ impl MultiLocation {
         }
     }
 
+    /// Create a MultiLocation for a 20-byte (EVM) account on the current chain
+    pub fn account_key20(key: [u8; 20]) -> Self {
+        Self {
+            parents: 0,
+            interior: vec![Junction::AccountId20 { network: None, key }],
+        }
+    }
+
+    /// Create a MultiLocation for another consensus system, as seen from a parachain
+    pub fn global_consensus(network: NetworkId) -> Self {
+        Self {
+            parents: 2,
+            interior: vec![Junction::GlobalConsensus(network)],
+        }
+    }
+
     /// Create a MultiLocation for an account on a specific parachain
     pub fn parachain_account(para_id: u32, account_id: [u8; 32]) -> Self {
         Self {
@@ -142,6 +226,9 @@ impl MultiLocation {
     }
 }
 
+/// XCM v4+ name for [`MultiLocation`]
+pub type Location = MultiLocation;
+
 /// Interior junction types for multi-location
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Junction {
@@ -154,7 +241,7 @@ pub enum Junction {
         id: [u8; 32],
     },
 
-    /// AccountId20 junction (for EVM accounts)
+    /// 20-byte account junction for EVM accounts (`AccountKey20` in XCM)
     AccountId20 {
         network: Option<NetworkId>,
         key: [u8; 20],
@@ -168,6 +255,9 @@ pub enum Junction {
 
     /// Pallet instance junction
     PalletInstance(u8),
+
+    /// Another consensus system, such as a different relay chain or Ethereum
+    GlobalConsensus(NetworkId),
 }
 
 /// Network identifier for cross-consensus messaging
@@ -183,6 +273,14 @@ pub enum NetworkId {
     Rococo,
     /// Generic network by ID
     ByGenesis([u8; 32]),
+    /// Ethereum network by EIP-155 chain ID
+    Ethereum { chain_id: u64 },
+    /// Bitcoin mainnet
+    BitcoinCore,
+    /// Bitcoin Cash mainnet
+    BitcoinCash,
+    /// Polkadot Bulletin chain
+    PolkadotBulletin,
 }
 
 /// XCM asset representation
@@ -254,7 +352,8 @@ impl Default for WeightLimit {
 /// Configuration for XCM transfers
 #[derive(Debug, Clone, Default)]
 pub struct XcmConfig {
-    /// XCM version to use
+    /// XCM version to use when version negotiation is disabled or the
+    /// destination's version is unknown
     pub version: XcmVersion,
     /// Weight limit for execution
     pub weight_limit: WeightLimit,
@@ -262,10 +361,114 @@ pub struct XcmConfig {
     pub fee_asset: Option<XcmAsset>,
 }
 
+/// A submitted XCM transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XcmSubmission {
+    /// Hash of the transfer extrinsic
+    pub tx_hash: String,
+    /// Hash of the finalized block containing the extrinsic
+    pub block_hash: String,
+    /// Message ID (topic) from the `XcmPallet.Sent`/`PolkadotXcm.Sent` event
+    pub message_id: Option<[u8; 32]>,
+}
+
+/// Processing outcome of an XCM message on the destination chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XcmMessageOutcome {
+    /// Destination block in which the message was processed
+    pub block_number: u64,
+    /// Hash of that block
+    pub block_hash: String,
+    /// Whether the message executed successfully
+    pub success: bool,
+    /// XCM or message-queue error reported for a failed message
+    pub error: Option<String>,
+}
+
+/// Fee estimate for an XCM transfer, produced by dry-running it
+#[derive(Debug, Clone)]
+pub struct XcmFeeEstimate {
+    /// Fee for the transfer extrinsic on the origin chain
+    pub local_fee: u128,
+    /// Fees charged by the origin chain to deliver the forwarded messages
+    pub delivery_fees: Vec<XcmAsset>,
+    /// Weight (`ref_time`) of the forwarded message on the destination
+    pub destination_weight: Option<u64>,
+    /// Fee for executing the forwarded message on the destination, in the
+    /// asset paid by its `BuyExecution` instruction
+    pub destination_fee: Option<u128>,
+    /// Error from dry-running the forwarded message on the destination
+    pub destination_error: Option<String>,
+    /// Messages the transfer would send, by destination
+    pub forwarded_xcms: Vec<ForwardedXcm>,
+}
+
+/// XCM messages forwarded to one destination by a dry-run
+#[derive(Debug, Clone)]
+pub struct ForwardedXcm {
+    /// Versioned destination location, relative to the origin chain
+    pub destination: Value,
+    /// Versioned XCM messages
+    pub messages: Vec<Value>,
+}
+
+impl ForwardedXcm {
+    /// Decode the destination into a [`MultiLocation`]
+    ///
+    /// Returns `None` for junctions that `MultiLocation` cannot represent.
+    pub fn location(&self) -> Option<MultiLocation> {
+        decode_location(&self.destination)
+    }
+}
+
+/// Final state of an XCM transfer as seen from the destination chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XcmDeliveryStatus {
+    /// The message executed successfully on the destination
+    Delivered {
+        /// Destination block in which the message was processed
+        block_number: u64,
+        /// Hash of that block
+        block_hash: String,
+    },
+    /// The message reached the destination but failed to execute
+    Failed {
+        /// Destination block in which the message was processed
+        block_number: u64,
+        /// Hash of that block
+        block_hash: String,
+        /// Error reported by the destination
+        error: String,
+    },
+    /// The message was not processed before the timeout
+    TimedOut,
+}
+
+impl From<XcmMessageOutcome> for XcmDeliveryStatus {
+    fn from(outcome: XcmMessageOutcome) -> Self {
+        if outcome.success {
+            Self::Delivered {
+                block_number: outcome.block_number,
+                block_hash: outcome.block_hash,
+            }
+        } else {
+            Self::Failed {
+                block_number: outcome.block_number,
+                block_hash: outcome.block_hash,
+                error: outcome
+                    .error
+                    .unwrap_or_else(|| "XCM execution failed".to_string()),
+            }
+        }
+    }
+}
+
 /// XCM executor for sending cross-chain messages
 pub struct XcmExecutor {
     client: OnlineClient<PolkadotConfig>,
     config: XcmConfig,
+    destination: Option<OnlineClient<PolkadotConfig>>,
+    negotiate_version: bool,
 }
 
 impl XcmExecutor {
@@ -274,17 +477,45 @@ impl XcmExecutor {
         Self {
             client,
             config: XcmConfig::default(),
+            destination: None,
+            negotiate_version: true,
         }
     }
 
     /// Create a new XCM executor with custom configuration
     pub fn with_config(client: OnlineClient<PolkadotConfig>, config: XcmConfig) -> Self {
-        Self { client, config }
+        Self {
+            client,
+            config,
+            destination: None,
+            negotiate_version: true,
+        }
+    }
+
+    /// Set a client for the destination chain
+    ///
+    /// Used by [`estimate`](Self::estimate) to price and dry-run the
+    /// forwarded message on the destination.
+    pub fn with_destination(mut self, client: OnlineClient<PolkadotConfig>) -> Self {
+        self.destination = Some(client);
+        self
     }
 
     /// Set the XCM version
+    ///
+    /// Pins the version for every message, disabling version negotiation.
     pub fn with_version(mut self, version: XcmVersion) -> Self {
         self.config.version = version;
+        self.negotiate_version = false;
+        self
+    }
+
+    /// Enable or disable version negotiation (enabled by default)
+    ///
+    /// When enabled, messages are encoded in the version chosen by
+    /// [`negotiate_version`](Self::negotiate_version).
+    pub fn with_version_negotiation(mut self, enabled: bool) -> Self {
+        self.negotiate_version = enabled;
         self
     }
 
@@ -315,25 +546,15 @@ impl XcmExecutor {
     ) -> Result<String> {
         info!("Executing reserve transfer to {:?} for beneficiary", dest);
 
-        // Build the reserve transfer call using dynamic API
-        let dest_value = self.encode_multilocation(&dest)?;
-        let beneficiary_value = self.encode_multilocation(&MultiLocation::account(beneficiary))?;
-        let assets_value = self.encode_assets(&assets)?;
-        let fee_index = 0u32; // Use first asset for fees
-
-        let call = subxt::dynamic::tx(
-            "XcmPallet",
-            "limited_reserve_transfer_assets",
-            vec![
-                dest_value,
-                beneficiary_value,
-                assets_value,
-                subxt::dynamic::Value::u128(fee_index as u128),
-                self.encode_weight_limit()?,
-            ],
-        );
-
-        self.submit_xcm_call(&call, wallet).await
+        self.transfer(
+            wallet,
+            XcmTransferType::LimitedReserveTransfer,
+            dest,
+            MultiLocation::account(beneficiary),
+            assets,
+        )
+        .await
+        .map(|submission| submission.tx_hash)
     }
 
     /// Execute a teleport transfer to another chain
@@ -357,264 +578,1953 @@ impl XcmExecutor {
     ) -> Result<String> {
         info!("Executing teleport to {:?} for beneficiary", dest);
 
-        let dest_value = self.encode_multilocation(&dest)?;
-        let beneficiary_value = self.encode_multilocation(&MultiLocation::account(beneficiary))?;
-        let assets_value = self.encode_assets(&assets)?;
-        let fee_index = 0u32;
-
-        let call = subxt::dynamic::tx(
-            "XcmPallet",
-            "limited_teleport_assets",
-            vec![
-                dest_value,
-                beneficiary_value,
-                assets_value,
-                subxt::dynamic::Value::u128(fee_index as u128),
-                self.encode_weight_limit()?,
-            ],
-        );
-
-        self.submit_xcm_call(&call, wallet).await
+        self.transfer(
+            wallet,
+            XcmTransferType::LimitedTeleport,
+            dest,
+            MultiLocation::account(beneficiary),
+            assets,
+        )
+        .await
+        .map(|submission| submission.tx_hash)
     }
 
-    /// Transfer to relay chain (convenience method)
+    /// Execute a transfer and return its submission details
     ///
-    /// Automatically uses reserve transfer to parent chain
-    pub async fn transfer_to_relay(
+    /// Unlike [`reserve_transfer`](Self::reserve_transfer) and
+    /// [`teleport`](Self::teleport), this also returns the finalized block and
+    /// the message ID needed to follow the message to its destination. The
+    /// beneficiary is a location relative to the destination, such as
+    /// [`MultiLocation::account`] or [`MultiLocation::account_key20`].
+    pub async fn transfer(
         &self,
         wallet: &Wallet,
-        beneficiary: [u8; 32],
-        amount: u128,
-    ) -> Result<String> {
-        debug!("Transferring {} to relay chain", amount);
+        transfer_type: XcmTransferType,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        assets: Vec<XcmAsset>,
+    ) -> Result<XcmSubmission> {
+        self.submit_transfer(wallet, None, transfer_type, dest, beneficiary, assets)
+            .await
+    }
 
-        self.reserve_transfer(
+    /// Execute a transfer from a proxy's real account
+    ///
+    /// Same as [`transfer`](Self::transfer), but `wallet` signs as the proxy
+    /// and the assets are sent from `proxy.real`.
+    pub async fn transfer_as(
+        &self,
+        wallet: &Wallet,
+        proxy: &ProxyConfig,
+        transfer_type: XcmTransferType,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        assets: Vec<XcmAsset>,
+    ) -> Result<XcmSubmission> {
+        self.submit_transfer(
             wallet,
-            MultiLocation::parent(),
+            Some(proxy),
+            transfer_type,
+            dest,
             beneficiary,
-            vec![XcmAsset::native(amount)],
+            assets,
         )
         .await
     }
 
-    /// Transfer to another parachain
+    async fn submit_transfer(
+        &self,
+        wallet: &Wallet,
+        proxy: Option<&ProxyConfig>,
+        transfer_type: XcmTransferType,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        assets: Vec<XcmAsset>,
+    ) -> Result<XcmSubmission> {
+        let version = self.message_version(&dest).await?;
+        let (call_name, fields) =
+            self.transfer_call(transfer_type, &dest, &beneficiary, &assets, version)?;
+        let call = subxt::dynamic::tx(self.pallet_name(), call_name, fields);
+
+        self.submit_xcm_call(&call, wallet, proxy).await
+    }
+
+    /// Execute an XCM program locally with `execute`
     ///
-    /// Automatically uses reserve transfer via relay chain
-    pub async fn transfer_to_parachain(
+    /// The program runs with the signer's origin, so it usually starts with
+    /// `WithdrawAsset`. It is encoded in the highest version this runtime
+    /// supports, or the configured version when negotiation is disabled.
+    pub async fn execute(
         &self,
         wallet: &Wallet,
-        para_id: u32,
-        beneficiary: [u8; 32],
-        amount: u128,
-    ) -> Result<String> {
-        debug!("Transferring {} to parachain {}", amount, para_id);
+        program: &XcmProgram,
+        max_weight: XcmWeight,
+    ) -> Result<XcmSubmission> {
+        let call = self.execute_call(program, max_weight)?;
+        self.submit_xcm_call(&call, wallet, None).await
+    }
 
-        self.reserve_transfer(
-            wallet,
-            MultiLocation::parachain(para_id),
-            beneficiary,
-            vec![XcmAsset::native(amount)],
-        )
-        .await
+    /// Execute an XCM program locally from a proxy's real account
+    ///
+    /// Same as [`execute`](Self::execute), but `wallet` signs as the proxy and
+    /// the program runs with `proxy.real` as origin.
+    pub async fn execute_as(
+        &self,
+        wallet: &Wallet,
+        proxy: &ProxyConfig,
+        program: &XcmProgram,
+        max_weight: XcmWeight,
+    ) -> Result<XcmSubmission> {
+        let call = self.execute_call(program, max_weight)?;
+        self.submit_xcm_call(&call, wallet, Some(proxy)).await
     }
 
-    // Helper methods for encoding XCM types
+    fn execute_call(&self, program: &XcmProgram, max_weight: XcmWeight) -> Result<DynamicPayload> {
+        let version = if self.negotiate_version {
+            self.runtime_version().unwrap_or(self.config.version)
+        } else {
+            self.config.version
+        };
+
+        Ok(subxt::dynamic::tx(
+            self.pallet_name(),
+            "execute",
+            vec![program.encode_versioned(version)?, max_weight.encode()],
+        ))
+    }
 
-    fn encode_multilocation(&self, location: &MultiLocation) -> Result<subxt::dynamic::Value> {
-        // Encode MultiLocation as composite value
-        // Structure: { parents: u8, interior: Junctions }
+    /// Send an XCM program to another chain with `send`
+    ///
+    /// The program runs on `dest` with this chain (or the signer's location
+    /// on it) as origin, e.g. for remote `Transact` calls. Sending from a
+    /// signed origin usually requires the runtime to allow it; governance
+    /// origins are typically used on production chains.
+    pub async fn send(
+        &self,
+        wallet: &Wallet,
+        dest: MultiLocation,
+        program: &XcmProgram,
+    ) -> Result<XcmSubmission> {
+        let call = self.send_call(&dest, program).await?;
+        self.submit_xcm_call(&call, wallet, None).await
+    }
 
-        let interior = self.encode_junctions(&location.interior)?;
+    /// Send an XCM program to another chain from a proxy's real account
+    ///
+    /// Same as [`send`](Self::send), but `wallet` signs as the proxy and the
+    /// message is sent with `proxy.real` as origin.
+    pub async fn send_as(
+        &self,
+        wallet: &Wallet,
+        proxy: &ProxyConfig,
+        dest: MultiLocation,
+        program: &XcmProgram,
+    ) -> Result<XcmSubmission> {
+        let call = self.send_call(&dest, program).await?;
+        self.submit_xcm_call(&call, wallet, Some(proxy)).await
+    }
 
-        Ok(subxt::dynamic::Value::named_composite([
-            (
-                "parents",
-                subxt::dynamic::Value::u128(location.parents as u128),
-            ),
-            ("interior", interior),
-        ]))
+    async fn send_call(
+        &self,
+        dest: &MultiLocation,
+        program: &XcmProgram,
+    ) -> Result<DynamicPayload> {
+        let version = self.message_version(dest).await?;
+
+        Ok(subxt::dynamic::tx(
+            self.pallet_name(),
+            "send",
+            vec![
+                encode_versioned_location(dest, version)?,
+                program.encode_versioned(version)?,
+            ],
+        ))
     }
 
-    fn encode_junctions(&self, junctions: &[Junction]) -> Result<subxt::dynamic::Value> {
-        if junctions.is_empty() {
-            // X0 (Here) variant
-            return Ok(subxt::dynamic::Value::unnamed_variant("Here", vec![]));
-        }
+    /// Transfer assets with `transfer_assets_using_type_and_then`
+    ///
+    /// Supports remote reserves and custom XCM on the destination, which the
+    /// reserve and teleport calls behind [`transfer`](Self::transfer) cannot
+    /// express.
+    pub async fn transfer_assets_using_type_and_then(
+        &self,
+        wallet: &Wallet,
+        transfer: &TransferAssetsUsingType,
+    ) -> Result<XcmSubmission> {
+        let call = self.transfer_assets_using_type_call(transfer).await?;
+        self.submit_xcm_call(&call, wallet, None).await
+    }
 
-        // Encode junctions as nested X1, X2, etc.
-        let encoded_junctions: Vec<subxt::dynamic::Value> = junctions
-            .iter()
-            .map(|j| self.encode_junction(j))
-            .collect::<Result<Vec<_>>>()?;
-
-        // Use appropriate variant based on number of junctions
-        let variant_name = match junctions.len() {
-            1 => "X1",
-            2 => "X2",
-            3 => "X3",
-            4 => "X4",
-            5 => "X5",
-            6 => "X6",
-            7 => "X7",
-            8 => "X8",
-            _ => return Err(Error::Transaction("Too many junctions (max 8)".to_string())),
-        };
+    /// Transfer assets with `transfer_assets_using_type_and_then` from a
+    /// proxy's real account
+    ///
+    /// Same as
+    /// [`transfer_assets_using_type_and_then`](Self::transfer_assets_using_type_and_then),
+    /// but `wallet` signs as the proxy and the assets are sent from
+    /// `proxy.real`.
+    pub async fn transfer_assets_using_type_and_then_as(
+        &self,
+        wallet: &Wallet,
+        proxy: &ProxyConfig,
+        transfer: &TransferAssetsUsingType,
+    ) -> Result<XcmSubmission> {
+        let call = self.transfer_assets_using_type_call(transfer).await?;
+        self.submit_xcm_call(&call, wallet, Some(proxy)).await
+    }
 
-        Ok(subxt::dynamic::Value::unnamed_variant(
-            variant_name,
-            encoded_junctions,
+    async fn transfer_assets_using_type_call(
+        &self,
+        transfer: &TransferAssetsUsingType,
+    ) -> Result<DynamicPayload> {
+        let version = self.message_version(&transfer.dest).await?;
+
+        Ok(subxt::dynamic::tx(
+            self.pallet_name(),
+            "transfer_assets_using_type_and_then",
+            transfer.call_fields(version, self.config.weight_limit)?,
         ))
     }
 
-    fn encode_junction(&self, junction: &Junction) -> Result<subxt::dynamic::Value> {
-        match junction {
-            Junction::Parachain(id) => Ok(subxt::dynamic::Value::unnamed_variant(
-                "Parachain",
-                vec![subxt::dynamic::Value::u128(*id as u128)],
-            )),
-            Junction::AccountId32 { network, id } => {
-                let network_value = if let Some(_net) = network {
-                    // Encode network if present
-                    subxt::dynamic::Value::unnamed_variant("Some", vec![])
-                } else {
-                    subxt::dynamic::Value::unnamed_variant("None", vec![])
-                };
+    /// Estimate the fees of a transfer without signing it
+    ///
+    /// Dry-runs the transfer on the origin with `DryRunApi_dry_run_call` to
+    /// collect the forwarded messages, prices the extrinsic and message
+    /// delivery, and, if a destination client is set, prices the forwarded
+    /// message with `XcmPaymentApi_query_xcm_weight` and
+    /// `XcmPaymentApi_query_weight_to_asset_fee` and dry-runs it there with
+    /// `DryRunApi_dry_run_xcm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - Account that would sign the transfer
+    /// * `transfer_type` - Reserve transfer or teleport
+    /// * `dest` - Destination multi-location
+    /// * `beneficiary` - Beneficiary location on the destination chain
+    /// * `assets` - Assets to transfer
+    pub async fn estimate(
+        &self,
+        sender: [u8; 32],
+        transfer_type: XcmTransferType,
+        dest: MultiLocation,
+        beneficiary: MultiLocation,
+        assets: Vec<XcmAsset>,
+    ) -> Result<XcmFeeEstimate> {
+        let version = self.message_version(&dest).await?;
+        let (call_name, fields) =
+            self.transfer_call(transfer_type, &dest, &beneficiary, &assets, version)?;
+        let pallet = self.pallet_name();
+        let call = Value::unnamed_variant(
+            pallet,
+            vec![Value::unnamed_variant(call_name, fields.clone())],
+        );
 
-                Ok(subxt::dynamic::Value::unnamed_variant(
-                    "AccountId32",
-                    vec![network_value, subxt::dynamic::Value::from_bytes(id)],
-                ))
+        let api = self
+            .client
+            .runtime_api()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get latest block: {}", e)))?;
+
+        // Dry-run the call as a signed extrinsic from the sender
+        let origin = Value::unnamed_variant(
+            "system",
+            vec![Value::unnamed_variant(
+                "Signed",
+                vec![Value::from_bytes(sender)],
+            )],
+        );
+        let mut args = vec![origin, call.clone()];
+        if dry_run_call_inputs(&self.client) > 2 {
+            args.push(Value::u128(version.number() as u128));
+        }
+        let effects = api_ok(
+            call_runtime_api(&api, "DryRunApi", "dry_run_call", args).await?,
+            "DryRunApi_dry_run_call",
+        )?;
+
+        if let Some(ValueDef::Variant(result)) =
+            named_field(&effects, "execution_result").map(|v| &v.value)
+        {
+            if result.name == "Err" {
+                let error = items(&result.values)
+                    .first()
+                    .and_then(|err| named_field(err, "error"))
+                    .map(describe_error)
+                    .unwrap_or_else(|| "unknown error".to_string());
+                return Err(Error::Transaction(format!(
+                    "XCM transfer dry run failed: {}",
+                    error
+                )));
             }
-            Junction::AccountId20 { network, key } => {
-                let network_value = if let Some(_net) = network {
-                    subxt::dynamic::Value::unnamed_variant("Some", vec![])
-                } else {
-                    subxt::dynamic::Value::unnamed_variant("None", vec![])
-                };
+        }
 
-                Ok(subxt::dynamic::Value::unnamed_variant(
-                    "AccountId20",
-                    vec![network_value, subxt::dynamic::Value::from_bytes(key)],
-                ))
+        let forwarded_xcms: Vec<ForwardedXcm> = named_field(&effects, "forwarded_xcms")
+            .map(forwarded_entries)
+            .unwrap_or_default();
+        debug!("Dry run forwarded {} XCM batches", forwarded_xcms.len());
+
+        // Fee of the extrinsic itself
+        let call_len = self
+            .client
+            .tx()
+            .call_data(&subxt::dynamic::tx(pallet, call_name, fields))
+            .map_err(|e| Error::Transaction(format!("Failed to encode XCM call: {}", e)))?
+            .len();
+        let info = call_runtime_api(
+            &api,
+            "TransactionPaymentCallApi",
+            "query_call_info",
+            vec![call, Value::u128(call_len as u128)],
+        )
+        .await?;
+        let local_fee = named_field(&info, "partial_fee")
+            .and_then(|fee| fee.as_u128())
+            .ok_or_else(|| Error::Transaction("Missing partial_fee in call info".to_string()))?;
+
+        // Fees for delivering each forwarded message
+        let mut delivery_fees = Vec::new();
+        for forwarded in &forwarded_xcms {
+            for message in &forwarded.messages {
+                let fees = api_ok(
+                    call_runtime_api(
+                        &api,
+                        "XcmPaymentApi",
+                        "query_delivery_fees",
+                        vec![forwarded.destination.clone(), message.clone()],
+                    )
+                    .await?,
+                    "XcmPaymentApi_query_delivery_fees",
+                )?;
+                delivery_fees.extend(decode_assets(&fees));
             }
-            Junction::GeneralIndex(index) => Ok(subxt::dynamic::Value::unnamed_variant(
-                "GeneralIndex",
-                vec![subxt::dynamic::Value::u128(*index)],
-            )),
-            Junction::GeneralKey { data } => Ok(subxt::dynamic::Value::unnamed_variant(
-                "GeneralKey",
-                vec![subxt::dynamic::Value::from_bytes(data)],
-            )),
-            Junction::PalletInstance(instance) => Ok(subxt::dynamic::Value::unnamed_variant(
-                "PalletInstance",
-                vec![subxt::dynamic::Value::u128(*instance as u128)],
-            )),
         }
-    }
 
-    fn encode_assets(&self, assets: &[XcmAsset]) -> Result<subxt::dynamic::Value> {
-        let encoded_assets: Vec<subxt::dynamic::Value> = assets
-            .iter()
-            .map(|asset| {
-                let id_value = match &asset.id {
-                    AssetId::Concrete(location) => {
-                        let loc = self.encode_multilocation(location)?;
-                        subxt::dynamic::Value::unnamed_variant("Concrete", vec![loc])
-                    }
-                    AssetId::Abstract(data) => subxt::dynamic::Value::unnamed_variant(
-                        "Abstract",
-                        vec![subxt::dynamic::Value::from_bytes(data)],
-                    ),
-                };
+        let mut estimate = XcmFeeEstimate {
+            local_fee,
+            delivery_fees,
+            destination_weight: None,
+            destination_fee: None,
+            destination_error: None,
+            forwarded_xcms,
+        };
 
-                let fun_value = match asset.fun {
-                    Fungibility::Fungible(amount) => subxt::dynamic::Value::unnamed_variant(
-                        "Fungible",
-                        vec![subxt::dynamic::Value::u128(amount)],
-                    ),
-                    Fungibility::NonFungible(instance) => subxt::dynamic::Value::unnamed_variant(
-                        "NonFungible",
-                        vec![subxt::dynamic::Value::u128(instance)],
-                    ),
-                };
+        let message = estimate
+            .forwarded_xcms
+            .iter()
+            .find(|forwarded| forwarded.location().as_ref() == Some(&dest))
+            .and_then(|forwarded| forwarded.messages.first())
+            .cloned();
 
-                Ok(subxt::dynamic::Value::named_composite([
-                    ("id", id_value),
-                    ("fun", fun_value),
-                ]))
-            })
-            .collect::<Result<Vec<_>>>()?;
+        if let (Some(destination), Some(message)) = (&self.destination, message) {
+            self.estimate_destination(destination, &dest, message, &mut estimate)
+                .await?;
+        }
 
-        // Wrap in VersionedAssets::V3
-        Ok(subxt::dynamic::Value::unnamed_variant(
-            "V3",
-            vec![subxt::dynamic::Value::unnamed_composite(encoded_assets)],
-        ))
+        Ok(estimate)
     }
 
-    fn encode_weight_limit(&self) -> Result<subxt::dynamic::Value> {
-        match self.config.weight_limit {
-            WeightLimit::Unlimited => {
-                Ok(subxt::dynamic::Value::unnamed_variant("Unlimited", vec![]))
-            }
-            WeightLimit::Limited(weight) => Ok(subxt::dynamic::Value::unnamed_variant(
-                "Limited",
-                vec![subxt::dynamic::Value::u128(weight as u128)],
-            )),
+    /// Price and dry-run a forwarded message on the destination chain
+    async fn estimate_destination(
+        &self,
+        destination: &OnlineClient<PolkadotConfig>,
+        dest: &MultiLocation,
+        message: Value,
+        estimate: &mut XcmFeeEstimate,
+    ) -> Result<()> {
+        let api = destination
+            .runtime_api()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Connection(format!("Failed to get latest block: {}", e)))?;
+
+        let weight = api_ok(
+            call_runtime_api(
+                &api,
+                "XcmPaymentApi",
+                "query_xcm_weight",
+                vec![message.clone()],
+            )
+            .await?,
+            "XcmPaymentApi_query_xcm_weight",
+        )?;
+        estimate.destination_weight = named_field(&weight, "ref_time")
+            .and_then(|ref_time| ref_time.as_u128())
+            .map(|ref_time| ref_time as u64);
+
+        if let Some(fee_asset) = fee_asset_id(&message) {
+            let fee = api_ok(
+                call_runtime_api(
+                    &api,
+                    "XcmPaymentApi",
+                    "query_weight_to_asset_fee",
+                    vec![weight.remove_context(), fee_asset],
+                )
+                .await?,
+                "XcmPaymentApi_query_weight_to_asset_fee",
+            )?;
+            estimate.destination_fee = fee.as_u128();
         }
-    }
 
-    async fn submit_xcm_call<Call>(&self, call: &Call, wallet: &Wallet) -> Result<String>
-    where
-        Call: subxt::tx::Payload,
-    {
-        debug!("Submitting XCM extrinsic");
+        let own_para_id = self.parachain_id().await?;
+        let Some(origin) = origin_relative_to(dest, own_para_id) else {
+            debug!(
+                "Cannot express the origin relative to {:?}; skipping dry run",
+                dest
+            );
+            return Ok(());
+        };
+        let Some(version) = message_version(&message) else {
+            return Ok(());
+        };
 
-        let pair = wallet
-            .sr25519_pair()
-            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+        let effects = api_ok(
+            call_runtime_api(
+                &api,
+                "DryRunApi",
+                "dry_run_xcm",
+                vec![encode_versioned_location(&origin, version)?, message],
+            )
+            .await?,
+            "DryRunApi_dry_run_xcm",
+        )?;
+        if let Some(ValueDef::Variant(outcome)) =
+            named_field(&effects, "execution_result").map(|v| &v.value)
+        {
+            if outcome.name != "Complete" {
+                let error = match &outcome.values {
+                    Composite::Named(values) => values
+                        .iter()
+                        .find(|(name, _)| name == "error")
+                        .map(|(_, value)| value),
+                    Composite::Unnamed(values) => values.last(),
+                };
+                estimate.destination_error = Some(
+                    error
+                        .map(|error| {
+                            // Newer outcomes wrap the error with its instruction index
+                            named_field(error, "error").unwrap_or(error)
+                        })
+                        .map(describe_error)
+                        .unwrap_or_else(|| outcome.name.clone()),
+                );
+            }
+        }
 
-        let signer = Sr25519Signer::new(pair.clone());
+        Ok(())
+    }
+
+    /// Negotiate the XCM version for messages to `dest`
+    ///
+    /// Returns the highest version supported by this chain, the destination
+    /// (as recorded in the XCM pallet's `SupportedVersion` storage) and this
+    /// SDK. Falls back to `SafeXcmVersion`, then the configured version, when
+    /// the destination's version is unknown.
+    pub async fn negotiate_version(&self, dest: &MultiLocation) -> Result<XcmVersion> {
+        let pallet = self.pallet_name();
+
+        // The runtime's own version is the highest one it can encode a key for
+        let Some((local, query)) = [XcmVersion::V5, XcmVersion::V4, XcmVersion::V3]
+            .into_iter()
+            .find_map(|version| {
+                self.supported_version_query(dest, version)
+                    .map(|query| (version, query))
+            })
+        else {
+            debug!("Runtime does not support XCM v3 or later");
+            return Ok(self.config.version);
+        };
 
-        let mut progress = self
+        let at = self
             .client
-            .tx()
-            .sign_and_submit_then_watch_default(call, &signer)
+            .storage()
+            .at_latest()
             .await
-            .map_err(|e| Error::Transaction(format!("Failed to submit XCM transaction: {}", e)))?;
+            .map_err(|e| Error::Storage(format!("Failed to get latest block: {}", e)))?;
 
-        // Wait for finalization
-        while let Some(event) = progress.next().await {
-            let event =
-                event.map_err(|e| Error::Transaction(format!("XCM transaction error: {}", e)))?;
+        let mut remote = at
+            .fetch(&query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to fetch supported version: {}", e)))?
+            .and_then(|value| value.to_value().ok())
+            .and_then(|value| value.as_u128());
+
+        if remote.is_none() {
+            let query = subxt::dynamic::storage(pallet, "SafeXcmVersion", ());
+            remote = at
+                .fetch(&query)
+                .await
+                .map_err(|e| Error::Storage(format!("Failed to fetch safe version: {}", e)))?
+                .and_then(|value| value.to_value().ok())
+                .and_then(|value| value.as_u128());
+        }
 
-            if event.as_in_block().is_some() {
-                info!("XCM transaction included in block");
-            }
+        let version = match remote {
+            Some(remote) => XcmVersion::from_number(remote.min(local.number() as u128) as u32)
+                .unwrap_or(self.config.version),
+            None => self.config.version.min(local),
+        };
+        debug!(
+            "Negotiated XCM {:?} for {:?} (local {:?}, remote {:?})",
+            version, dest, local, remote
+        );
 
-            if let Some(finalized) = event.as_finalized() {
-                let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
-                info!("XCM transaction finalized: {}", tx_hash);
+        Ok(version)
+    }
 
-                finalized
-                    .wait_for_success()
-                    .await
-                    .map_err(|e| Error::Transaction(format!("XCM transaction failed: {}", e)))?;
+    /// Highest XCM version this chain's runtime supports
+    ///
+    /// Probed by encoding a `SupportedVersion` storage key in each version.
+    pub fn runtime_version(&self) -> Option<XcmVersion> {
+        [XcmVersion::V5, XcmVersion::V4, XcmVersion::V3]
+            .into_iter()
+            .find(|&version| {
+                self.supported_version_query(&MultiLocation::parent(), version)
+                    .is_some()
+            })
+    }
 
-                return Ok(tx_hash);
-            }
+    /// Build a `SupportedVersion` query, or `None` if the runtime cannot
+    /// encode `dest` in `version`
+    fn supported_version_query(
+        &self,
+        dest: &MultiLocation,
+        version: XcmVersion,
+    ) -> Option<subxt::storage::DynamicAddress<Vec<Value>>> {
+        let query = subxt::dynamic::storage(
+            self.pallet_name(),
+            "SupportedVersion",
+            vec![
+                Value::u128(version.number() as u128),
+                encode_versioned_location(dest, version).ok()?,
+            ],
+        );
+        self.client.storage().address_bytes(&query).ok()?;
+        Some(query)
+    }
+
+    /// Version to encode messages to `dest` in
+    async fn message_version(&self, dest: &MultiLocation) -> Result<XcmVersion> {
+        if self.negotiate_version {
+            self.negotiate_version(dest).await
+        } else {
+            Ok(self.config.version)
         }
+    }
 
-        Err(Error::Transaction(
-            "XCM transaction stream ended without finalization".to_string(),
+    /// Build the pallet-xcm call for a transfer
+    fn transfer_call(
+        &self,
+        transfer_type: XcmTransferType,
+        dest: &MultiLocation,
+        beneficiary: &MultiLocation,
+        assets: &[XcmAsset],
+        version: XcmVersion,
+    ) -> Result<(&'static str, Vec<Value>)> {
+        let call_name = match transfer_type {
+            XcmTransferType::ReserveTransfer | XcmTransferType::LimitedReserveTransfer => {
+                "limited_reserve_transfer_assets"
+            }
+            XcmTransferType::Teleport | XcmTransferType::LimitedTeleport => {
+                "limited_teleport_assets"
+            }
+        };
+
+        // Pay fees with the configured fee asset, or the first asset
+        let fee_index = self
+            .config
+            .fee_asset
+            .as_ref()
+            .and_then(|fee| assets.iter().position(|asset| asset.id == fee.id))
+            .unwrap_or(0);
+
+        Ok((
+            call_name,
+            vec![
+                encode_versioned_location(dest, version)?,
+                encode_versioned_location(beneficiary, version)?,
+                encode_versioned_assets(assets, version)?,
+                Value::u128(fee_index as u128),
+                encode_weight_limit(self.config.weight_limit),
+            ],
         ))
     }
-}
 
-#[cfg(test)]
+    /// Name of the XCM pallet on this chain
+    ///
+    /// Relay chains call it `XcmPallet`, parachains `PolkadotXcm`.
+    fn pallet_name(&self) -> &'static str {
+        if self
+            .client
+            .metadata()
+            .pallet_by_name("PolkadotXcm")
+            .is_some()
+        {
+            "PolkadotXcm"
+        } else {
+            "XcmPallet"
+        }
+    }
+
+    /// Get this chain's parachain ID, or `None` on a relay chain
+    async fn parachain_id(&self) -> Result<Option<u32>> {
+        if self
+            .client
+            .metadata()
+            .pallet_by_name("ParachainInfo")
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let query = subxt::dynamic::storage("ParachainInfo", "ParachainId", ());
+        let value = self
+            .client
+            .storage()
+            .at_latest()
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to get latest block: {}", e)))?
+            .fetch(&query)
+            .await
+            .map_err(|e| Error::Storage(format!("Failed to fetch parachain ID: {}", e)))?;
+
+        Ok(value
+            .and_then(|value| value.to_value().ok())
+            .and_then(|value| {
+                value
+                    .as_u128()
+                    .or_else(|| items_of(&value).first()?.as_u128())
+            })
+            .map(|id| id as u32))
+    }
+
+    /// Transfer to relay chain (convenience method)
+    ///
+    /// Automatically uses reserve transfer to parent chain
+    pub async fn transfer_to_relay(
+        &self,
+        wallet: &Wallet,
+        beneficiary: [u8; 32],
+        amount: u128,
+    ) -> Result<String> {
+        debug!("Transferring {} to relay chain", amount);
+
+        self.reserve_transfer(
+            wallet,
+            MultiLocation::parent(),
+            beneficiary,
+            vec![XcmAsset::native(amount)],
+        )
+        .await
+    }
+
+    /// Transfer to another parachain
+    ///
+    /// Automatically uses reserve transfer via relay chain
+    pub async fn transfer_to_parachain(
+        &self,
+        wallet: &Wallet,
+        para_id: u32,
+        beneficiary: [u8; 32],
+        amount: u128,
+    ) -> Result<String> {
+        debug!("Transferring {} to parachain {}", amount, para_id);
+
+        self.reserve_transfer(
+            wallet,
+            MultiLocation::parachain(para_id),
+            beneficiary,
+            vec![XcmAsset::native(amount)],
+        )
+        .await
+    }
+
+    async fn submit_xcm_call<Call>(
+        &self,
+        call: &Call,
+        wallet: &Wallet,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<XcmSubmission>
+    where
+        Call: subxt::tx::Payload,
+    {
+        debug!("Submitting XCM extrinsic");
+
+        let pair = wallet
+            .sr25519_pair()
+            .ok_or_else(|| Error::Transaction("Wallet does not have SR25519 key".to_string()))?;
+
+        let signer = Sr25519Signer::new(pair.clone());
+
+        let metadata = self.client.metadata();
+        let proxied = proxy.map(|proxy| proxy.wrap(&metadata, call)).transpose()?;
+
+        let mut tx = self.client.tx();
+        let mut progress = match &proxied {
+            Some(proxied) => {
+                tx.sign_and_submit_then_watch_default(proxied, &signer)
+                    .await
+            }
+            None => tx.sign_and_submit_then_watch_default(call, &signer).await,
+        }
+        .map_err(|e| Error::Transaction(format!("Failed to submit XCM transaction: {}", e)))?;
+
+        // Wait for finalization
+        while let Some(event) = progress.next().await {
+            let event =
+                event.map_err(|e| Error::Transaction(format!("XCM transaction error: {}", e)))?;
+
+            if event.as_in_block().is_some() {
+                info!("XCM transaction included in block");
+            }
+
+            if let Some(finalized) = event.as_finalized() {
+                let tx_hash = format!("0x{}", hex::encode(finalized.extrinsic_hash()));
+                info!("XCM transaction finalized: {}", tx_hash);
+
+                let events = finalized
+                    .wait_for_success()
+                    .await
+                    .map_err(|e| transaction_error("XCM transaction failed", e))?;
+
+                if proxied.is_some() {
+                    check_proxy_executed(&events, &metadata)?;
+                }
+
+                let message_id = events
+                    .iter()
+                    .filter_map(|event| event.ok())
+                    .find(|event| is_sent_event(event.pallet_name(), event.variant_name()))
+                    .and_then(|event| message_id_from_sent_fields(event.field_bytes()));
+
+                if message_id.is_none() {
+                    debug!("No XCM Sent event found for {}", tx_hash);
+                }
+
+                return Ok(XcmSubmission {
+                    tx_hash,
+                    block_hash: format!("0x{}", hex::encode(finalized.block_hash())),
+                    message_id,
+                });
+            }
+        }
+
+        Err(Error::Transaction(
+            "XCM transaction stream ended without finalization".to_string(),
+        ))
+    }
+}
+
+/// Watches a destination chain for the processing of XCM messages
+pub struct XcmMessageWatcher {
+    client: OnlineClient<PolkadotConfig>,
+}
+
+impl XcmMessageWatcher {
+    /// Create a new watcher for the destination chain
+    pub fn new(client: OnlineClient<PolkadotConfig>) -> Self {
+        Self { client }
+    }
+
+    /// Wait for a message to be processed on the destination chain
+    ///
+    /// Follows finalized blocks and matches `MessageQueue.Processed` and
+    /// `XcmpQueue.Success`/`XcmpQueue.Fail` events against the message ID.
+    /// Starts at `from_block` (or the next finalized block), walking back
+    /// from the finalized head to cover blocks finalized before the call.
+    pub async fn wait_for_processed(
+        &self,
+        message_id: &[u8; 32],
+        from_block: Option<u64>,
+    ) -> Result<XcmMessageOutcome> {
+        let mut subscription = self
+            .client
+            .blocks()
+            .subscribe_finalized()
+            .await
+            .map_err(|e| {
+                Error::Connection(format!("Failed to subscribe to finalized blocks: {}", e))
+            })?;
+
+        let mut next_block = from_block;
+
+        while let Some(block) = subscription.next().await {
+            let block = block
+                .map_err(|e| Error::Connection(format!("Finalized block stream error: {}", e)))?;
+            let number = block.number() as u64;
+            let start = *next_block.get_or_insert(number);
+
+            if number < start {
+                continue;
+            }
+
+            // Collect any blocks between the last inspected one and this head
+            let mut pending = vec![block];
+            while let Some(oldest) = pending.last() {
+                if oldest.number() as u64 <= start {
+                    break;
+                }
+                let parent = self
+                    .client
+                    .blocks()
+                    .at(oldest.header().parent_hash)
+                    .await
+                    .map_err(|e| Error::Connection(format!("Failed to traverse blocks: {}", e)))?;
+                pending.push(parent);
+            }
+
+            for block in pending.iter().rev() {
+                if let Some(outcome) = find_processed(block, message_id).await? {
+                    info!(
+                        "XCM message 0x{} processed in block {}",
+                        hex::encode(message_id),
+                        outcome.block_number
+                    );
+                    return Ok(outcome);
+                }
+            }
+
+            next_block = Some(number + 1);
+        }
+
+        Err(Error::Connection(
+            "Finalized block subscription ended".to_string(),
+        ))
+    }
+}
+
+/// Follows an XCM transfer from its source extrinsic to the destination chain
+///
+/// The message ID is read from the `XcmPallet.Sent`/`PolkadotXcm.Sent` event
+/// of the source extrinsic, then the destination chain is watched for the
+/// matching `MessageQueue`, `XcmpQueue` or `DmpQueue` processing event.
+pub struct XcmDeliveryTracker {
+    source: OnlineClient<PolkadotConfig>,
+    destination: OnlineClient<PolkadotConfig>,
+    timeout: Duration,
+    search_depth: u32,
+}
+
+impl XcmDeliveryTracker {
+    /// Create a tracker for transfers from `source` to `destination`
+    pub fn new(source: &SubstrateAdapter, destination: &SubstrateAdapter) -> Self {
+        Self {
+            source: source.client().clone(),
+            destination: destination.client().clone(),
+            timeout: DEFAULT_XCM_DELIVERY_TIMEOUT,
+            search_depth: crate::MAX_BLOCK_SEARCH_DEPTH,
+        }
+    }
+
+    /// Set how long to wait for the destination to process the message
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how many finalized blocks are searched on each chain
+    ///
+    /// Bounds the search for the source extrinsic and how far back the
+    /// destination is scanned for a message processed before tracking began.
+    pub fn with_search_depth(mut self, depth: u32) -> Self {
+        self.search_depth = depth;
+        self
+    }
+
+    /// Track the transfer submitted in extrinsic `tx_hash` on the source chain
+    pub async fn track(&self, tx_hash: &str) -> Result<XcmDeliveryStatus> {
+        let message_id = self.message_id(tx_hash).await?;
+        self.track_message(&message_id).await
+    }
+
+    /// Track a message by its ID on the destination chain
+    pub async fn track_message(&self, message_id: &[u8; 32]) -> Result<XcmDeliveryStatus> {
+        let head = finalized_head(&self.destination).await?;
+        let from_block = (head.number() as u64).saturating_sub(self.search_depth as u64);
+
+        let watcher = XcmMessageWatcher::new(self.destination.clone());
+        match tokio::time::timeout(
+            self.timeout,
+            watcher.wait_for_processed(message_id, Some(from_block)),
+        )
+        .await
+        {
+            Ok(outcome) => outcome.map(XcmDeliveryStatus::from),
+            Err(_) => {
+                warn!(
+                    "XCM message 0x{} not processed within {:?}",
+                    hex::encode(message_id),
+                    self.timeout
+                );
+                Ok(XcmDeliveryStatus::TimedOut)
+            }
+        }
+    }
+
+    /// Get the message ID sent by an extrinsic on the source chain
+    ///
+    /// Walks back from the finalized head looking for the extrinsic and reads
+    /// the message ID from its `Sent` event.
+    pub async fn message_id(&self, tx_hash: &str) -> Result<[u8; 32]> {
+        let hash: [u8; 32] = hex::decode(tx_hash.trim_start_matches("0x"))
+            .map_err(|e| Error::Transaction(format!("Invalid transaction hash: {}", e)))?
+            .try_into()
+            .map_err(|_| Error::Transaction("Transaction hash must be 32 bytes".to_string()))?;
+
+        let mut block = finalized_head(&self.source).await?;
+
+        for _ in 0..self.search_depth {
+            let extrinsics = block
+                .extrinsics()
+                .await
+                .map_err(|e| Error::Transaction(format!("Failed to get extrinsics: {}", e)))?;
+
+            if let Some(ext) = extrinsics
+                .iter()
+                .find(|ext| sp_core::blake2_256(ext.bytes()) == hash)
+            {
+                let events = ext
+                    .events()
+                    .await
+                    .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
+
+                return events
+                    .iter()
+                    .filter_map(|event| event.ok())
+                    .find(|event| is_sent_event(event.pallet_name(), event.variant_name()))
+                    .and_then(|event| message_id_from_sent_fields(event.field_bytes()))
+                    .ok_or_else(|| {
+                        Error::Transaction(format!(
+                            "Extrinsic {} did not emit an XCM Sent event",
+                            tx_hash
+                        ))
+                    });
+            }
+
+            if block.number() == 0 {
+                break;
+            }
+            block = self
+                .source
+                .blocks()
+                .at(block.header().parent_hash)
+                .await
+                .map_err(|e| Error::Connection(format!("Failed to traverse blocks: {}", e)))?;
+        }
+
+        Err(Error::Transaction(format!(
+            "Extrinsic {} not found in the last {} finalized blocks",
+            tx_hash, self.search_depth
+        )))
+    }
+}
+
+/// Get the latest finalized block of a chain
+async fn finalized_head(
+    client: &OnlineClient<PolkadotConfig>,
+) -> Result<subxt::blocks::Block<PolkadotConfig, OnlineClient<PolkadotConfig>>> {
+    let head = client
+        .backend()
+        .latest_finalized_block_ref()
+        .await
+        .map_err(|e| Error::Connection(format!("Failed to get finalized head: {}", e)))?;
+
+    client
+        .blocks()
+        .at(head)
+        .await
+        .map_err(|e| Error::Connection(format!("Failed to get finalized block: {}", e)))
+}
+
+/// Look for the processing event of a message in a block
+async fn find_processed(
+    block: &subxt::blocks::Block<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+    message_id: &[u8; 32],
+) -> Result<Option<XcmMessageOutcome>> {
+    let events = block
+        .events()
+        .await
+        .map_err(|e| Error::Transaction(format!("Failed to get events: {}", e)))?;
+
+    for event in events.iter() {
+        let event =
+            event.map_err(|e| Error::Transaction(format!("Failed to decode event: {}", e)))?;
+
+        if !event
+            .field_bytes()
+            .windows(message_id.len())
+            .any(|window| window == message_id)
+        {
+            continue;
+        }
+        let Some(result) = processing_result(event.pallet_name(), event.variant_name(), || {
+            event.field_values().ok()
+        }) else {
+            continue;
+        };
+
+        return Ok(Some(XcmMessageOutcome {
+            block_number: block.number() as u64,
+            block_hash: format!("0x{}", hex::encode(block.hash())),
+            success: result.is_ok(),
+            error: result.err(),
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Check whether an event is the XCM pallet's `Sent` event
+fn is_sent_event(pallet: &str, variant: &str) -> bool {
+    matches!(pallet, "XcmPallet" | "PolkadotXcm") && variant == "Sent"
+}
+
+/// Extract the message ID from the encoded fields of a `Sent` event
+///
+/// `message_id` is the last field of `Sent { origin, destination, message, message_id }`.
+fn message_id_from_sent_fields(field_bytes: &[u8]) -> Option<[u8; 32]> {
+    let start = field_bytes.len().checked_sub(32)?;
+    field_bytes[start..].try_into().ok()
+}
+
+/// Get the result of a message-processing event, or `None` for other events
+///
+/// `fields` is only decoded for processing events.
+fn processing_result<T, F>(
+    pallet: &str,
+    variant: &str,
+    fields: F,
+) -> Option<std::result::Result<(), String>>
+where
+    F: FnOnce() -> Option<Composite<T>>,
+{
+    match (pallet, variant) {
+        ("XcmpQueue", "Success") => Some(Ok(())),
+        // Fail { message_hash, message_id, error, weight }
+        ("XcmpQueue", "Fail") => Some(Err(field(fields(), "error")
+            .map(|error| describe_error(&error))
+            .unwrap_or_else(|| "XCM execution failed".to_string()))),
+        // Processed { id, origin, weight_used, success }
+        ("MessageQueue", "Processed") => {
+            let success = field(fields(), "success").and_then(|value| match value.value {
+                ValueDef::Primitive(Primitive::Bool(b)) => Some(b),
+                _ => None,
+            });
+            Some(if success.unwrap_or(false) {
+                Ok(())
+            } else {
+                Err("XCM execution failed".to_string())
+            })
+        }
+        // ProcessingFailed { id, origin, error }
+        ("MessageQueue", "ProcessingFailed") => Some(Err(field(fields(), "error")
+            .map(|error| describe_error(&error))
+            .unwrap_or_else(|| "Message processing failed".to_string()))),
+        // ExecutedDownward { message_hash, message_id, outcome }
+        ("DmpQueue", "ExecutedDownward") => {
+            let outcome = field(fields(), "outcome")?;
+            match outcome.value {
+                ValueDef::Variant(variant) if variant.name == "Complete" => Some(Ok(())),
+                ValueDef::Variant(variant) => {
+                    let error = match variant.values {
+                        Composite::Named(values) => values
+                            .into_iter()
+                            .find(|(name, _)| name == "error")
+                            .map(|(_, value)| value),
+                        Composite::Unnamed(values) => values.into_iter().last(),
+                    };
+                    Some(Err(error
+                        .map(|error| describe_error(&error))
+                        .unwrap_or(variant.name)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Get a named field from decoded event fields
+fn field<T>(fields: Option<Composite<T>>, name: &str) -> Option<Value<T>> {
+    match fields? {
+        Composite::Named(fields) => fields
+            .into_iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value),
+        Composite::Unnamed(_) => None,
+    }
+}
+
+/// Render a decoded error value, using the bare variant name where possible
+fn describe_error<T>(error: &Value<T>) -> String {
+    match &error.value {
+        ValueDef::Variant(variant) if variant.values.is_empty() => variant.name.clone(),
+        _ => error.to_string(),
+    }
+}
+
+/// Encode a location wrapped in its versioned enum, e.g. `VersionedLocation::V4`
+pub(crate) fn encode_versioned_location(
+    location: &MultiLocation,
+    version: XcmVersion,
+) -> Result<Value> {
+    Ok(Value::unnamed_variant(
+        version.variant_name(),
+        vec![encode_location(location, version)?],
+    ))
+}
+
+/// Encode a `MultiLocation` (v3) or `Location` (v4+)
+pub(crate) fn encode_location(location: &MultiLocation, version: XcmVersion) -> Result<Value> {
+    Ok(Value::named_composite([
+        ("parents", Value::u128(location.parents as u128)),
+        ("interior", encode_junctions(&location.interior, version)?),
+    ]))
+}
+
+/// Encode interior junctions (`Junctions`)
+pub(crate) fn encode_junctions(junctions: &[Junction], version: XcmVersion) -> Result<Value> {
+    if version == XcmVersion::V2 {
+        return Err(Error::Transaction(
+            "XCM v2 is no longer supported; use v3 or later".to_string(),
+        ));
+    }
+
+    let junctions = junctions
+        .iter()
+        .map(|junction| encode_junction(junction, version))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(match junctions.len() {
+        0 => Value::unnamed_variant("Here", vec![]),
+        n @ 1..=8 => {
+            let name = format!("X{}", n);
+            if version == XcmVersion::V3 {
+                // V3 junctions are variant fields
+                Value::unnamed_variant(name, junctions)
+            } else {
+                // V4+ junctions are an array
+                Value::unnamed_variant(name, vec![Value::unnamed_composite(junctions)])
+            }
+        }
+        _ => return Err(Error::Transaction("Too many junctions (max 8)".to_string())),
+    })
+}
+
+fn encode_junction(junction: &Junction, version: XcmVersion) -> Result<Value> {
+    let network_option = |network: &Option<NetworkId>| -> Result<Value> {
+        Ok(match network {
+            Some(network) => {
+                Value::unnamed_variant("Some", vec![encode_network(network, version)?])
+            }
+            None => Value::unnamed_variant("None", vec![]),
+        })
+    };
+
+    Ok(match junction {
+        Junction::Parachain(id) => {
+            Value::unnamed_variant("Parachain", vec![Value::u128(*id as u128)])
+        }
+        Junction::AccountId32 { network, id } => Value::named_variant(
+            "AccountId32",
+            [
+                ("network", network_option(network)?),
+                ("id", Value::from_bytes(id)),
+            ],
+        ),
+        Junction::AccountId20 { network, key } => Value::named_variant(
+            "AccountKey20",
+            [
+                ("network", network_option(network)?),
+                ("key", Value::from_bytes(key)),
+            ],
+        ),
+        Junction::GeneralIndex(index) => {
+            Value::unnamed_variant("GeneralIndex", vec![Value::u128(*index)])
+        }
+        Junction::GeneralKey { data } => {
+            if data.len() > 32 {
+                return Err(Error::Transaction(
+                    "GeneralKey data must be at most 32 bytes".to_string(),
+                ));
+            }
+            let mut padded = [0u8; 32];
+            padded[..data.len()].copy_from_slice(data);
+            Value::named_variant(
+                "GeneralKey",
+                [
+                    ("length", Value::u128(data.len() as u128)),
+                    ("data", Value::from_bytes(padded)),
+                ],
+            )
+        }
+        Junction::PalletInstance(instance) => {
+            Value::unnamed_variant("PalletInstance", vec![Value::u128(*instance as u128)])
+        }
+        Junction::GlobalConsensus(network) => {
+            Value::unnamed_variant("GlobalConsensus", vec![encode_network(network, version)?])
+        }
+    })
+}
+
+fn encode_network(network: &NetworkId, version: XcmVersion) -> Result<Value> {
+    let by_genesis =
+        |genesis: &[u8; 32]| Value::unnamed_variant("ByGenesis", vec![Value::from_bytes(genesis)]);
+
+    Ok(match network {
+        NetworkId::Polkadot => Value::unnamed_variant("Polkadot", vec![]),
+        NetworkId::Kusama => Value::unnamed_variant("Kusama", vec![]),
+        // V5 identifies test networks by genesis hash
+        NetworkId::Westend if version >= XcmVersion::V5 => by_genesis(&WESTEND_GENESIS),
+        NetworkId::Westend => Value::unnamed_variant("Westend", vec![]),
+        NetworkId::Rococo if version >= XcmVersion::V5 => by_genesis(&ROCOCO_GENESIS),
+        NetworkId::Rococo => Value::unnamed_variant("Rococo", vec![]),
+        NetworkId::ByGenesis(genesis) => by_genesis(genesis),
+        NetworkId::Ethereum { chain_id } => {
+            Value::named_variant("Ethereum", [("chain_id", Value::u128(*chain_id as u128))])
+        }
+        NetworkId::BitcoinCore => Value::unnamed_variant("BitcoinCore", vec![]),
+        NetworkId::BitcoinCash => Value::unnamed_variant("BitcoinCash", vec![]),
+        NetworkId::PolkadotBulletin => Value::unnamed_variant("PolkadotBulletin", vec![]),
+    })
+}
+
+/// Encode assets wrapped in their versioned enum, e.g. `VersionedAssets::V4`
+pub(crate) fn encode_versioned_assets(assets: &[XcmAsset], version: XcmVersion) -> Result<Value> {
+    Ok(Value::unnamed_variant(
+        version.variant_name(),
+        vec![encode_assets(assets, version)?],
+    ))
+}
+
+/// Encode `MultiAssets` (v3) or `Assets` (v4+)
+pub(crate) fn encode_assets(assets: &[XcmAsset], version: XcmVersion) -> Result<Value> {
+    let assets = assets
+        .iter()
+        .map(|asset| encode_asset(asset, version))
+        .collect::<Result<Vec<_>>>()?;
+
+    // `MultiAssets`/`Assets` wrap a sorted vector
+    Ok(Value::unnamed_composite(vec![Value::unnamed_composite(
+        assets,
+    )]))
+}
+
+/// Encode a `MultiAsset` (v3) or `Asset` (v4+)
+pub(crate) fn encode_asset(asset: &XcmAsset, version: XcmVersion) -> Result<Value> {
+    let id = encode_asset_id(&asset.id, version)?;
+
+    let fun = match asset.fun {
+        Fungibility::Fungible(amount) => {
+            Value::unnamed_variant("Fungible", vec![Value::u128(amount)])
+        }
+        Fungibility::NonFungible(instance) => Value::unnamed_variant(
+            "NonFungible",
+            vec![Value::unnamed_variant("Index", vec![Value::u128(instance)])],
+        ),
+    };
+
+    Ok(Value::named_composite([("id", id), ("fun", fun)]))
+}
+
+/// Encode a `MultiAssetId` (v3) or `AssetId` (v4+)
+pub(crate) fn encode_asset_id(id: &AssetId, version: XcmVersion) -> Result<Value> {
+    Ok(match (id, version) {
+        (AssetId::Concrete(location), XcmVersion::V3) => {
+            Value::unnamed_variant("Concrete", vec![encode_location(location, version)?])
+        }
+        (AssetId::Abstract(data), XcmVersion::V3) => {
+            let id: [u8; 32] = data.as_slice().try_into().map_err(|_| {
+                Error::Transaction("Abstract asset IDs must be 32 bytes".to_string())
+            })?;
+            Value::unnamed_variant("Abstract", vec![Value::from_bytes(id)])
+        }
+        // V4+ asset IDs are a location newtype
+        (AssetId::Concrete(location), _) => {
+            Value::unnamed_composite(vec![encode_location(location, version)?])
+        }
+        (AssetId::Abstract(_), _) => {
+            return Err(Error::Transaction(format!(
+                "Abstract asset IDs are not supported in XCM {:?}",
+                version
+            )))
+        }
+    })
+}
+
+pub(crate) fn encode_weight_limit(limit: WeightLimit) -> Value {
+    match limit {
+        WeightLimit::Unlimited => Value::unnamed_variant("Unlimited", vec![]),
+        WeightLimit::Limited(ref_time) => Value::unnamed_variant(
+            "Limited",
+            vec![Value::named_composite([
+                ("ref_time", Value::u128(ref_time as u128)),
+                ("proof_size", Value::u128(WEIGHT_LIMIT_PROOF_SIZE as u128)),
+            ])],
+        ),
+    }
+}
+
+/// Get the XCM version of a versioned message or location
+fn message_version<T>(value: &Value<T>) -> Option<XcmVersion> {
+    match &value.value {
+        ValueDef::Variant(versioned) => {
+            XcmVersion::from_number(versioned.name.strip_prefix('V')?.parse().ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Call a runtime API and decode the result into a dynamic value
+async fn call_runtime_api(
+    api: &subxt::runtime_api::RuntimeApi<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+    trait_name: &str,
+    method: &str,
+    args: Vec<Value>,
+) -> Result<Value<u32>> {
+    let payload = subxt::dynamic::runtime_api_call(trait_name, method, args);
+    api.call(payload)
+        .await
+        .map_err(|e| Error::Transaction(format!("{}_{} failed: {}", trait_name, method, e)))?
+        .to_value()
+        .map_err(|e| {
+            Error::Metadata(format!(
+                "Failed to decode {}_{} result: {}",
+                trait_name, method, e
+            ))
+        })
+}
+
+/// Number of arguments `DryRunApi_dry_run_call` takes
+///
+/// Version 1 of the API has no `result_xcms_version` argument.
+fn dry_run_call_inputs(client: &OnlineClient<PolkadotConfig>) -> usize {
+    client
+        .metadata()
+        .runtime_api_trait_by_name("DryRunApi")
+        .and_then(|api| api.method_by_name("dry_run_call").map(|m| m.inputs().len()))
+        .unwrap_or(3)
+}
+
+/// Unwrap the `Ok` value of a runtime API `Result`
+fn api_ok(value: Value<u32>, api: &str) -> Result<Value<u32>> {
+    match value.value {
+        ValueDef::Variant(result) if result.name == "Ok" => items(&result.values)
+            .first()
+            .cloned()
+            .ok_or_else(|| Error::Transaction(format!("{} returned no value", api))),
+        ValueDef::Variant(result) if result.name == "Err" => Err(Error::Transaction(format!(
+            "{} failed: {}",
+            api,
+            items(&result.values)
+                .first()
+                .map(describe_error)
+                .unwrap_or(result.name)
+        ))),
+        _ => Err(Error::Metadata(format!(
+            "{} returned an unexpected value",
+            api
+        ))),
+    }
+}
+
+/// Parse the `forwarded_xcms` of dry-run effects
+fn forwarded_entries<T: Clone>(forwarded: &Value<T>) -> Vec<ForwardedXcm> {
+    items_of(forwarded)
+        .iter()
+        .filter_map(|entry| match items_of(entry) {
+            [destination, messages] => Some(ForwardedXcm {
+                destination: destination.clone().remove_context(),
+                messages: items_of(messages)
+                    .iter()
+                    .map(|message| message.clone().remove_context())
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Find the asset paid by a message's `BuyExecution` or `PayFees` instruction
+///
+/// Returned as a versioned asset ID in the message's XCM version.
+fn fee_asset_id(message: &Value) -> Option<Value> {
+    let ValueDef::Variant(versioned) = &message.value else {
+        return None;
+    };
+
+    let xcm = items(&versioned.values).first()?;
+    newtype_items(xcm).iter().find_map(|instruction| {
+        let ValueDef::Variant(instruction) = &instruction.value else {
+            return None;
+        };
+        let asset = match instruction.name.as_str() {
+            "BuyExecution" => composite_field(&instruction.values, "fees")?,
+            "PayFees" => composite_field(&instruction.values, "asset")?,
+            _ => return None,
+        };
+        let id = named_field(asset, "id")?;
+        Some(Value::unnamed_variant(
+            versioned.name.clone(),
+            vec![id.clone()],
+        ))
+    })
+}
+
+/// Location of the origin chain as seen from `dest`
+///
+/// Covers transfers between a relay chain and its parachains and between
+/// sibling parachains.
+fn origin_relative_to(dest: &MultiLocation, own_para_id: Option<u32>) -> Option<MultiLocation> {
+    match (dest.parents, dest.interior.as_slice(), own_para_id) {
+        // Parachain to relay chain
+        (1, [], Some(id)) => Some(MultiLocation::new(0, vec![Junction::Parachain(id)])),
+        // Relay chain to parachain
+        (0, [Junction::Parachain(_)], None) => Some(MultiLocation::parent()),
+        // Parachain to sibling
+        (1, [Junction::Parachain(_)], Some(id)) => Some(MultiLocation::parachain(id)),
+        _ => None,
+    }
+}
+
+/// Decode a (possibly versioned) location value
+fn decode_location<T>(value: &Value<T>) -> Option<MultiLocation> {
+    match &value.value {
+        ValueDef::Variant(versioned) if versioned.name.starts_with('V') => {
+            decode_location(items(&versioned.values).first()?)
+        }
+        ValueDef::Composite(Composite::Named(_)) => {
+            let parents = named_field(value, "parents")?.as_u128()? as u8;
+            let ValueDef::Variant(interior) = &named_field(value, "interior")?.value else {
+                return None;
+            };
+            // V3 junctions are variant fields, later versions use an array
+            let junctions = match items(&interior.values) {
+                [array @ Value {
+                    value: ValueDef::Composite(_),
+                    ..
+                }] => items_of(array),
+                junctions => junctions,
+            };
+            let interior = junctions
+                .iter()
+                .map(decode_junction)
+                .collect::<Option<Vec<_>>>()?;
+            Some(MultiLocation { parents, interior })
+        }
+        // Newtype wrappers such as the V4 asset ID
+        ValueDef::Composite(Composite::Unnamed(fields)) if fields.len() == 1 => {
+            decode_location(&fields[0])
+        }
+        _ => None,
+    }
+}
+
+fn decode_junction<T>(value: &Value<T>) -> Option<Junction> {
+    let ValueDef::Variant(junction) = &value.value else {
+        return None;
+    };
+    let fields = items(&junction.values);
+
+    match junction.name.as_str() {
+        "Parachain" => Some(Junction::Parachain(fields.first()?.as_u128()? as u32)),
+        "AccountId32" => Some(Junction::AccountId32 {
+            network: decode_network_option(composite_field(&junction.values, "network")?),
+            id: bytes(composite_field(&junction.values, "id")?)?
+                .try_into()
+                .ok()?,
+        }),
+        "AccountKey20" => Some(Junction::AccountId20 {
+            network: decode_network_option(composite_field(&junction.values, "network")?),
+            key: bytes(composite_field(&junction.values, "key")?)?
+                .try_into()
+                .ok()?,
+        }),
+        "PalletInstance" => Some(Junction::PalletInstance(fields.first()?.as_u128()? as u8)),
+        "GlobalConsensus" => Some(Junction::GlobalConsensus(decode_network(fields.first()?)?)),
+        "GeneralIndex" => Some(Junction::GeneralIndex(fields.first()?.as_u128()?)),
+        "GeneralKey" => {
+            let length = composite_field(&junction.values, "length")?.as_u128()? as usize;
+            let mut data = bytes(composite_field(&junction.values, "data")?)?;
+            data.truncate(length);
+            Some(Junction::GeneralKey { data })
+        }
+        _ => None,
+    }
+}
+
+fn decode_network_option<T>(value: &Value<T>) -> Option<NetworkId> {
+    match &value.value {
+        ValueDef::Variant(option) if option.name == "Some" => {
+            decode_network(items(&option.values).first()?)
+        }
+        _ => None,
+    }
+}
+
+fn decode_network<T>(value: &Value<T>) -> Option<NetworkId> {
+    let ValueDef::Variant(network) = &value.value else {
+        return None;
+    };
+    match network.name.as_str() {
+        "Polkadot" => Some(NetworkId::Polkadot),
+        "Kusama" => Some(NetworkId::Kusama),
+        "Westend" => Some(NetworkId::Westend),
+        "Rococo" => Some(NetworkId::Rococo),
+        "ByGenesis" => {
+            let genesis: [u8; 32] = bytes(items(&network.values).first()?)?.try_into().ok()?;
+            Some(match genesis {
+                WESTEND_GENESIS => NetworkId::Westend,
+                ROCOCO_GENESIS => NetworkId::Rococo,
+                genesis => NetworkId::ByGenesis(genesis),
+            })
+        }
+        "Ethereum" => Some(NetworkId::Ethereum {
+            chain_id: composite_field(&network.values, "chain_id")?.as_u128()? as u64,
+        }),
+        "BitcoinCore" => Some(NetworkId::BitcoinCore),
+        "BitcoinCash" => Some(NetworkId::BitcoinCash),
+        "PolkadotBulletin" => Some(NetworkId::PolkadotBulletin),
+        _ => None,
+    }
+}
+
+/// Decode versioned assets, skipping assets that cannot be represented
+fn decode_assets<T>(value: &Value<T>) -> Vec<XcmAsset> {
+    let assets = match &value.value {
+        ValueDef::Variant(versioned) => items(&versioned.values)
+            .first()
+            .map(newtype_items)
+            .unwrap_or(&[]),
+        _ => newtype_items(value),
+    };
+    assets
+        .iter()
+        .filter_map(|asset| {
+            let decoded = decode_asset(asset);
+            if decoded.is_none() {
+                debug!("Skipping undecodable XCM asset");
+            }
+            decoded
+        })
+        .collect()
+}
+
+fn decode_asset<T>(value: &Value<T>) -> Option<XcmAsset> {
+    let id = named_field(value, "id")?;
+    let id = match &id.value {
+        ValueDef::Variant(id) if id.name == "Concrete" => {
+            AssetId::Concrete(decode_location(items(&id.values).first()?)?)
+        }
+        ValueDef::Variant(id) if id.name == "Abstract" => {
+            AssetId::Abstract(bytes(items(&id.values).first()?)?)
+        }
+        _ => AssetId::Concrete(decode_location(id)?),
+    };
+
+    let ValueDef::Variant(fun) = &named_field(value, "fun")?.value else {
+        return None;
+    };
+    let amount = items(&fun.values).first()?;
+    let fun = match fun.name.as_str() {
+        "Fungible" => Fungibility::Fungible(amount.as_u128()?),
+        "NonFungible" => match &amount.value {
+            ValueDef::Variant(instance) if instance.name == "Index" => {
+                Fungibility::NonFungible(items(&instance.values).first()?.as_u128()?)
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(XcmAsset { id, fun })
+}
+
+/// Get a field of a named composite value
+fn named_field<'a, T>(value: &'a Value<T>, name: &str) -> Option<&'a Value<T>> {
+    match &value.value {
+        ValueDef::Composite(fields) => composite_field(fields, name),
+        _ => None,
+    }
+}
+
+fn composite_field<'a, T>(fields: &'a Composite<T>, name: &str) -> Option<&'a Value<T>> {
+    match fields {
+        Composite::Named(fields) => fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value),
+        Composite::Unnamed(_) => None,
+    }
+}
+
+/// Items of a sequence or tuple value
+fn items_of<T>(value: &Value<T>) -> &[Value<T>] {
+    match &value.value {
+        ValueDef::Composite(fields) => items(fields),
+        _ => &[],
+    }
+}
+
+fn items<T>(fields: &Composite<T>) -> &[Value<T>] {
+    match fields {
+        Composite::Unnamed(values) => values,
+        Composite::Named(_) => &[],
+    }
+}
+
+/// Items of a newtype around a sequence, such as `Xcm(Vec<Instruction>)`
+fn newtype_items<T>(value: &Value<T>) -> &[Value<T>] {
+    items_of(value).first().map(items_of).unwrap_or(&[])
+}
+
+/// Decode a byte array or sequence value
+fn bytes<T>(value: &Value<T>) -> Option<Vec<u8>> {
+    items_of(value)
+        .iter()
+        .map(|byte| byte.as_u128().map(|b| b as u8))
+        .collect()
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sent_event_message_id() {
+        assert!(is_sent_event("XcmPallet", "Sent"));
+        assert!(is_sent_event("PolkadotXcm", "Sent"));
+        assert!(!is_sent_event("XcmpQueue", "Sent"));
+
+        let mut fields = vec![0xaa; 40];
+        fields.extend_from_slice(&[7u8; 32]);
+        assert_eq!(message_id_from_sent_fields(&fields), Some([7u8; 32]));
+        assert_eq!(message_id_from_sent_fields(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_processing_result() {
+        fn named(fields: Vec<(&'static str, Value)>) -> impl FnOnce() -> Option<Composite<()>> {
+            move || {
+                Some(Composite::Named(
+                    fields
+                        .into_iter()
+                        .map(|(name, value)| (name.to_string(), value))
+                        .collect(),
+                ))
+            }
+        }
+
+        assert_eq!(
+            processing_result("XcmpQueue", "Success", named(vec![])),
+            Some(Ok(()))
+        );
+        assert_eq!(
+            processing_result(
+                "XcmpQueue",
+                "Fail",
+                named(vec![(
+                    "error",
+                    Value::unnamed_variant("TooExpensive", vec![])
+                )])
+            ),
+            Some(Err("TooExpensive".to_string()))
+        );
+        assert_eq!(
+            processing_result(
+                "MessageQueue",
+                "Processed",
+                named(vec![("success", Value::bool(true))])
+            ),
+            Some(Ok(()))
+        );
+        assert_eq!(
+            processing_result(
+                "MessageQueue",
+                "Processed",
+                named(vec![("success", Value::bool(false))])
+            ),
+            Some(Err("XCM execution failed".to_string()))
+        );
+        assert_eq!(
+            processing_result(
+                "DmpQueue",
+                "ExecutedDownward",
+                named(vec![(
+                    "outcome",
+                    Value::named_variant(
+                        "Incomplete",
+                        [
+                            ("used", Value::u128(1)),
+                            ("error", Value::unnamed_variant("Barrier", vec![])),
+                        ]
+                    )
+                )])
+            ),
+            Some(Err("Barrier".to_string()))
+        );
+        assert_eq!(
+            processing_result("Balances", "Transfer", named(vec![])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_delivery_status_from_outcome() {
+        let outcome = XcmMessageOutcome {
+            block_number: 7,
+            block_hash: "0x07".to_string(),
+            success: false,
+            error: None,
+        };
+        assert_eq!(
+            XcmDeliveryStatus::from(outcome),
+            XcmDeliveryStatus::Failed {
+                block_number: 7,
+                block_hash: "0x07".to_string(),
+                error: "XCM execution failed".to_string(),
+            }
+        );
+    }
+
+    fn location_value(version: &str, para_id: u32) -> Value {
+        let junction = Value::unnamed_variant("Parachain", vec![Value::u128(para_id as u128)]);
+        let junctions = if version == "V3" {
+            vec![junction]
+        } else {
+            vec![Value::unnamed_composite(vec![junction])]
+        };
+        Value::unnamed_variant(
+            version,
+            vec![Value::named_composite([
+                ("parents", Value::u128(1)),
+                ("interior", Value::unnamed_variant("X1", junctions)),
+            ])],
+        )
+    }
+
+    #[test]
+    fn test_decode_versioned_location() {
+        assert_eq!(
+            decode_location(&location_value("V3", 1000)),
+            Some(MultiLocation::parachain(1000))
+        );
+        assert_eq!(
+            decode_location(&location_value("V4", 2000)),
+            Some(MultiLocation::parachain(2000))
+        );
+
+        let here = Value::unnamed_variant(
+            "V4",
+            vec![Value::named_composite([
+                ("parents", Value::u128(1)),
+                ("interior", Value::unnamed_variant("Here", vec![])),
+            ])],
+        );
+        assert_eq!(decode_location(&here), Some(MultiLocation::parent()));
+    }
+
+    #[test]
+    fn test_forwarded_xcm_and_fee_asset() {
+        let fee_id = Value::named_composite([
+            ("parents", Value::u128(1)),
+            ("interior", Value::unnamed_variant("Here", vec![])),
+        ]);
+        let fees = Value::named_composite([
+            ("id", Value::unnamed_composite(vec![fee_id.clone()])),
+            (
+                "fun",
+                Value::unnamed_variant("Fungible", vec![Value::u128(500)]),
+            ),
+        ]);
+        let message = Value::unnamed_variant(
+            "V4",
+            vec![Value::unnamed_composite(vec![Value::unnamed_composite(
+                vec![
+                    Value::unnamed_variant("ClearOrigin", vec![]),
+                    Value::named_variant(
+                        "BuyExecution",
+                        [
+                            ("fees", fees.clone()),
+                            ("weight_limit", Value::unnamed_variant("Unlimited", vec![])),
+                        ],
+                    ),
+                ],
+            )])],
+        );
+
+        // A single (destination, messages) entry must not be mistaken for a newtype
+        let forwarded = Value::unnamed_composite(vec![Value::unnamed_composite(vec![
+            location_value("V4", 1000),
+            Value::unnamed_composite(vec![message.clone()]),
+        ])]);
+        let entries = forwarded_entries(&forwarded);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].location(), Some(MultiLocation::parachain(1000)));
+        assert_eq!(entries[0].messages.len(), 1);
+
+        assert_eq!(
+            fee_asset_id(&message),
+            Some(Value::unnamed_variant(
+                "V4",
+                vec![Value::unnamed_composite(vec![fee_id])]
+            ))
+        );
+
+        let assets = Value::unnamed_variant(
+            "V4",
+            vec![Value::unnamed_composite(vec![Value::unnamed_composite(
+                vec![fees],
+            )])],
+        );
+        let decoded = decode_assets(&assets);
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].id, AssetId::Concrete(MultiLocation::parent()));
+        assert_eq!(decoded[0].fun, Fungibility::Fungible(500));
+    }
+
+    #[test]
+    fn test_api_ok() {
+        let ok = Value::unnamed_variant("Ok", vec![Value::u128(42)]).map_context(|_| 0u32);
+        assert_eq!(api_ok(ok, "Test").unwrap().as_u128(), Some(42));
+
+        let err = Value::unnamed_variant(
+            "Err",
+            vec![Value::unnamed_variant("WeightNotComputable", vec![])],
+        )
+        .map_context(|_| 0u32);
+        let error = api_ok(err, "XcmPaymentApi_query_xcm_weight").unwrap_err();
+        assert!(error.to_string().contains("WeightNotComputable"));
+    }
+
+    #[test]
+    fn test_origin_relative_to_destination() {
+        assert_eq!(
+            origin_relative_to(&MultiLocation::parent(), Some(1000)),
+            Some(MultiLocation::new(0, vec![Junction::Parachain(1000)]))
+        );
+        assert_eq!(
+            origin_relative_to(
+                &MultiLocation::new(0, vec![Junction::Parachain(1000)]),
+                None
+            ),
+            Some(MultiLocation::parent())
+        );
+        assert_eq!(
+            origin_relative_to(&MultiLocation::parachain(2000), Some(1000)),
+            Some(MultiLocation::parachain(1000))
+        );
+        assert_eq!(
+            origin_relative_to(
+                &MultiLocation::parachain_account(2000, [1u8; 32]),
+                Some(1000)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_location_encoding_round_trips() {
+        let locations = [
+            MultiLocation::parent(),
+            MultiLocation::parachain_account(2004, [3u8; 32]),
+            MultiLocation::account_key20([0x42; 20]),
+            MultiLocation::new(
+                2,
+                vec![
+                    Junction::GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+                    Junction::AccountId20 {
+                        network: None,
+                        key: [9u8; 20],
+                    },
+                ],
+            ),
+            MultiLocation::new(
+                1,
+                vec![
+                    Junction::Parachain(1000),
+                    Junction::PalletInstance(50),
+                    Junction::GeneralIndex(1984),
+                    Junction::GeneralKey {
+                        data: vec![1, 2, 3],
+                    },
+                ],
+            ),
+        ];
+
+        for version in [XcmVersion::V3, XcmVersion::V4, XcmVersion::V5] {
+            for location in &locations {
+                let encoded = encode_versioned_location(location, version).unwrap();
+                assert_eq!(message_version(&encoded), Some(version));
+                assert_eq!(decode_location(&encoded).as_ref(), Some(location));
+            }
+        }
+    }
+
+    #[test]
+    fn test_junction_layout_per_version() {
+        let location = MultiLocation::parachain(1000);
+        let interior = |version| {
+            let encoded = encode_location(&location, version).unwrap();
+            named_field(&encoded, "interior").unwrap().clone()
+        };
+
+        // V3 junctions are fields of X1, V4+ wrap them in an array
+        let ValueDef::Variant(v3) = interior(XcmVersion::V3).value else {
+            panic!("expected a variant");
+        };
+        assert!(matches!(items(&v3.values)[0].value, ValueDef::Variant(_)));
+        let ValueDef::Variant(v4) = interior(XcmVersion::V4).value else {
+            panic!("expected a variant");
+        };
+        assert!(matches!(items(&v4.values)[0].value, ValueDef::Composite(_)));
+
+        assert!(encode_location(&location, XcmVersion::V2).is_err());
+    }
+
+    #[test]
+    fn test_network_encoding_per_version() {
+        let westend = MultiLocation::global_consensus(NetworkId::Westend);
+        let v4 = encode_location(&westend, XcmVersion::V4).unwrap();
+        let v5 = encode_location(&westend, XcmVersion::V5).unwrap();
+
+        assert!(v4.to_string().contains("Westend"));
+        assert!(v5.to_string().contains("ByGenesis"));
+        assert_eq!(decode_location(&v5), Some(westend));
+    }
+
+    #[test]
+    fn test_asset_encoding_per_version() {
+        let asset = XcmAsset::native(1_000);
+        for version in [XcmVersion::V3, XcmVersion::V4, XcmVersion::V5] {
+            let encoded = encode_versioned_assets(std::slice::from_ref(&asset), version).unwrap();
+            let decoded = decode_assets(&encoded);
+            assert_eq!(decoded.len(), 1);
+            assert_eq!(decoded[0].id, asset.id);
+            assert_eq!(decoded[0].fun, asset.fun);
+        }
+
+        let abstract_asset = XcmAsset::fungible(AssetId::Abstract(vec![1u8; 32]), 1);
+        assert!(encode_asset(&abstract_asset, XcmVersion::V3).is_ok());
+        assert!(encode_asset(&abstract_asset, XcmVersion::V4).is_err());
+    }
+
+    #[test]
+    fn test_weight_limit_encoding() {
+        let encoded = encode_weight_limit(WeightLimit::Limited(1_000));
+        let ValueDef::Variant(limited) = &encoded.value else {
+            panic!("expected a variant");
+        };
+        let weight = &items(&limited.values)[0];
+        assert_eq!(
+            named_field(weight, "ref_time").and_then(|v| v.as_u128()),
+            Some(1_000)
+        );
+        assert_eq!(
+            named_field(weight, "proof_size").and_then(|v| v.as_u128()),
+            Some(WEIGHT_LIMIT_PROOF_SIZE as u128)
+        );
+    }
+
     #[test]
     fn test_multilocation_parent() {
         let location = MultiLocation::parent();
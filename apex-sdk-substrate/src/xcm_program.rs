@@ -0,0 +1,741 @@
+//! Typed XCM program builder
+//!
+//! Builds arbitrary XCM programs for `XcmPallet.execute`, `XcmPallet.send`
+//! and the custom destination XCM of `transfer_assets_using_type_and_then`.
+//! Programs are encoded in the XCM version negotiated for the destination.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use apex_sdk_substrate::xcm::{AssetId, MultiLocation, WeightLimit, XcmAsset};
+//! use apex_sdk_substrate::xcm_program::{AssetFilter, OriginKind, XcmProgram, XcmWeight};
+//!
+//! // Remote governance call on a sibling parachain, paid from our sovereign account
+//! let fee = XcmAsset::fungible(AssetId::Concrete(MultiLocation::parent()), 1_000_000_000);
+//! let program = XcmProgram::new()
+//!     .withdraw_asset(vec![fee.clone()])
+//!     .buy_execution(fee, WeightLimit::Unlimited)
+//!     .transact(OriginKind::SovereignAccount, encoded_call, Some(XcmWeight::new(1_000_000_000, 65_536)))
+//!     .refund_surplus()
+//!     .deposit_asset(AssetFilter::AllCounted(1), MultiLocation::parachain(2000))
+//!     .set_topic(topic);
+//!
+//! let submission = executor.send(&wallet, MultiLocation::parachain(2034), &program).await?;
+//! ```
+
+use crate::xcm::{
+    encode_asset, encode_asset_id, encode_assets, encode_junctions, encode_location,
+    encode_versioned_assets, encode_versioned_location, encode_weight_limit, AssetId, Junction,
+    MultiLocation, WeightLimit, XcmAsset, XcmVersion,
+};
+use crate::{Error, Result};
+use subxt::dynamic::Value;
+
+/// Weight of XCM programs and `Transact` calls
+///
+/// Alias of [`Weight`](crate::weight::Weight), kept for compatibility.
+pub use crate::weight::Weight as XcmWeight;
+
+/// Origin a `Transact` call is dispatched with on the destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginKind {
+    /// The chain's native origin for the message origin
+    Native,
+    /// The sovereign account of the message origin
+    SovereignAccount,
+    /// Root origin
+    Superuser,
+    /// The XCM pallet origin
+    Xcm,
+}
+
+impl OriginKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Native => "Native",
+            Self::SovereignAccount => "SovereignAccount",
+            Self::Superuser => "Superuser",
+            Self::Xcm => "Xcm",
+        }
+    }
+}
+
+/// Assets selected from the holding register
+#[derive(Debug, Clone)]
+pub enum AssetFilter {
+    /// Exactly these assets
+    Definite(Vec<XcmAsset>),
+    /// Every asset in holding
+    All,
+    /// Every asset in holding, limited to this many asset kinds
+    AllCounted(u32),
+}
+
+/// How assets reach the destination in `transfer_assets_using_type_and_then`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetTransferType {
+    /// Burn on the origin and mint on the destination
+    Teleport,
+    /// The origin chain is the reserve
+    LocalReserve,
+    /// The destination chain is the reserve
+    DestinationReserve,
+    /// A third chain is the reserve
+    RemoteReserve(MultiLocation),
+}
+
+/// Arguments of `transfer_assets_using_type_and_then`
+///
+/// Used for transfers the plain reserve/teleport calls cannot express, such
+/// as remote reserve transfers through Asset Hub with custom destination XCM.
+#[derive(Debug, Clone)]
+pub struct TransferAssetsUsingType {
+    /// Destination chain
+    pub dest: MultiLocation,
+    /// Assets to transfer
+    pub assets: Vec<XcmAsset>,
+    /// How the assets are transferred
+    pub assets_transfer_type: AssetTransferType,
+    /// Asset used to pay fees on the destination
+    pub remote_fees_id: AssetId,
+    /// How the fee asset is transferred
+    pub fees_transfer_type: AssetTransferType,
+    /// Program run on the destination after the assets arrive
+    pub custom_xcm_on_dest: XcmProgram,
+}
+
+impl TransferAssetsUsingType {
+    /// Create a transfer paying fees with the first asset
+    pub fn new(
+        dest: MultiLocation,
+        assets: Vec<XcmAsset>,
+        transfer_type: AssetTransferType,
+        custom_xcm_on_dest: XcmProgram,
+    ) -> Self {
+        let remote_fees_id = assets
+            .first()
+            .map(|asset| asset.id.clone())
+            .unwrap_or(AssetId::Concrete(MultiLocation::parent()));
+
+        Self {
+            dest,
+            assets,
+            fees_transfer_type: transfer_type.clone(),
+            assets_transfer_type: transfer_type,
+            remote_fees_id,
+            custom_xcm_on_dest,
+        }
+    }
+
+    /// Pay destination fees with another asset
+    pub fn with_fees(mut self, fees_id: AssetId, transfer_type: AssetTransferType) -> Self {
+        self.remote_fees_id = fees_id;
+        self.fees_transfer_type = transfer_type;
+        self
+    }
+
+    /// Arguments of the pallet-xcm call
+    pub(crate) fn call_fields(
+        &self,
+        version: XcmVersion,
+        weight_limit: WeightLimit,
+    ) -> Result<Vec<Value>> {
+        Ok(vec![
+            encode_versioned_location(&self.dest, version)?,
+            encode_versioned_assets(&self.assets, version)?,
+            encode_transfer_type(&self.assets_transfer_type, version)?,
+            Value::unnamed_variant(
+                version.variant_name(),
+                vec![encode_asset_id(&self.remote_fees_id, version)?],
+            ),
+            encode_transfer_type(&self.fees_transfer_type, version)?,
+            self.custom_xcm_on_dest.encode_versioned(version)?,
+            encode_weight_limit(weight_limit),
+        ])
+    }
+}
+
+/// A single XCM instruction
+#[derive(Debug, Clone)]
+pub enum XcmInstruction {
+    /// Withdraw assets from the origin's account into holding
+    WithdrawAsset(Vec<XcmAsset>),
+    /// Credit holding with assets held in reserve by the origin
+    ReserveAssetDeposited(Vec<XcmAsset>),
+    /// Credit holding with assets teleported from the origin
+    ReceiveTeleportedAsset(Vec<XcmAsset>),
+    /// Clear the origin
+    ClearOrigin,
+    /// Pay for execution from holding
+    BuyExecution {
+        /// Maximum fee to pay
+        fees: XcmAsset,
+        /// Weight to purchase
+        weight_limit: WeightLimit,
+    },
+    /// Pay for execution from holding (XCM v5)
+    PayFees {
+        /// Asset to pay with
+        asset: XcmAsset,
+    },
+    /// Deposit assets from holding into an account
+    DepositAsset {
+        /// Assets to deposit
+        assets: AssetFilter,
+        /// Receiving location
+        beneficiary: MultiLocation,
+    },
+    /// Deposit assets into the destination's sovereign account and notify it
+    DepositReserveAsset {
+        /// Assets to deposit
+        assets: AssetFilter,
+        /// Destination chain
+        dest: MultiLocation,
+        /// Program to run on the destination
+        xcm: XcmProgram,
+    },
+    /// Withdraw assets from a reserve chain
+    InitiateReserveWithdraw {
+        /// Assets to withdraw
+        assets: AssetFilter,
+        /// Reserve chain
+        reserve: MultiLocation,
+        /// Program to run on the reserve
+        xcm: XcmProgram,
+    },
+    /// Teleport assets to a destination
+    InitiateTeleport {
+        /// Assets to teleport
+        assets: AssetFilter,
+        /// Destination chain
+        dest: MultiLocation,
+        /// Program to run on the destination
+        xcm: XcmProgram,
+    },
+    /// Move assets from the origin's account to a beneficiary
+    TransferAsset {
+        /// Assets to move
+        assets: Vec<XcmAsset>,
+        /// Receiving location
+        beneficiary: MultiLocation,
+    },
+    /// Move assets to the destination's sovereign account and notify it
+    TransferReserveAsset {
+        /// Assets to move
+        assets: Vec<XcmAsset>,
+        /// Destination chain
+        dest: MultiLocation,
+        /// Program to run on the destination
+        xcm: XcmProgram,
+    },
+    /// Dispatch an encoded runtime call
+    Transact {
+        /// Origin to dispatch with
+        origin_kind: OriginKind,
+        /// SCALE-encoded runtime call
+        call: Vec<u8>,
+        /// Maximum weight of the call; required before XCM v5
+        max_weight: Option<XcmWeight>,
+    },
+    /// Refund unused execution fees into holding
+    RefundSurplus,
+    /// Set the message topic (ID)
+    SetTopic([u8; 32]),
+    /// Fail unless holding contains these assets
+    ExpectAsset(Vec<XcmAsset>),
+    /// Fail unless the origin matches
+    ExpectOrigin(Option<MultiLocation>),
+    /// Move the origin to an interior location
+    DescendOrigin(Vec<Junction>),
+    /// Run a program after this one, even on error
+    SetAppendix(XcmProgram),
+    /// Run a program if this one fails
+    SetErrorHandler(XcmProgram),
+}
+
+/// Builder for an XCM program
+#[derive(Debug, Clone, Default)]
+pub struct XcmProgram {
+    instructions: Vec<XcmInstruction>,
+}
+
+impl XcmProgram {
+    /// Create an empty program
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an instruction
+    pub fn push(mut self, instruction: XcmInstruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Instructions in execution order
+    pub fn instructions(&self) -> &[XcmInstruction] {
+        &self.instructions
+    }
+
+    /// Check whether the program has no instructions
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// Append `WithdrawAsset`
+    pub fn withdraw_asset(self, assets: Vec<XcmAsset>) -> Self {
+        self.push(XcmInstruction::WithdrawAsset(assets))
+    }
+
+    /// Append `ReserveAssetDeposited`
+    pub fn reserve_asset_deposited(self, assets: Vec<XcmAsset>) -> Self {
+        self.push(XcmInstruction::ReserveAssetDeposited(assets))
+    }
+
+    /// Append `ReceiveTeleportedAsset`
+    pub fn receive_teleported_asset(self, assets: Vec<XcmAsset>) -> Self {
+        self.push(XcmInstruction::ReceiveTeleportedAsset(assets))
+    }
+
+    /// Append `ClearOrigin`
+    pub fn clear_origin(self) -> Self {
+        self.push(XcmInstruction::ClearOrigin)
+    }
+
+    /// Append `BuyExecution`
+    pub fn buy_execution(self, fees: XcmAsset, weight_limit: WeightLimit) -> Self {
+        self.push(XcmInstruction::BuyExecution { fees, weight_limit })
+    }
+
+    /// Append `PayFees` (XCM v5)
+    pub fn pay_fees(self, asset: XcmAsset) -> Self {
+        self.push(XcmInstruction::PayFees { asset })
+    }
+
+    /// Append `DepositAsset`
+    pub fn deposit_asset(self, assets: AssetFilter, beneficiary: MultiLocation) -> Self {
+        self.push(XcmInstruction::DepositAsset {
+            assets,
+            beneficiary,
+        })
+    }
+
+    /// Append `DepositReserveAsset`
+    pub fn deposit_reserve_asset(
+        self,
+        assets: AssetFilter,
+        dest: MultiLocation,
+        xcm: XcmProgram,
+    ) -> Self {
+        self.push(XcmInstruction::DepositReserveAsset { assets, dest, xcm })
+    }
+
+    /// Append `InitiateReserveWithdraw`
+    pub fn initiate_reserve_withdraw(
+        self,
+        assets: AssetFilter,
+        reserve: MultiLocation,
+        xcm: XcmProgram,
+    ) -> Self {
+        self.push(XcmInstruction::InitiateReserveWithdraw {
+            assets,
+            reserve,
+            xcm,
+        })
+    }
+
+    /// Append `InitiateTeleport`
+    pub fn initiate_teleport(
+        self,
+        assets: AssetFilter,
+        dest: MultiLocation,
+        xcm: XcmProgram,
+    ) -> Self {
+        self.push(XcmInstruction::InitiateTeleport { assets, dest, xcm })
+    }
+
+    /// Append `TransferAsset`
+    pub fn transfer_asset(self, assets: Vec<XcmAsset>, beneficiary: MultiLocation) -> Self {
+        self.push(XcmInstruction::TransferAsset {
+            assets,
+            beneficiary,
+        })
+    }
+
+    /// Append `TransferReserveAsset`
+    pub fn transfer_reserve_asset(
+        self,
+        assets: Vec<XcmAsset>,
+        dest: MultiLocation,
+        xcm: XcmProgram,
+    ) -> Self {
+        self.push(XcmInstruction::TransferReserveAsset { assets, dest, xcm })
+    }
+
+    /// Append `Transact`
+    pub fn transact(
+        self,
+        origin_kind: OriginKind,
+        call: Vec<u8>,
+        max_weight: Option<XcmWeight>,
+    ) -> Self {
+        self.push(XcmInstruction::Transact {
+            origin_kind,
+            call,
+            max_weight,
+        })
+    }
+
+    /// Append `RefundSurplus`
+    pub fn refund_surplus(self) -> Self {
+        self.push(XcmInstruction::RefundSurplus)
+    }
+
+    /// Append `SetTopic`
+    pub fn set_topic(self, topic: [u8; 32]) -> Self {
+        self.push(XcmInstruction::SetTopic(topic))
+    }
+
+    /// Append `ExpectAsset`
+    pub fn expect_asset(self, assets: Vec<XcmAsset>) -> Self {
+        self.push(XcmInstruction::ExpectAsset(assets))
+    }
+
+    /// Append `ExpectOrigin`
+    pub fn expect_origin(self, origin: Option<MultiLocation>) -> Self {
+        self.push(XcmInstruction::ExpectOrigin(origin))
+    }
+
+    /// Append `DescendOrigin`
+    pub fn descend_origin(self, interior: Vec<Junction>) -> Self {
+        self.push(XcmInstruction::DescendOrigin(interior))
+    }
+
+    /// Append `SetAppendix`
+    pub fn set_appendix(self, xcm: XcmProgram) -> Self {
+        self.push(XcmInstruction::SetAppendix(xcm))
+    }
+
+    /// Append `SetErrorHandler`
+    pub fn set_error_handler(self, xcm: XcmProgram) -> Self {
+        self.push(XcmInstruction::SetErrorHandler(xcm))
+    }
+
+    /// Encode as a versioned message, e.g. `VersionedXcm::V4`
+    pub fn encode_versioned(&self, version: XcmVersion) -> Result<Value> {
+        Ok(Value::unnamed_variant(
+            version.variant_name(),
+            vec![self.encode(version)?],
+        ))
+    }
+
+    /// Encode as an unversioned `Xcm`
+    pub(crate) fn encode(&self, version: XcmVersion) -> Result<Value> {
+        let instructions = self
+            .instructions
+            .iter()
+            .map(|instruction| instruction.encode(version))
+            .collect::<Result<Vec<_>>>()?;
+
+        // `Xcm` wraps a vector of instructions
+        Ok(Value::unnamed_composite(vec![Value::unnamed_composite(
+            instructions,
+        )]))
+    }
+}
+
+impl XcmInstruction {
+    fn encode(&self, version: XcmVersion) -> Result<Value> {
+        let unit = |name: &str| Value::unnamed_variant(name, vec![]);
+
+        Ok(match self {
+            Self::WithdrawAsset(assets) => {
+                Value::unnamed_variant("WithdrawAsset", vec![encode_assets(assets, version)?])
+            }
+            Self::ReserveAssetDeposited(assets) => Value::unnamed_variant(
+                "ReserveAssetDeposited",
+                vec![encode_assets(assets, version)?],
+            ),
+            Self::ReceiveTeleportedAsset(assets) => Value::unnamed_variant(
+                "ReceiveTeleportedAsset",
+                vec![encode_assets(assets, version)?],
+            ),
+            Self::ClearOrigin => unit("ClearOrigin"),
+            Self::BuyExecution { fees, weight_limit } => Value::named_variant(
+                "BuyExecution",
+                [
+                    ("fees", encode_asset(fees, version)?),
+                    ("weight_limit", encode_weight_limit(*weight_limit)),
+                ],
+            ),
+            Self::PayFees { asset } => {
+                if version < XcmVersion::V5 {
+                    return Err(Error::Transaction(format!(
+                        "PayFees requires XCM v5, not {:?}",
+                        version
+                    )));
+                }
+                Value::named_variant("PayFees", [("asset", encode_asset(asset, version)?)])
+            }
+            Self::DepositAsset {
+                assets,
+                beneficiary,
+            } => Value::named_variant(
+                "DepositAsset",
+                [
+                    ("assets", encode_filter(assets, version)?),
+                    ("beneficiary", encode_location(beneficiary, version)?),
+                ],
+            ),
+            Self::DepositReserveAsset { assets, dest, xcm } => Value::named_variant(
+                "DepositReserveAsset",
+                [
+                    ("assets", encode_filter(assets, version)?),
+                    ("dest", encode_location(dest, version)?),
+                    ("xcm", xcm.encode(version)?),
+                ],
+            ),
+            Self::InitiateReserveWithdraw {
+                assets,
+                reserve,
+                xcm,
+            } => Value::named_variant(
+                "InitiateReserveWithdraw",
+                [
+                    ("assets", encode_filter(assets, version)?),
+                    ("reserve", encode_location(reserve, version)?),
+                    ("xcm", xcm.encode(version)?),
+                ],
+            ),
+            Self::InitiateTeleport { assets, dest, xcm } => Value::named_variant(
+                "InitiateTeleport",
+                [
+                    ("assets", encode_filter(assets, version)?),
+                    ("dest", encode_location(dest, version)?),
+                    ("xcm", xcm.encode(version)?),
+                ],
+            ),
+            Self::TransferAsset {
+                assets,
+                beneficiary,
+            } => Value::named_variant(
+                "TransferAsset",
+                [
+                    ("assets", encode_assets(assets, version)?),
+                    ("beneficiary", encode_location(beneficiary, version)?),
+                ],
+            ),
+            Self::TransferReserveAsset { assets, dest, xcm } => Value::named_variant(
+                "TransferReserveAsset",
+                [
+                    ("assets", encode_assets(assets, version)?),
+                    ("dest", encode_location(dest, version)?),
+                    ("xcm", xcm.encode(version)?),
+                ],
+            ),
+            Self::Transact {
+                origin_kind,
+                call,
+                max_weight,
+            } => {
+                let origin_kind = unit(origin_kind.name());
+                let call = Value::named_composite([("encoded", Value::from_bytes(call))]);
+
+                if version >= XcmVersion::V5 {
+                    let fallback = match max_weight {
+                        Some(weight) => Value::unnamed_variant("Some", vec![weight.encode()]),
+                        None => unit("None"),
+                    };
+                    Value::named_variant(
+                        "Transact",
+                        [
+                            ("origin_kind", origin_kind),
+                            ("fallback_max_weight", fallback),
+                            ("call", call),
+                        ],
+                    )
+                } else {
+                    let weight = max_weight.ok_or_else(|| {
+                        Error::Transaction(format!(
+                            "Transact requires a max weight in XCM {:?}",
+                            version
+                        ))
+                    })?;
+                    Value::named_variant(
+                        "Transact",
+                        [
+                            ("origin_kind", origin_kind),
+                            ("require_weight_at_most", weight.encode()),
+                            ("call", call),
+                        ],
+                    )
+                }
+            }
+            Self::RefundSurplus => unit("RefundSurplus"),
+            Self::SetTopic(topic) => {
+                Value::unnamed_variant("SetTopic", vec![Value::from_bytes(topic)])
+            }
+            Self::ExpectAsset(assets) => {
+                Value::unnamed_variant("ExpectAsset", vec![encode_assets(assets, version)?])
+            }
+            Self::ExpectOrigin(origin) => {
+                let origin = match origin {
+                    Some(origin) => {
+                        Value::unnamed_variant("Some", vec![encode_location(origin, version)?])
+                    }
+                    None => unit("None"),
+                };
+                Value::unnamed_variant("ExpectOrigin", vec![origin])
+            }
+            Self::DescendOrigin(interior) => {
+                Value::unnamed_variant("DescendOrigin", vec![encode_junctions(interior, version)?])
+            }
+            Self::SetAppendix(xcm) => {
+                Value::unnamed_variant("SetAppendix", vec![xcm.encode(version)?])
+            }
+            Self::SetErrorHandler(xcm) => {
+                Value::unnamed_variant("SetErrorHandler", vec![xcm.encode(version)?])
+            }
+        })
+    }
+}
+
+/// Encode a `MultiAssetFilter` (v3) or `AssetFilter` (v4+)
+fn encode_filter(filter: &AssetFilter, version: XcmVersion) -> Result<Value> {
+    Ok(match filter {
+        AssetFilter::Definite(assets) => {
+            Value::unnamed_variant("Definite", vec![encode_assets(assets, version)?])
+        }
+        AssetFilter::All => {
+            Value::unnamed_variant("Wild", vec![Value::unnamed_variant("All", vec![])])
+        }
+        AssetFilter::AllCounted(count) => Value::unnamed_variant(
+            "Wild",
+            vec![Value::unnamed_variant(
+                "AllCounted",
+                vec![Value::u128(*count as u128)],
+            )],
+        ),
+    })
+}
+
+/// Encode a pallet-xcm `TransferType`
+pub(crate) fn encode_transfer_type(
+    transfer_type: &AssetTransferType,
+    version: XcmVersion,
+) -> Result<Value> {
+    Ok(match transfer_type {
+        AssetTransferType::Teleport => Value::unnamed_variant("Teleport", vec![]),
+        AssetTransferType::LocalReserve => Value::unnamed_variant("LocalReserve", vec![]),
+        AssetTransferType::DestinationReserve => {
+            Value::unnamed_variant("DestinationReserve", vec![])
+        }
+        AssetTransferType::RemoteReserve(reserve) => Value::unnamed_variant(
+            "RemoteReserve",
+            vec![encode_versioned_location(reserve, version)?],
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee() -> XcmAsset {
+        XcmAsset::fungible(AssetId::Concrete(MultiLocation::parent()), 1_000)
+    }
+
+    fn remote_transact() -> XcmProgram {
+        XcmProgram::new()
+            .withdraw_asset(vec![fee()])
+            .buy_execution(fee(), WeightLimit::Unlimited)
+            .transact(
+                OriginKind::SovereignAccount,
+                vec![0x00, 0x07],
+                Some(XcmWeight::new(1_000, 2_000)),
+            )
+            .refund_surplus()
+            .deposit_asset(AssetFilter::AllCounted(1), MultiLocation::parachain(2000))
+            .set_topic([7u8; 32])
+    }
+
+    #[test]
+    fn test_program_builder() {
+        let program = remote_transact();
+        assert_eq!(program.instructions().len(), 6);
+        assert!(matches!(
+            program.instructions()[2],
+            XcmInstruction::Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                ..
+            }
+        ));
+        assert!(XcmProgram::new().is_empty());
+    }
+
+    #[test]
+    fn test_transact_encoding_per_version() {
+        let v4 = remote_transact().encode_versioned(XcmVersion::V4).unwrap();
+        let v5 = remote_transact().encode_versioned(XcmVersion::V5).unwrap();
+
+        assert!(v4.to_string().contains("require_weight_at_most"));
+        assert!(v5.to_string().contains("fallback_max_weight"));
+
+        let without_weight = XcmProgram::new().transact(OriginKind::Superuser, vec![0x00], None);
+        assert!(without_weight.encode_versioned(XcmVersion::V4).is_err());
+        assert!(without_weight.encode_versioned(XcmVersion::V5).is_ok());
+    }
+
+    #[test]
+    fn test_pay_fees_requires_v5() {
+        let program = XcmProgram::new().pay_fees(fee());
+        assert!(program.encode_versioned(XcmVersion::V4).is_err());
+        assert!(program.encode_versioned(XcmVersion::V5).is_ok());
+    }
+
+    #[test]
+    fn test_transfer_assets_using_type_fields() {
+        let transfer = TransferAssetsUsingType::new(
+            MultiLocation::parachain(2034),
+            vec![fee()],
+            AssetTransferType::RemoteReserve(MultiLocation::parachain(1000)),
+            XcmProgram::new().deposit_asset(
+                AssetFilter::AllCounted(1),
+                MultiLocation::account([1u8; 32]),
+            ),
+        );
+        assert_eq!(
+            transfer.remote_fees_id,
+            AssetId::Concrete(MultiLocation::parent())
+        );
+        assert_eq!(transfer.fees_transfer_type, transfer.assets_transfer_type);
+
+        let fields = transfer
+            .call_fields(XcmVersion::V4, WeightLimit::Unlimited)
+            .unwrap();
+        assert_eq!(fields.len(), 7);
+        assert!(fields[2].to_string().starts_with("RemoteReserve"));
+        assert!(fields[3].to_string().starts_with("V4"));
+        assert!(fields[5].to_string().contains("DepositAsset"));
+    }
+
+    #[test]
+    fn test_nested_program_encoding() {
+        let program = XcmProgram::new()
+            .withdraw_asset(vec![fee()])
+            .initiate_reserve_withdraw(
+                AssetFilter::All,
+                MultiLocation::parent(),
+                XcmProgram::new()
+                    .buy_execution(fee(), WeightLimit::Unlimited)
+                    .deposit_asset(AssetFilter::All, MultiLocation::account([1u8; 32])),
+            );
+
+        let encoded = program
+            .encode_versioned(XcmVersion::V3)
+            .unwrap()
+            .to_string();
+        assert!(encoded.contains("InitiateReserveWithdraw"));
+        assert!(encoded.contains("DepositAsset"));
+        assert!(encoded.contains("Concrete"));
+    }
+}
@@ -362,6 +362,7 @@ fn test_event_spec_structure() {
         label: "Approval".to_string(),
         args: vec![],
         docs: vec!["Approval event".to_string()],
+        signature_topic: None,
     };
 
     assert_eq!(event.label, "Approval");
@@ -498,3 +499,412 @@ fn test_large_args() {
     assert_eq!(call_data.len(), 1028);
     assert_eq!(&call_data[0..4], &selector);
 }
+
+/// ERC-20 style metadata with `Result<T, LangError>` returns
+fn erc20_metadata() -> ContractMetadata {
+    let json = r#"{
+        "spec": {
+            "constructors": [{
+                "label": "new",
+                "selector": [155, 174, 157, 94],
+                "args": [{ "label": "total_supply", "type_ref": { "ty": 0, "display_name": ["Balance"] } }],
+                "docs": []
+            }],
+            "messages": [{
+                "label": "balance_of",
+                "selector": [15, 117, 90, 86],
+                "args": [{ "label": "owner", "type_ref": { "ty": 3, "display_name": ["AccountId"] } }],
+                "return_type": { "ty": 4, "display_name": ["Result"] },
+                "mutates": false,
+                "payable": false,
+                "docs": []
+            }, {
+                "label": "transfer",
+                "selector": [132, 161, 93, 161],
+                "args": [
+                    { "label": "to", "type_ref": { "ty": 3, "display_name": ["AccountId"] } },
+                    { "label": "value", "type_ref": { "ty": 0, "display_name": ["Balance"] } }
+                ],
+                "return_type": { "ty": 9, "display_name": ["Result"] },
+                "mutates": true,
+                "payable": false,
+                "docs": []
+            }, {
+                "label": "set_memo",
+                "selector": [1, 2, 3, 4],
+                "args": [
+                    { "label": "key", "type_ref": { "ty": 10, "display_name": ["Option"] } },
+                    { "label": "data", "type_ref": { "ty": 11, "display_name": ["Vec"] } },
+                    { "label": "note", "type_ref": { "ty": 12, "display_name": ["String"] } }
+                ],
+                "return_type": null,
+                "mutates": true,
+                "payable": false,
+                "docs": []
+            }],
+            "events": []
+        },
+        "storage": { "root": { "key": "0x00000000", "ty": 0 } },
+        "types": [
+            { "id": 0, "path": [], "params": [], "def": { "type": "Primitive", "primitive": "u128" } },
+            { "id": 1, "path": [], "params": [], "def": { "type": "Array", "len": 32, "type_param": 2 } },
+            { "id": 2, "path": [], "params": [], "def": { "type": "Primitive", "primitive": "u8" } },
+            { "id": 3, "path": ["ink_primitives", "types", "AccountId"], "params": [],
+              "def": { "type": "Composite", "fields": [{ "name": null, "ty": 1 }] } },
+            { "id": 4, "path": ["Result"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "Ok", "fields": [{ "name": null, "ty": 0 }], "index": 0 },
+                  { "name": "Err", "fields": [{ "name": null, "ty": 5 }], "index": 1 }
+              ] } },
+            { "id": 5, "path": ["ink_primitives", "LangError"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "CouldNotReadInput", "fields": [], "index": 1 }
+              ] } },
+            { "id": 6, "path": ["Result"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "Ok", "fields": [{ "name": null, "ty": 7 }], "index": 0 },
+                  { "name": "Err", "fields": [{ "name": null, "ty": 8 }], "index": 1 }
+              ] } },
+            { "id": 7, "path": [], "params": [], "def": { "type": "Tuple", "fields": [] } },
+            { "id": 8, "path": ["erc20", "erc20", "Error"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "InsufficientBalance", "fields": [], "index": 0 },
+                  { "name": "InsufficientAllowance", "fields": [], "index": 1 }
+              ] } },
+            { "id": 9, "path": ["Result"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "Ok", "fields": [{ "name": null, "ty": 6 }], "index": 0 },
+                  { "name": "Err", "fields": [{ "name": null, "ty": 5 }], "index": 1 }
+              ] } },
+            { "id": 10, "path": ["Option"], "params": [],
+              "def": { "type": "Variant", "variants": [
+                  { "name": "None", "fields": [], "index": 0 },
+                  { "name": "Some", "fields": [{ "name": null, "ty": 0 }], "index": 1 }
+              ] } },
+            { "id": 11, "path": [], "params": [], "def": { "type": "Sequence", "type_param": 2 } },
+            { "id": 12, "path": [], "params": [], "def": { "type": "Primitive", "primitive": "str" } }
+        ]
+    }"#;
+
+    parse_metadata(json).unwrap()
+}
+
+const ALICE_SS58: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+#[test]
+fn test_encode_message_json_args() {
+    let metadata = erc20_metadata();
+
+    let expected = {
+        let mut bytes = hex::decode(ALICE).unwrap();
+        bytes.extend_from_slice(&1_000_000_000_000_000_000_000u128.to_le_bytes());
+        bytes
+    };
+
+    // Positional, with an SS58 address and a decimal string amount
+    let positional = metadata
+        .encode_message_json(
+            "transfer",
+            &serde_json::json!([ALICE_SS58, "1000000000000000000000"]),
+        )
+        .unwrap();
+    assert_eq!(positional, expected);
+
+    // Keyed by label, with a hex address
+    let keyed = metadata
+        .encode_message_json(
+            "transfer",
+            &serde_json::json!({ "value": "1000000000000000000000", "to": format!("0x{}", ALICE) }),
+        )
+        .unwrap();
+    assert_eq!(keyed, expected);
+}
+
+#[test]
+fn test_encode_message_value_args() {
+    use subxt::dynamic::Value;
+
+    let metadata = erc20_metadata();
+    let alice: [u8; 32] = hex::decode(ALICE).unwrap().try_into().unwrap();
+
+    let encoded = metadata
+        .encode_message_args("transfer", &[Value::from_bytes(alice), Value::u128(5)])
+        .unwrap();
+    assert_eq!(&encoded[..32], &alice);
+    assert_eq!(&encoded[32..], &5u128.to_le_bytes());
+
+    assert!(metadata
+        .encode_message_args("transfer", &[Value::u128(5)])
+        .is_err());
+    assert!(metadata
+        .encode_constructor_args("new", &[Value::u128(1)])
+        .is_ok());
+}
+
+#[test]
+fn test_encode_option_bytes_and_string() {
+    let metadata = erc20_metadata();
+
+    let some = metadata
+        .encode_message_json("set_memo", &serde_json::json!([7, "0xabcd", "hi"]))
+        .unwrap();
+    let mut expected = vec![1];
+    expected.extend_from_slice(&7u128.to_le_bytes());
+    expected.extend_from_slice(&[8, 0xab, 0xcd]);
+    expected.extend_from_slice(&[8, b'h', b'i']);
+    assert_eq!(some, expected);
+
+    let none = metadata
+        .encode_message_json("set_memo", &serde_json::json!([null, "0x", ""]))
+        .unwrap();
+    assert_eq!(none, vec![0, 0, 0]);
+
+    // u8 overflow is rejected rather than truncated
+    assert!(metadata
+        .encode_message_json("set_memo", &serde_json::json!([null, [256], ""]))
+        .is_err());
+}
+
+#[test]
+fn test_decode_message_return() {
+    let metadata = erc20_metadata();
+
+    // Ok(1000)
+    let mut data = vec![0];
+    data.extend_from_slice(&1000u128.to_le_bytes());
+    let result = metadata.decode_message_return("balance_of", &data).unwrap();
+    assert_eq!(result.json, serde_json::json!(1000));
+    assert_eq!(result.value.as_u128(), Some(1000));
+    assert!(!result.is_err());
+
+    // Balances beyond u64 are rendered as strings
+    let mut data = vec![0];
+    data.extend_from_slice(&u128::MAX.to_le_bytes());
+    let result = metadata.decode_message_return("balance_of", &data).unwrap();
+    assert_eq!(result.json, serde_json::json!(u128::MAX.to_string()));
+
+    // Err(LangError::CouldNotReadInput)
+    let err = metadata
+        .decode_message_return("balance_of", &[1, 1])
+        .unwrap_err();
+    assert!(err.to_string().contains("CouldNotReadInput"));
+
+    // Trailing bytes are rejected
+    assert!(metadata
+        .decode_message_return("balance_of", &[0; 18])
+        .is_err());
+}
+
+#[test]
+fn test_decode_contract_error() {
+    let metadata = erc20_metadata();
+
+    // Ok(Err(Error::InsufficientBalance))
+    let result = metadata
+        .decode_message_return("transfer", &[0, 1, 0])
+        .unwrap();
+    assert!(result.is_err());
+    assert_eq!(result.error, Some(serde_json::json!("InsufficientBalance")));
+    assert_eq!(
+        result.json,
+        serde_json::json!({ "Err": "InsufficientBalance" })
+    );
+
+    // Ok(Ok(()))
+    let result = metadata.decode_message_return("transfer", &[0, 0]).unwrap();
+    assert!(!result.is_err());
+    assert_eq!(result.json, serde_json::json!({ "Ok": null }));
+}
+
+#[test]
+fn test_value_round_trip() {
+    let metadata = erc20_metadata();
+    let encoded = metadata
+        .encode_message_json("balance_of", &serde_json::json!([ALICE_SS58]))
+        .unwrap();
+
+    let value = metadata.decode_value(3, &encoded).unwrap();
+    assert_eq!(
+        metadata.value_to_json(3, &value).unwrap(),
+        serde_json::json!(format!("0x{}", ALICE))
+    );
+    assert_eq!(metadata.encode_value(3, &value).unwrap(), encoded);
+}
+
+fn dry_run(storage_deposit: StorageDeposit) -> DryRunResult {
+    DryRunResult {
+        gas_consumed: GasLimit::new(800, 80),
+        gas_required: GasLimit::new(1_000, 100),
+        storage_deposit,
+        debug_message: String::new(),
+        reverted: false,
+        data: vec![],
+        contract_address: None,
+        error: None,
+    }
+}
+
+#[test]
+fn test_dry_run_limits_with_margin() {
+    let result = dry_run(StorageDeposit::Charge(1_000));
+
+    assert_eq!(
+        result.gas_limit(DEFAULT_GAS_MARGIN_PERCENT),
+        GasLimit::new(1_100, 110)
+    );
+    assert_eq!(result.gas_limit(0), result.gas_required);
+    assert!(matches!(
+        result.storage_deposit_limit(20),
+        StorageDepositLimit::Limited(1_200)
+    ));
+
+    // Refunds need no deposit
+    assert!(matches!(
+        dry_run(StorageDeposit::Refund(500)).storage_deposit_limit(10),
+        StorageDepositLimit::Limited(0)
+    ));
+}
+
+#[test]
+fn test_dry_run_success() {
+    assert!(dry_run(StorageDeposit::Charge(0)).is_success());
+
+    let reverted = DryRunResult {
+        reverted: true,
+        ..dry_run(StorageDeposit::Charge(0))
+    };
+    assert!(!reverted.is_success());
+
+    let failed = DryRunResult {
+        error: Some("Module(ContractTrapped)".to_string()),
+        ..dry_run(StorageDeposit::Charge(0))
+    };
+    assert!(!failed.is_success());
+}
+
+fn transfer_event(signature_topic: Option<&str>) -> EventSpec {
+    let arg = |label: &str, ty: u32, indexed: bool| EventArg {
+        label: label.to_string(),
+        type_ref: TypeRef {
+            ty,
+            display_name: vec![],
+        },
+        indexed,
+    };
+
+    EventSpec {
+        label: "Transfer".to_string(),
+        args: vec![
+            arg("from", 10, true),
+            arg("to", 3, true),
+            arg("value", 0, false),
+        ],
+        docs: vec![],
+        signature_topic: signature_topic.map(str::to_string),
+    }
+}
+
+fn approval_event() -> EventSpec {
+    EventSpec {
+        label: "Approval".to_string(),
+        args: vec![EventArg {
+            label: "value".to_string(),
+            type_ref: TypeRef {
+                ty: 0,
+                display_name: vec![],
+            },
+            indexed: false,
+        }],
+        docs: vec![],
+        signature_topic: None,
+    }
+}
+
+/// `Transfer { from: None, to: ALICE, value: 42 }` without an index prefix
+fn transfer_data() -> Vec<u8> {
+    let mut data = vec![0];
+    data.extend_from_slice(&hex::decode(ALICE).unwrap());
+    data.extend_from_slice(&42u128.to_le_bytes());
+    data
+}
+
+#[test]
+fn test_decode_v4_event_by_index() {
+    let mut metadata = erc20_metadata();
+    metadata.version = Some(4);
+    metadata.spec.events = vec![approval_event(), transfer_event(None)];
+
+    let mut data = vec![1];
+    data.extend_from_slice(&transfer_data());
+
+    let event = metadata.decode_event(&data, &[]).unwrap();
+    assert_eq!(event.name, "Transfer");
+    assert_eq!(event.field("value").unwrap().as_u128(), Some(42));
+    assert_eq!(
+        event.json,
+        serde_json::json!({ "from": null, "to": format!("0x{}", ALICE), "value": 42 })
+    );
+
+    assert!(metadata.decode_event(&[5], &[]).is_err());
+}
+
+#[test]
+fn test_decode_v5_event_by_signature_topic() {
+    let topic = [0xabu8; 32];
+    let mut metadata = erc20_metadata();
+    metadata.version = Some(5);
+    metadata.spec.events = vec![
+        approval_event(),
+        transfer_event(Some(&format!("0x{}", hex::encode(topic)))),
+    ];
+
+    let event = metadata
+        .decode_event(&transfer_data(), &[topic, [0u8; 32]])
+        .unwrap();
+    assert_eq!(event.name, "Transfer");
+    assert_eq!(event.fields.len(), 3);
+
+    // Anonymous events are matched by decoding
+    let event = metadata
+        .decode_event(&7u128.to_le_bytes(), &[[0x11; 32]])
+        .unwrap();
+    assert_eq!(event.name, "Approval");
+    assert_eq!(event.json, serde_json::json!({ "value": 7 }));
+
+    // Data no event can decode
+    assert!(metadata.decode_event(&[1, 2, 3], &[[0x11; 32]]).is_err());
+}
+
+#[test]
+fn test_decode_event_follows_metadata_version() {
+    // Without signature topics, v5 metadata still matches anonymous events
+    // by decoding rather than reading an index prefix
+    let mut metadata = erc20_metadata();
+    metadata.version = Some(5);
+    metadata.spec.events = vec![approval_event()];
+    let event = metadata.decode_event(&7u128.to_le_bytes(), &[]).unwrap();
+    assert_eq!(event.json, serde_json::json!({ "value": 7 }));
+
+    metadata.version = None;
+    assert!(metadata.decode_event(&7u128.to_le_bytes(), &[]).is_err());
+}
+
+#[test]
+fn test_contract_metadata_version() {
+    let json = |version: &str| {
+        format!(
+            r#"{{
+                "version": {},
+                "spec": {{ "constructors": [], "messages": [], "events": [] }},
+                "storage": {{ "root": {{ "key": "0x00", "ty": 0 }} }},
+                "types": []
+            }}"#,
+            version
+        )
+    };
+
+    assert_eq!(parse_metadata(&json("\"4\"")).unwrap().version, Some(4));
+    assert_eq!(parse_metadata(&json("5")).unwrap().version, Some(5));
+    assert!(parse_metadata(&json("\"v5\"")).is_err());
+}
@@ -0,0 +1,216 @@
+//! Cross-checks the Substrate golden vectors against subxt
+//!
+//! `apex-sdk-core` encodes Substrate signing payloads without depending on
+//! subxt. These tests build the same payloads with subxt's offline
+//! `PartialTransaction::signer_payload` against a minimal Polkadot-shaped
+//! metadata, so the core encoder cannot drift from what subxt actually signs.
+
+use apex_sdk_core::golden_vectors::{
+    encode_golden_vector_input, load_default_golden_vectors, GoldenVectorInput, SubstrateEra,
+    SubstrateValue,
+};
+use parity_scale_codec::Compact;
+use scale_info::{meta_type, TypeInfo};
+use sp_runtime::{generic::Era, AccountId32, MultiAddress, MultiSignature};
+use subxt::config::polkadot::PolkadotExtrinsicParamsBuilder;
+use subxt::config::PolkadotConfig;
+use subxt::dynamic::Value;
+use subxt::ext::frame_metadata::v15;
+use subxt::utils::H256;
+use subxt::{client::RuntimeVersion, OfflineClient};
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(TypeInfo)]
+enum BalancesCall {
+    #[codec(index = 3)]
+    transfer_keep_alive {
+        dest: MultiAddress<AccountId32, ()>,
+        #[codec(compact)]
+        value: u128,
+    },
+}
+
+#[allow(dead_code)]
+#[derive(TypeInfo)]
+enum RuntimeCall {
+    #[codec(index = 5)]
+    Balances(BalancesCall),
+}
+
+/// `CheckMetadataHash` mode as encoded by `frame_metadata_hash_extension`
+#[allow(dead_code)]
+#[derive(TypeInfo)]
+enum Mode {
+    Disabled,
+    Enabled,
+}
+
+/// Metadata with the Balances pallet at index 5 and Polkadot's signed
+/// extensions, in Polkadot's order
+fn polkadot_metadata() -> subxt::Metadata {
+    let extension = |identifier, ty, additional_signed| v15::SignedExtensionMetadata {
+        identifier,
+        ty,
+        additional_signed,
+    };
+
+    let metadata = v15::RuntimeMetadataV15::new(
+        vec![v15::PalletMetadata {
+            name: "Balances",
+            storage: None,
+            calls: Some(v15::PalletCallMetadata {
+                ty: meta_type::<BalancesCall>(),
+            }),
+            event: None,
+            constants: vec![],
+            error: None,
+            index: 5,
+            docs: vec![],
+        }],
+        v15::ExtrinsicMetadata {
+            version: 4,
+            address_ty: meta_type::<MultiAddress<AccountId32, ()>>(),
+            call_ty: meta_type::<RuntimeCall>(),
+            signature_ty: meta_type::<MultiSignature>(),
+            extra_ty: meta_type::<()>(),
+            signed_extensions: vec![
+                extension("CheckNonZeroSender", meta_type::<()>(), meta_type::<()>()),
+                extension("CheckSpecVersion", meta_type::<()>(), meta_type::<u32>()),
+                extension("CheckTxVersion", meta_type::<()>(), meta_type::<u32>()),
+                extension("CheckGenesis", meta_type::<()>(), meta_type::<H256>()),
+                extension("CheckMortality", meta_type::<Era>(), meta_type::<H256>()),
+                extension("CheckNonce", meta_type::<Compact<u32>>(), meta_type::<()>()),
+                extension("CheckWeight", meta_type::<()>(), meta_type::<()>()),
+                extension(
+                    "ChargeTransactionPayment",
+                    meta_type::<Compact<u128>>(),
+                    meta_type::<()>(),
+                ),
+                extension(
+                    "CheckMetadataHash",
+                    meta_type::<Mode>(),
+                    meta_type::<Option<[u8; 32]>>(),
+                ),
+            ],
+        },
+        meta_type::<()>(),
+        vec![],
+        v15::OuterEnums {
+            call_enum_ty: meta_type::<RuntimeCall>(),
+            event_enum_ty: meta_type::<()>(),
+            error_enum_ty: meta_type::<()>(),
+        },
+        v15::CustomMetadata {
+            map: Default::default(),
+        },
+    );
+
+    subxt::metadata::types::Metadata::try_from(metadata)
+        .expect("valid metadata")
+        .into()
+}
+
+fn parse_h256(hash: &str) -> H256 {
+    hash.parse().expect("valid hash")
+}
+
+/// Build the signer payload for a `Balances::transfer_keep_alive` input with subxt
+fn subxt_signer_payload(input: &GoldenVectorInput) -> Vec<u8> {
+    let GoldenVectorInput::SubstrateExtrinsic {
+        call,
+        args,
+        era,
+        nonce,
+        tip,
+        spec_version,
+        transaction_version,
+        genesis_hash,
+        block_hash,
+        ..
+    } = input
+    else {
+        panic!("expected a Substrate extrinsic input");
+    };
+
+    let [SubstrateValue::AccountId(dest), SubstrateValue::Balance(value)] = args.as_slice() else {
+        panic!("expected transfer arguments");
+    };
+    let dest: AccountId32 = dest.parse().expect("valid SS58 address");
+
+    let client = OfflineClient::<PolkadotConfig>::new(
+        parse_h256(genesis_hash),
+        RuntimeVersion {
+            spec_version: *spec_version,
+            transaction_version: *transaction_version,
+        },
+        polkadot_metadata(),
+    );
+
+    let params = PolkadotExtrinsicParamsBuilder::new()
+        .nonce(*nonce)
+        .tip(*tip);
+    let params = match era {
+        // subxt derives the phase from the block number the era starts at
+        Some(SubstrateEra { period, phase }) => {
+            params.mortal_from_unchecked(*period, *phase, parse_h256(block_hash))
+        }
+        None => params.immortal(),
+    };
+
+    let tx = subxt::dynamic::tx(
+        "Balances",
+        call.as_str(),
+        vec![
+            Value::unnamed_variant("Id", [Value::from_bytes(AsRef::<[u8; 32]>::as_ref(&dest))]),
+            Value::u128(*value),
+        ],
+    );
+
+    client
+        .tx()
+        .create_partial_offline(&tx, params.build())
+        .expect("subxt builds the transaction")
+        .signer_payload()
+}
+
+#[test]
+fn test_default_substrate_vector_matches_subxt() {
+    let vector = load_default_golden_vectors()
+        .vectors
+        .into_iter()
+        .find(|v| matches!(v.input, GoldenVectorInput::SubstrateExtrinsic { .. }))
+        .expect("default corpus has a Substrate vector");
+
+    let subxt_payload = subxt_signer_payload(&vector.input);
+
+    assert_eq!(
+        vector.expected_encoded,
+        format!("0x{}", hex::encode(&subxt_payload))
+    );
+    assert_eq!(
+        encode_golden_vector_input(&vector.input, &vector.encoding_version).unwrap(),
+        subxt_payload
+    );
+}
+
+#[test]
+fn test_core_encoder_matches_subxt_for_tip_and_immortal_era() {
+    let mut vector = load_default_golden_vectors()
+        .vectors
+        .into_iter()
+        .find(|v| matches!(v.input, GoldenVectorInput::SubstrateExtrinsic { .. }))
+        .expect("default corpus has a Substrate vector");
+    if let GoldenVectorInput::SubstrateExtrinsic {
+        era, nonce, tip, ..
+    } = &mut vector.input
+    {
+        *era = None;
+        *nonce = 42;
+        *tip = 1_000_000;
+    }
+
+    assert_eq!(
+        encode_golden_vector_input(&vector.input, &vector.encoding_version).unwrap(),
+        subxt_signer_payload(&vector.input)
+    );
+}
@@ -33,6 +33,35 @@ fn test_xcm_version_equality() {
     assert_eq!(XcmVersion::V4, XcmVersion::V4);
 }
 
+#[test]
+fn test_xcm_version_numbers_and_order() {
+    for version in [XcmVersion::V3, XcmVersion::V4, XcmVersion::V5] {
+        assert_eq!(XcmVersion::from_number(version.number()), Some(version));
+    }
+    assert_eq!(XcmVersion::from_number(6), None);
+    assert_eq!(XcmVersion::LATEST, XcmVersion::V5);
+    assert_eq!(XcmVersion::V5.variant_name(), "V5");
+    assert!(XcmVersion::V3 < XcmVersion::V4);
+    assert_eq!(XcmVersion::V5.min(XcmVersion::V4), XcmVersion::V4);
+}
+
+#[test]
+fn test_account_key20_location() {
+    let location = MultiLocation::account_key20([0xab; 20]);
+    assert_eq!(location.parents, 0);
+    assert_eq!(
+        location.interior,
+        vec![Junction::AccountId20 {
+            network: None,
+            key: [0xab; 20]
+        }]
+    );
+
+    let ethereum = MultiLocation::global_consensus(NetworkId::Ethereum { chain_id: 1 });
+    assert_eq!(ethereum.parents, 2);
+    assert!(!ethereum.is_parachain());
+}
+
 #[test]
 fn test_xcm_transfer_type_variants() {
     let reserve = XcmTransferType::ReserveTransfer;
@@ -742,10 +742,107 @@ pub enum TxStatus {
     Finalized,
     /// Transaction failed
     Failed,
+    /// Transaction was removed from its block by a chain reorganization
+    Reorged,
     /// Transaction status unknown
     Unknown,
 }
 
+/// Error raised by a pallet, resolved using runtime metadata
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleError {
+    /// Pallet name, e.g. `Balances`
+    pub pallet: String,
+    /// Pallet index in the runtime
+    pub pallet_index: u8,
+    /// Error variant name, e.g. `InsufficientBalance`
+    pub error: String,
+    /// Error variant index in the pallet
+    pub error_index: u8,
+    /// Documentation of the error variant
+    pub docs: String,
+}
+
+/// Decoded Substrate `DispatchError`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DispatchError {
+    /// Error raised by a pallet
+    Module(ModuleError),
+    /// Token error, e.g. `FundsUnavailable`
+    Token(String),
+    /// Arithmetic error, e.g. `Overflow`
+    Arithmetic(String),
+    /// Transactional layer error, e.g. `LimitReached`
+    Transactional(String),
+    /// Any other variant by name, e.g. `BadOrigin`
+    Other(String),
+}
+
+impl std::fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DispatchError::Module(module) => write!(f, "{}::{}", module.pallet, module.error),
+            DispatchError::Token(error) => write!(f, "Token::{}", error),
+            DispatchError::Arithmetic(error) => write!(f, "Arithmetic::{}", error),
+            DispatchError::Transactional(error) => write!(f, "Transactional::{}", error),
+            DispatchError::Other(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Why a transaction failed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxError {
+    /// Human-readable message
+    pub message: String,
+    /// Decoded dispatch error for failed Substrate extrinsics
+    pub dispatch: Option<DispatchError>,
+}
+
+impl TxError {
+    /// Create an error with only a message
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            dispatch: None,
+        }
+    }
+}
+
+impl std::fmt::Display for TxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<String> for TxError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for TxError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<DispatchError> for TxError {
+    fn from(error: DispatchError) -> Self {
+        let message = match &error {
+            DispatchError::Module(module) if !module.docs.is_empty() => {
+                format!("{}: {}", error, module.docs)
+            }
+            _ => error.to_string(),
+        };
+
+        Self {
+            message,
+            dispatch: Some(error),
+        }
+    }
+}
+
 /// Comprehensive transaction status information
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TransactionStatus {
@@ -763,8 +860,8 @@ pub struct TransactionStatus {
     pub effective_gas_price: Option<u128>,
     /// Number of confirmations
     pub confirmations: Option<u32>,
-    /// Error message (if status is Failed)
-    pub error: Option<String>,
+    /// Error (if status is Failed)
+    pub error: Option<TxError>,
 }
 
 impl TransactionStatus {
@@ -824,7 +921,7 @@ impl TransactionStatus {
     }
 
     /// Create a new failed transaction status
-    pub fn failed(hash: String, error: String) -> Self {
+    pub fn failed(hash: String, error: impl Into<TxError>) -> Self {
         Self {
             hash,
             status: TxStatus::Failed,
@@ -833,7 +930,24 @@ impl TransactionStatus {
             gas_used: None,
             effective_gas_price: None,
             confirmations: None,
-            error: Some(error),
+            error: Some(error.into()),
+        }
+    }
+
+    /// Create a new reorged transaction status
+    ///
+    /// `block_number` and `block_hash` identify the block the transaction
+    /// was removed from.
+    pub fn reorged(hash: String, block_number: u64, block_hash: String) -> Self {
+        Self {
+            hash,
+            status: TxStatus::Reorged,
+            block_number: Some(block_number),
+            block_hash: Some(block_hash),
+            gas_used: None,
+            effective_gas_price: None,
+            confirmations: None,
+            error: None,
         }
     }
 
@@ -952,6 +1066,46 @@ pub struct EventFilter {
     pub to_block: Option<u64>,
 }
 
+impl EventFilter {
+    /// Check whether an event name passes the `event_names` criterion.
+    ///
+    /// A filter entry matches the full name as well as its bare form, so `"Transfer"`
+    /// matches `"Balances.Transfer"` and `"Transfer(address,address,uint256)"`.
+    /// Comparison is case-sensitive.
+    ///
+    /// # Example
+    /// ```
+    /// use apex_sdk_types::EventFilter;
+    ///
+    /// let filter = EventFilter {
+    ///     event_names: Some(vec!["Transfer".to_string()]),
+    ///     addresses: None,
+    ///     from_block: None,
+    ///     to_block: None,
+    /// };
+    /// assert!(filter.matches_name("Balances.Transfer"));
+    /// assert!(!filter.matches_name("Balances.Deposit"));
+    /// ```
+    pub fn matches_name(&self, name: &str) -> bool {
+        let Some(names) = &self.event_names else {
+            return true;
+        };
+
+        let without_params = name.split('(').next().unwrap_or(name);
+        let bare = without_params.rsplit('.').next().unwrap_or(without_params);
+
+        names
+            .iter()
+            .any(|wanted| wanted == name || wanted == without_params || wanted == bare)
+    }
+
+    /// Check whether a block number falls inside the `from_block..=to_block` range.
+    pub fn matches_block(&self, block_number: u64) -> bool {
+        self.from_block.is_none_or(|from| block_number >= from)
+            && self.to_block.is_none_or(|to| block_number <= to)
+    }
+}
+
 /// Cross-chain transaction info
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossChainTransaction {
@@ -965,6 +1119,9 @@ pub struct CrossChainTransaction {
     pub source_tx_hash: Option<String>,
     /// Destination transaction hash
     pub destination_tx_hash: Option<String>,
+    /// Block the destination leg landed in, when known
+    #[serde(default)]
+    pub destination_block_hash: Option<String>,
     /// Transaction status
     pub status: TransactionStatus,
     /// Timestamp
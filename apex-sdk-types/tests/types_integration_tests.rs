@@ -1,6 +1,6 @@
 use apex_sdk_types::{
-    Address, Chain, ChainType, CrossChainTransaction, Event, EventFilter, TransactionStatus,
-    TxStatus, ValidationError,
+    Address, Chain, ChainType, CrossChainTransaction, DispatchError, Event, EventFilter,
+    ModuleError, TransactionStatus, TxError, TxStatus, ValidationError,
 };
 use proptest::prelude::*;
 use serde_json::json;
@@ -367,6 +367,40 @@ fn test_transaction_status_failed() {
     assert_eq!(status.block_number, None);
 }
 
+#[test]
+fn test_transaction_status_dispatch_error() {
+    let dispatch = DispatchError::Module(ModuleError {
+        pallet: "Balances".to_string(),
+        pallet_index: 5,
+        error: "InsufficientBalance".to_string(),
+        error_index: 2,
+        docs: "Balance too low to send value.".to_string(),
+    });
+    let status = TransactionStatus::failed("0x789".to_string(), dispatch.clone());
+
+    let error = status.error.unwrap();
+    assert_eq!(dispatch.to_string(), "Balances::InsufficientBalance");
+    assert_eq!(
+        error.to_string(),
+        "Balances::InsufficientBalance: Balance too low to send value."
+    );
+    assert_eq!(error.dispatch, Some(dispatch));
+
+    let arithmetic = TxError::from(DispatchError::Arithmetic("Overflow".to_string()));
+    assert_eq!(arithmetic.message, "Arithmetic::Overflow");
+    assert_eq!(TxError::from("Insufficient gas").dispatch, None);
+}
+
+#[test]
+fn test_transaction_status_reorged() {
+    let status = TransactionStatus::reorged("0xdef".to_string(), 42, "0xabc".to_string());
+
+    assert_eq!(status.status, TxStatus::Reorged);
+    assert_eq!(status.block_number, Some(42));
+    assert_eq!(status.block_hash, Some("0xabc".to_string()));
+    assert_eq!(status.confirmations, None);
+}
+
 #[test]
 fn test_transaction_status_unknown() {
     let status = TransactionStatus::unknown("0xabc".to_string());
@@ -1015,6 +1049,53 @@ fn test_event_filter_serde() {
     assert_eq!(filter.to_block, deserialized.to_block);
 }
 
+#[test]
+fn test_event_filter_matches_name() {
+    let filter = EventFilter {
+        event_names: Some(vec!["Transfer".to_string(), "System.Remarked".to_string()]),
+        addresses: None,
+        from_block: None,
+        to_block: None,
+    };
+
+    assert!(filter.matches_name("Transfer"));
+    assert!(filter.matches_name("Balances.Transfer"));
+    assert!(filter.matches_name("Transfer(address,address,uint256)"));
+    assert!(filter.matches_name("System.Remarked"));
+    assert!(!filter.matches_name("Approval"));
+    assert!(!filter.matches_name("Remarked"));
+
+    let unfiltered = EventFilter {
+        event_names: None,
+        addresses: None,
+        from_block: None,
+        to_block: None,
+    };
+    assert!(unfiltered.matches_name("Anything"));
+}
+
+#[test]
+fn test_event_filter_matches_block() {
+    let filter = EventFilter {
+        event_names: None,
+        addresses: None,
+        from_block: Some(100),
+        to_block: Some(200),
+    };
+
+    assert!(!filter.matches_block(99));
+    assert!(filter.matches_block(100));
+    assert!(filter.matches_block(200));
+    assert!(!filter.matches_block(201));
+
+    let open_ended = EventFilter {
+        from_block: Some(100),
+        to_block: None,
+        ..filter
+    };
+    assert!(open_ended.matches_block(u64::MAX));
+}
+
 // =============================================================================
 // CrossChainTransaction Tests
 // =============================================================================
@@ -1027,6 +1108,7 @@ fn test_cross_chain_transaction_creation() {
         destination_chain: Chain::Polkadot,
         source_tx_hash: Some("0xabc".to_string()),
         destination_tx_hash: Some("0xdef".to_string()),
+        destination_block_hash: None,
         status: TransactionStatus::confirmed(
             "0xabc".to_string(),
             1000,
@@ -1054,6 +1136,7 @@ fn test_cross_chain_transaction_pending() {
         destination_chain: Chain::Moonbeam,
         source_tx_hash: Some("0x111".to_string()),
         destination_tx_hash: None,
+        destination_block_hash: None,
         status: TransactionStatus::pending("0x111".to_string()),
         timestamp: 9876543210,
     };
@@ -1070,6 +1153,7 @@ fn test_cross_chain_transaction_clone() {
         destination_chain: Chain::Polygon,
         source_tx_hash: None,
         destination_tx_hash: None,
+        destination_block_hash: None,
         status: {
             let mut status = TransactionStatus::pending("0x789".to_string());
             status.status = TxStatus::InMempool;
@@ -1092,6 +1176,7 @@ fn test_cross_chain_transaction_serde() {
         destination_chain: Chain::Polkadot,
         source_tx_hash: Some("0xsrc".to_string()),
         destination_tx_hash: Some("0xdst".to_string()),
+        destination_block_hash: None,
         status: TransactionStatus::finalized(
             "0xsrc".to_string(),
             5000,
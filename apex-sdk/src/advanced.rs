@@ -1,6 +1,8 @@
 //! Advanced features and utilities.
 
 use crate::sdk::ApexSDK;
+use apex_sdk_core::NewHead;
+use apex_sdk_types::Event;
 use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -11,10 +13,30 @@ use tokio_util::sync::CancellationToken;
 pub struct BlockInfo {
     pub number: u64,
     pub hash: String,
-    pub timestamp: u64,
+    /// Block timestamp in seconds, if the chain reports one
+    pub timestamp: Option<u64>,
+    /// Parent block hash
+    pub parent_hash: String,
+    /// Whether this block replaces a previously emitted block at the same height
+    pub reorged: bool,
+}
+
+impl From<NewHead> for BlockInfo {
+    fn from(head: NewHead) -> Self {
+        Self {
+            number: head.number,
+            hash: head.hash,
+            timestamp: head.timestamp,
+            parent_hash: head.parent_hash,
+            reorged: head.reorged,
+        }
+    }
 }
 
 /// Block subscription for real-time updates
+///
+/// Created empty with [`BlockSubscription::new`] or fed from a chain with
+/// [`ApexSDK::subscribe_blocks`].
 pub struct BlockSubscription {
     receiver: broadcast::Receiver<BlockInfo>,
     cancellation_token: CancellationToken,
@@ -37,10 +59,21 @@ impl BlockSubscription {
     }
 
     /// Get the next block from the subscription
+    ///
+    /// Returns `None` once the subscription is stopped or its source has ended.
+    /// Blocks dropped because the receiver fell behind are skipped.
     pub async fn next(&mut self) -> Option<BlockInfo> {
-        tokio::select! {
-            result = self.receiver.recv() => result.ok(),
-            _ = self.cancellation_token.cancelled() => None,
+        loop {
+            tokio::select! {
+                result = self.receiver.recv() => match result {
+                    Ok(block) => return Some(block),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Block subscription lagged, skipped {} blocks", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                },
+                _ = self.cancellation_token.cancelled() => return None,
+            }
         }
     }
 
@@ -57,14 +90,17 @@ impl BlockSubscription {
 }
 
 /// Event subscription for blockchain events
+///
+/// Created empty with [`EventSubscription::new`] or fed from a chain with
+/// [`ApexSDK::subscribe_events`].
 pub struct EventSubscription {
-    receiver: broadcast::Receiver<String>,
+    receiver: broadcast::Receiver<Event>,
     cancellation_token: CancellationToken,
 }
 
 impl EventSubscription {
     /// Create a new event subscription with cancellation support
-    pub fn new() -> (broadcast::Sender<String>, CancellationToken, Self) {
+    pub fn new() -> (broadcast::Sender<Event>, CancellationToken, Self) {
         let (sender, receiver) = broadcast::channel(100);
         let cancellation_token = CancellationToken::new();
         let token_clone = cancellation_token.clone();
@@ -79,10 +115,21 @@ impl EventSubscription {
     }
 
     /// Get the next event from the subscription
-    pub async fn next(&mut self) -> Option<String> {
-        tokio::select! {
-            result = self.receiver.recv() => result.ok(),
-            _ = self.cancellation_token.cancelled() => None,
+    ///
+    /// Returns `None` once the subscription is stopped or its source has ended.
+    /// Events dropped because the receiver fell behind are skipped.
+    pub async fn next(&mut self) -> Option<Event> {
+        loop {
+            tokio::select! {
+                result = self.receiver.recv() => match result {
+                    Ok(event) => return Some(event),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Event subscription lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                },
+                _ = self.cancellation_token.cancelled() => return None,
+            }
         }
     }
 
@@ -370,7 +417,9 @@ mod tests {
             let _ = sender.send(BlockInfo {
                 number: 100,
                 hash: "0xabc123".to_string(),
-                timestamp: 1234567890,
+                timestamp: Some(1234567890),
+                parent_hash: "0xabc122".to_string(),
+                reorged: false,
             });
         });
 
@@ -404,16 +453,34 @@ mod tests {
 
         let send_task = tokio::spawn(async move {
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
-            let _ = sender.send("TestEvent".to_string());
+            let _ = sender.send(Event {
+                name: "TestEvent".to_string(),
+                data: serde_json::json!({ "value": 1 }),
+                block_number: Some(100),
+                tx_hash: None,
+                index: Some(0),
+            });
         });
 
         let event = subscription.next().await;
         assert!(event.is_some());
-        assert_eq!(event.unwrap(), "TestEvent");
+        let event = event.unwrap();
+        assert_eq!(event.name, "TestEvent");
+        assert_eq!(event.block_number, Some(100));
 
         send_task.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_event_subscription_ends_when_source_closes() {
+        let (sender, _cancellation_token, mut subscription) = EventSubscription::new();
+
+        drop(sender);
+
+        assert!(subscription.next().await.is_none());
+        assert!(!subscription.is_stopped());
+    }
+
     #[tokio::test]
     async fn test_subscription_multiple_events() {
         let (sender, _cancellation_token, mut subscription) = BlockSubscription::new();
@@ -424,7 +491,9 @@ mod tests {
                 let _ = sender.send(BlockInfo {
                     number: i,
                     hash: format!("0x{:x}", i),
-                    timestamp: 1000000 + i,
+                    timestamp: Some(1000000 + i),
+                    parent_hash: String::new(),
+                    reorged: false,
                 });
             }
         });
@@ -455,7 +524,9 @@ mod tests {
                     .send(BlockInfo {
                         number: i,
                         hash: format!("0x{:x}", i),
-                        timestamp: 1000000 + i,
+                        timestamp: Some(1000000 + i),
+                        parent_hash: String::new(),
+                        reorged: false,
                     })
                     .is_err()
                 {
@@ -487,7 +558,9 @@ mod tests {
             let _ = sender.send(BlockInfo {
                 number: 100,
                 hash: "0xabc".to_string(),
-                timestamp: 2000000,
+                timestamp: Some(2000000),
+                parent_hash: String::new(),
+                reorged: false,
             });
         });
 
@@ -495,7 +568,9 @@ mod tests {
             let _ = sender2.send(BlockInfo {
                 number: 200,
                 hash: "0xdef".to_string(),
-                timestamp: 3000000,
+                timestamp: Some(3000000),
+                parent_hash: String::new(),
+                reorged: false,
             });
         });
 
@@ -528,8 +603,14 @@ mod tests {
 
         drop(subscription);
 
-        let send_result = sender.send("test".to_string());
-        assert_eq!(send_result.err().unwrap().0, "test");
+        let send_result = sender.send(Event {
+            name: "test".to_string(),
+            data: serde_json::Value::Null,
+            block_number: None,
+            tx_hash: None,
+            index: None,
+        });
+        assert_eq!(send_result.err().unwrap().0.name, "test");
     }
 
     #[test]
@@ -1,10 +1,11 @@
 //! Builder pattern implementation for creating Apex SDK instances.
 
 use crate::{
+    cross_chain::CrossChainStore,
     error::{Error, Result},
     sdk::ApexSDK,
 };
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 #[cfg(feature = "substrate")]
 use apex_sdk_substrate::SubstrateAdapter;
@@ -43,11 +44,15 @@ pub struct ApexSDKBuilder {
     #[cfg(feature = "evm")]
     evm_endpoint: Option<String>,
 
+    #[cfg(feature = "evm")]
+    evm_ws_endpoint: Option<String>,
+
     #[cfg(feature = "evm")]
     evm_wallet: Option<apex_sdk_evm::wallet::Wallet>,
 
     timeout: Option<Duration>,
     config: Option<crate::sdk::SdkConfig>,
+    cross_chain_store: Option<Arc<dyn CrossChainStore>>,
 }
 
 impl ApexSDKBuilder {
@@ -96,6 +101,28 @@ impl ApexSDKBuilder {
         self
     }
 
+    /// Configure an EVM WebSocket endpoint used for block and log subscriptions.
+    ///
+    /// Best-block subscriptions use `newHeads` and event subscriptions use
+    /// `eth_subscribe("logs")` over it. Without it, or if a subscription
+    /// fails, both poll the HTTP endpoint instead. Finalized-block
+    /// subscriptions and event filters with a `to_block` always poll.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use apex_sdk::ApexSDKBuilder;
+    ///
+    /// let builder = ApexSDKBuilder::new()
+    ///     .with_evm_endpoint("https://mainnet.infura.io/v3/YOUR_KEY")
+    ///     .with_evm_ws_endpoint("wss://mainnet.infura.io/ws/v3/YOUR_KEY");
+    /// ```
+    #[cfg(feature = "evm")]
+    pub fn with_evm_ws_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.evm_ws_endpoint = Some(endpoint.into());
+        self
+    }
+
     /// Configure a Substrate wallet for signing transactions.
     ///
     /// # Example
@@ -196,6 +223,24 @@ impl ApexSDKBuilder {
         self.config = Some(config);
         self
     }
+    /// Persist cross-chain transaction progress in a custom store.
+    ///
+    /// Defaults to an [`InMemoryCrossChainStore`](crate::cross_chain::InMemoryCrossChainStore).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use apex_sdk::{ApexSDKBuilder, InMemoryCrossChainStore};
+    /// use std::sync::Arc;
+    ///
+    /// let builder = ApexSDKBuilder::new()
+    ///     .with_cross_chain_store(Arc::new(InMemoryCrossChainStore::new()));
+    /// ```
+    pub fn with_cross_chain_store(mut self, store: Arc<dyn CrossChainStore>) -> Self {
+        self.cross_chain_store = Some(store);
+        self
+    }
+
     /// Build the ApexSDK instance.
     ///
     /// # Errors
@@ -234,11 +279,13 @@ impl ApexSDKBuilder {
 
         #[cfg(feature = "evm")]
         let evm_adapter = if let Some(endpoint) = self.evm_endpoint {
-            Some(
-                EvmAdapter::connect(&endpoint)
-                    .await
-                    .map_err(|e| Error::Connection(e.to_string()))?,
-            )
+            let adapter = EvmAdapter::connect(&endpoint)
+                .await
+                .map_err(|e| Error::Connection(e.to_string()))?;
+            Some(match self.evm_ws_endpoint {
+                Some(ws_endpoint) => adapter.with_ws_endpoint(ws_endpoint),
+                None => adapter,
+            })
         } else {
             None
         };
@@ -282,6 +329,10 @@ impl ApexSDKBuilder {
             timeout,
             self.config.unwrap_or_default(),
         )
+        .map(|sdk| match self.cross_chain_store {
+            Some(store) => sdk.with_cross_chain_store(store),
+            None => sdk,
+        })
     }
 }
 
@@ -330,6 +381,16 @@ mod tests {
         let builder = ApexSDKBuilder::new().with_evm_endpoint(endpoint);
 
         assert_eq!(builder.evm_endpoint, Some(endpoint.to_string()));
+        assert!(builder.evm_ws_endpoint.is_none());
+    }
+
+    #[cfg(feature = "evm")]
+    #[test]
+    fn test_builder_with_evm_ws_endpoint() {
+        let endpoint = "wss://mainnet.infura.io/ws/v3/YOUR_KEY";
+        let builder = ApexSDKBuilder::new().with_evm_ws_endpoint(endpoint);
+
+        assert_eq!(builder.evm_ws_endpoint, Some(endpoint.to_string()));
     }
 
     #[test]
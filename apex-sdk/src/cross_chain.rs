@@ -0,0 +1,494 @@
+//! Cross-chain transaction execution and tracking
+//!
+//! [`ApexSDK::execute_cross_chain`](crate::ApexSDK::execute_cross_chain) submits
+//! the source leg of a transfer and then follows it to the destination chain:
+//! - XCM transfers are matched against `MessageQueue.Processed` and
+//!   `XcmpQueue.Success`/`Fail` events on the destination chain, and
+//!   `destination_block_hash` is set to the block that processed the message
+//! - Other transfers (such as EVM token bridges) go through a pluggable [`Bridge`]
+//!
+//! Every change to the [`CrossChainTransaction`] is saved to a
+//! [`CrossChainStore`] and published to the returned [`CrossChainHandle`].
+
+use crate::{error::Result, transaction::Transaction, types::Chain};
+use apex_sdk_types::{CrossChainTransaction, TransactionStatus, TxStatus};
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::{watch, RwLock};
+
+#[cfg(feature = "substrate")]
+use apex_sdk_substrate::{MultiLocation, SubstrateAdapter, XcmTransferType};
+
+/// Default time to wait for the destination leg
+pub const DEFAULT_DELIVERY_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Default interval between bridge status polls
+pub const DEFAULT_BRIDGE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// State of the destination leg as reported by a bridge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DestinationLeg {
+    /// Not delivered yet
+    Pending,
+    /// Delivered by the given destination transaction
+    Delivered {
+        /// Destination transaction hash
+        tx_hash: String,
+    },
+    /// The bridge gave up on the transfer
+    Failed {
+        /// Reason reported by the bridge
+        reason: String,
+    },
+}
+
+/// A bridge that carries transfers between chains
+///
+/// Implementations submit the source leg with their own signer and report
+/// when the destination leg lands, e.g. by querying a bridge relayer API.
+#[async_trait]
+pub trait Bridge: Send + Sync {
+    /// Bridge name used in logs
+    fn name(&self) -> &str;
+
+    /// Submit the source leg, returning the source transaction hash
+    async fn submit(&self, transaction: &Transaction) -> Result<String>;
+
+    /// Check whether the destination leg of a submitted transfer has landed
+    async fn poll_destination(&self, transfer: &CrossChainTransaction) -> Result<DestinationLeg>;
+}
+
+/// Persistence for cross-chain transaction progress
+#[async_trait]
+pub trait CrossChainStore: Send + Sync {
+    /// Insert or replace a cross-chain transaction
+    async fn save(&self, transfer: &CrossChainTransaction) -> Result<()>;
+
+    /// Load a cross-chain transaction by ID
+    async fn load(&self, id: &str) -> Result<Option<CrossChainTransaction>>;
+
+    /// List all stored cross-chain transactions
+    async fn list(&self) -> Result<Vec<CrossChainTransaction>>;
+}
+
+/// In-memory [`CrossChainStore`], used by default
+#[derive(Debug, Default)]
+pub struct InMemoryCrossChainStore {
+    transfers: RwLock<HashMap<String, CrossChainTransaction>>,
+}
+
+impl InMemoryCrossChainStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CrossChainStore for InMemoryCrossChainStore {
+    async fn save(&self, transfer: &CrossChainTransaction) -> Result<()> {
+        self.transfers
+            .write()
+            .await
+            .insert(transfer.id.clone(), transfer.clone());
+        Ok(())
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<CrossChainTransaction>> {
+        Ok(self.transfers.read().await.get(id).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<CrossChainTransaction>> {
+        let mut transfers: Vec<_> = self.transfers.read().await.values().cloned().collect();
+        transfers.sort_by_key(|t| t.timestamp);
+        Ok(transfers)
+    }
+}
+
+/// How a cross-chain transaction reaches its destination
+#[derive(Clone)]
+pub enum CrossChainTransport {
+    /// XCM transfer from the configured Substrate chain
+    #[cfg(feature = "substrate")]
+    Xcm {
+        /// Destination location relative to the source chain
+        destination: MultiLocation,
+        /// Reserve transfer or teleport
+        transfer_type: XcmTransferType,
+        /// Connection to the destination chain, used to observe delivery
+        destination_adapter: Arc<SubstrateAdapter>,
+    },
+    /// Transfer through a bridge
+    Bridge(Arc<dyn Bridge>),
+}
+
+/// Route of a cross-chain transaction
+#[derive(Clone)]
+pub struct CrossChainRoute {
+    /// Chain the source leg is submitted to
+    pub source_chain: Chain,
+    /// How the transfer reaches the destination
+    pub transport: CrossChainTransport,
+    /// How long to wait for the destination leg
+    pub delivery_timeout: Duration,
+    /// Interval between bridge status polls
+    pub poll_interval: Duration,
+    /// Connection to the source chain of an XCM transfer
+    ///
+    /// Defaults to the SDK's Substrate adapter, which must then serve
+    /// `source_chain`.
+    #[cfg(feature = "substrate")]
+    pub source_adapter: Option<Arc<SubstrateAdapter>>,
+}
+
+impl CrossChainRoute {
+    /// Route a transfer over XCM
+    #[cfg(feature = "substrate")]
+    pub fn xcm(
+        source_chain: Chain,
+        destination: MultiLocation,
+        transfer_type: XcmTransferType,
+        destination_adapter: Arc<SubstrateAdapter>,
+    ) -> Self {
+        Self::new(
+            source_chain,
+            CrossChainTransport::Xcm {
+                destination,
+                transfer_type,
+                destination_adapter,
+            },
+        )
+    }
+
+    /// Route a transfer through a bridge
+    pub fn bridge(source_chain: Chain, bridge: Arc<dyn Bridge>) -> Self {
+        Self::new(source_chain, CrossChainTransport::Bridge(bridge))
+    }
+
+    /// Submit an XCM transfer through this adapter instead of the SDK's
+    #[cfg(feature = "substrate")]
+    pub fn with_source_adapter(mut self, adapter: Arc<SubstrateAdapter>) -> Self {
+        self.source_adapter = Some(adapter);
+        self
+    }
+
+    /// Set how long to wait for the destination leg
+    pub fn with_delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.delivery_timeout = timeout;
+        self
+    }
+
+    /// Set the interval between bridge status polls
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    fn new(source_chain: Chain, transport: CrossChainTransport) -> Self {
+        Self {
+            source_chain,
+            transport,
+            delivery_timeout: DEFAULT_DELIVERY_TIMEOUT,
+            poll_interval: DEFAULT_BRIDGE_POLL_INTERVAL,
+            #[cfg(feature = "substrate")]
+            source_adapter: None,
+        }
+    }
+}
+
+/// Handle to a cross-chain transaction being tracked
+///
+/// Tracking continues in the background if the handle is dropped; the
+/// latest state can then be read back from the [`CrossChainStore`].
+pub struct CrossChainHandle {
+    updates: watch::Receiver<CrossChainTransaction>,
+}
+
+impl CrossChainHandle {
+    /// Get the cross-chain transaction ID
+    pub fn id(&self) -> String {
+        self.updates.borrow().id.clone()
+    }
+
+    /// Get the latest state of the cross-chain transaction
+    pub fn current(&self) -> CrossChainTransaction {
+        self.updates.borrow().clone()
+    }
+
+    /// Wait until the destination leg has landed, failed or timed out
+    pub async fn wait(&mut self) -> CrossChainTransaction {
+        if let Ok(transfer) = self.updates.wait_for(is_settled).await {
+            return transfer.clone();
+        }
+        // Tracking stopped without settling; return the last known state
+        self.current()
+    }
+}
+
+/// Check whether a cross-chain transaction is no longer in progress
+pub fn is_settled(transfer: &CrossChainTransaction) -> bool {
+    transfer.status.status != TxStatus::Pending
+}
+
+/// Saves and publishes progress of a cross-chain transaction
+pub(crate) struct CrossChainTracker {
+    store: Arc<dyn CrossChainStore>,
+    sender: watch::Sender<CrossChainTransaction>,
+}
+
+impl CrossChainTracker {
+    /// Start tracking a new cross-chain transaction
+    pub(crate) async fn start(
+        store: Arc<dyn CrossChainStore>,
+        transfer: CrossChainTransaction,
+    ) -> Result<(Self, CrossChainHandle)> {
+        store.save(&transfer).await?;
+        let (sender, updates) = watch::channel(transfer);
+        Ok((Self { store, sender }, CrossChainHandle { updates }))
+    }
+
+    /// Get the current state
+    pub(crate) fn current(&self) -> CrossChainTransaction {
+        self.sender.borrow().clone()
+    }
+
+    /// Apply a change, then save and publish the new state
+    pub(crate) async fn update(&self, change: impl FnOnce(&mut CrossChainTransaction)) {
+        self.sender.send_modify(change);
+        let transfer = self.current();
+        if let Err(e) = self.store.save(&transfer).await {
+            tracing::warn!(
+                "Failed to save cross-chain transaction {}: {}",
+                transfer.id,
+                e
+            );
+        }
+    }
+
+    /// Record the source leg
+    pub(crate) async fn source_submitted(&self, tx_hash: &str) {
+        self.update(|transfer| {
+            transfer.source_tx_hash = Some(tx_hash.to_string());
+            transfer.status = TransactionStatus::pending(tx_hash.to_string());
+        })
+        .await;
+    }
+
+    /// Record the destination leg
+    pub(crate) async fn delivered(&self, destination_tx_hash: String, status: TransactionStatus) {
+        self.update(|transfer| {
+            transfer.destination_tx_hash = Some(destination_tx_hash);
+            transfer.status = status;
+        })
+        .await;
+    }
+
+    /// Record the destination block of an XCM message
+    ///
+    /// XCM messages are processed by the destination runtime rather than by
+    /// an extrinsic, so there is no destination transaction hash.
+    #[cfg(feature = "substrate")]
+    pub(crate) async fn processed_in(&self, block_hash: String, status: Option<TransactionStatus>) {
+        self.update(|transfer| {
+            transfer.destination_block_hash = Some(block_hash);
+            if let Some(status) = status {
+                transfer.status = status;
+            }
+        })
+        .await;
+    }
+
+    /// Record a failed transfer
+    pub(crate) async fn failed(&self, error: impl Into<String>) {
+        let error = error.into();
+        self.update(|transfer| {
+            transfer.status = TransactionStatus::failed(source_hash(transfer), error);
+        })
+        .await;
+    }
+
+    /// Record that the outcome could not be determined
+    pub(crate) async fn unresolved(&self, error: impl Into<String>) {
+        let error = error.into();
+        self.update(|transfer| {
+            transfer.status = TransactionStatus {
+                error: Some(error.into()),
+                ..TransactionStatus::unknown(source_hash(transfer))
+            };
+        })
+        .await;
+    }
+}
+
+/// Source transaction hash, or an empty string before submission
+pub(crate) fn source_hash(transfer: &CrossChainTransaction) -> String {
+    transfer.source_tx_hash.clone().unwrap_or_default()
+}
+
+/// Follow a bridge transfer until its destination leg is reported
+pub(crate) async fn track_bridge(
+    tracker: CrossChainTracker,
+    bridge: Arc<dyn Bridge>,
+    route: CrossChainRoute,
+) {
+    let poll = async {
+        loop {
+            match bridge.poll_destination(&tracker.current()).await {
+                Ok(DestinationLeg::Pending) => {}
+                Ok(leg) => return leg,
+                Err(e) => tracing::debug!("{} bridge status query failed: {}", bridge.name(), e),
+            }
+            tokio::time::sleep(route.poll_interval).await;
+        }
+    };
+
+    match tokio::time::timeout(route.delivery_timeout, poll).await {
+        Ok(DestinationLeg::Delivered { tx_hash }) => {
+            let status = TransactionStatus {
+                status: TxStatus::Confirmed,
+                ..TransactionStatus::pending(source_hash(&tracker.current()))
+            };
+            tracker.delivered(tx_hash, status).await;
+        }
+        Ok(DestinationLeg::Failed { reason }) => tracker.failed(reason).await,
+        // The poll loop only returns once the leg has settled
+        Ok(DestinationLeg::Pending) => {}
+        Err(_) => {
+            tracker
+                .unresolved(format!(
+                    "{} bridge did not report delivery within {:?}",
+                    bridge.name(),
+                    route.delivery_timeout
+                ))
+                .await
+        }
+    }
+}
+
+/// Follow an XCM message until the destination chain processes it
+#[cfg(feature = "substrate")]
+pub(crate) async fn track_xcm(
+    tracker: CrossChainTracker,
+    destination_adapter: Arc<SubstrateAdapter>,
+    message_id: [u8; 32],
+    from_block: u64,
+    delivery_timeout: Duration,
+) {
+    let watcher = apex_sdk_substrate::XcmMessageWatcher::new(destination_adapter.client().clone());
+
+    match tokio::time::timeout(
+        delivery_timeout,
+        watcher.wait_for_processed(&message_id, Some(from_block)),
+    )
+    .await
+    {
+        Ok(Ok(outcome)) if outcome.success => {
+            let status = TransactionStatus::finalized(
+                source_hash(&tracker.current()),
+                outcome.block_number,
+                outcome.block_hash.clone(),
+                None,
+                None,
+                None,
+            );
+            tracker.processed_in(outcome.block_hash, Some(status)).await;
+        }
+        Ok(Ok(outcome)) => {
+            tracker.processed_in(outcome.block_hash.clone(), None).await;
+            tracker
+                .failed(format!(
+                    "XCM message failed on the destination in block {}: {}",
+                    outcome.block_number,
+                    outcome.error.as_deref().unwrap_or("XCM execution failed")
+                ))
+                .await;
+        }
+        Ok(Err(e)) => {
+            tracker
+                .unresolved(format!("Failed to follow XCM message: {}", e))
+                .await
+        }
+        Err(_) => {
+            tracker
+                .unresolved(format!(
+                    "XCM message was not processed within {:?}",
+                    delivery_timeout
+                ))
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(id: &str, timestamp: u64) -> CrossChainTransaction {
+        CrossChainTransaction {
+            id: id.to_string(),
+            source_chain: Chain::Polkadot,
+            destination_chain: Chain::Moonbeam,
+            source_tx_hash: None,
+            destination_tx_hash: None,
+            destination_block_hash: None,
+            status: TransactionStatus::pending(String::new()),
+            timestamp,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store() {
+        let store = InMemoryCrossChainStore::new();
+        store.save(&transfer("b", 2)).await.unwrap();
+        store.save(&transfer("a", 1)).await.unwrap();
+
+        let mut updated = transfer("b", 2);
+        updated.source_tx_hash = Some("0x01".to_string());
+        store.save(&updated).await.unwrap();
+
+        let loaded = store.load("b").await.unwrap().unwrap();
+        assert_eq!(loaded.source_tx_hash, Some("0x01".to_string()));
+        assert!(store.load("c").await.unwrap().is_none());
+
+        let ids: Vec<_> = store
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_tracker_saves_and_publishes_progress() {
+        let store = Arc::new(InMemoryCrossChainStore::new());
+        let (tracker, mut handle) = CrossChainTracker::start(store.clone(), transfer("t", 1))
+            .await
+            .unwrap();
+
+        tracker.source_submitted("0xsrc").await;
+        assert!(!is_settled(&handle.current()));
+        assert_eq!(handle.current().source_tx_hash, Some("0xsrc".to_string()));
+
+        tracker.failed("rejected").await;
+        let settled = handle.wait().await;
+        assert_eq!(settled.status.status, TxStatus::Failed);
+        assert_eq!(settled.status.hash, "0xsrc");
+
+        let stored = store.load("t").await.unwrap().unwrap();
+        assert_eq!(stored.status.error, Some("rejected".into()));
+    }
+
+    #[tokio::test]
+    async fn test_handle_wait_returns_when_tracker_dropped() {
+        let store = Arc::new(InMemoryCrossChainStore::new());
+        let (tracker, mut handle) = CrossChainTracker::start(store, transfer("t", 1))
+            .await
+            .unwrap();
+        drop(tracker);
+
+        let current = handle.wait().await;
+        assert_eq!(current.status.status, TxStatus::Pending);
+    }
+}
@@ -28,6 +28,7 @@
 
 pub mod advanced;
 pub mod builder;
+pub mod cross_chain;
 pub mod error;
 pub mod error_recovery;
 pub mod performance;
@@ -43,6 +44,9 @@ pub use advanced::{
     BlockInfo, BlockSubscription, EventSubscription, ParallelExecutor, TransactionBatch,
 };
 pub use builder::ApexSDKBuilder;
+pub use cross_chain::{
+    Bridge, CrossChainHandle, CrossChainRoute, CrossChainStore, InMemoryCrossChainStore,
+};
 pub use error::{Error, Result};
 pub use error_recovery::{with_retry, CircuitBreaker, RetryConfig};
 pub use performance::{
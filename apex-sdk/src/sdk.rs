@@ -1,13 +1,19 @@
 //! Main SDK interface providing unified access to both Substrate and EVM blockchains.
 
+use crate::advanced::{BlockSubscription, EventSubscription};
+use crate::cross_chain::{
+    CrossChainHandle, CrossChainRoute, CrossChainStore, CrossChainTracker, CrossChainTransport,
+    InMemoryCrossChainStore,
+};
 use crate::{
     error::{Error, Result},
     transaction::{Transaction, TransactionResult},
     types::{Address, Chain},
 };
-use apex_sdk_core::ChainAdapter;
-use apex_sdk_types::TxStatus;
-use std::{sync::Arc, time::Duration};
+use apex_sdk_core::{BlockSubscriptionMode, ChainAdapter, NewHead};
+use apex_sdk_types::{ChainType, CrossChainTransaction, EventFilter, TransactionStatus, TxStatus};
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio_util::sync::CancellationToken;
 
 #[cfg(feature = "evm")]
 /// Transaction confirmation strategy
@@ -86,6 +92,7 @@ pub struct ApexSDK {
 
     timeout: Duration,
     config: SdkConfig,
+    cross_chain_store: Arc<dyn CrossChainStore>,
 }
 
 impl ApexSDK {
@@ -136,9 +143,16 @@ impl ApexSDK {
 
             timeout,
             config,
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         })
     }
 
+    /// Use a custom store for cross-chain transaction progress.
+    pub(crate) fn with_cross_chain_store(mut self, store: Arc<dyn CrossChainStore>) -> Self {
+        self.cross_chain_store = store;
+        self
+    }
+
     /// Execute a transaction on the appropriate blockchain.
     pub async fn execute(&self, transaction: Transaction) -> Result<TransactionResult> {
         match transaction.destination_chain() {
@@ -387,6 +401,13 @@ impl ApexSDK {
                         tracing::debug!("Transaction {} still pending, waiting...", tx_hash);
                         tokio::time::sleep(Duration::from_secs(2)).await;
                     }
+                    TxStatus::Reorged => {
+                        tracing::warn!(
+                            "Transaction {} was removed by a reorg, waiting for re-inclusion...",
+                            tx_hash
+                        );
+                        tokio::time::sleep(Duration::from_secs(2)).await;
+                    }
                     TxStatus::Unknown => {
                         tracing::debug!("Transaction {} status unknown, waiting...", tx_hash);
                         tokio::time::sleep(Duration::from_secs(2)).await;
@@ -400,6 +421,354 @@ impl ApexSDK {
         }
     }
 
+    /// Subscribe to new blocks on the given chain.
+    ///
+    /// Substrate chains use subxt's best or finalized block streams. EVM chains
+    /// use `eth_subscribe("newHeads")` when a WebSocket endpoint is configured
+    /// and poll over HTTP otherwise. Blocks skipped by the source are
+    /// backfilled, and blocks re-emitted after a reorg have `reorged` set.
+    /// Hybrid chains use the Substrate adapter when one is configured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use apex_sdk::{ApexSDK, types::Chain};
+    /// # use apex_sdk::core::BlockSubscriptionMode;
+    /// # async fn example(sdk: ApexSDK) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut blocks = sdk
+    ///     .subscribe_blocks(&Chain::Polkadot, BlockSubscriptionMode::Finalized)
+    ///     .await?;
+    ///
+    /// while let Some(block) = blocks.next().await {
+    ///     println!("Block #{} {}", block.number, block.hash);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_blocks(
+        &self,
+        chain: &Chain,
+        mode: BlockSubscriptionMode,
+    ) -> Result<BlockSubscription> {
+        let (sender, cancellation_token, subscription) = BlockSubscription::new();
+        let on_block = move |head: NewHead| sender.send(head.into()).is_ok();
+
+        #[cfg(feature = "substrate")]
+        if matches!(chain.chain_type(), ChainType::Substrate | ChainType::Hybrid) {
+            if let Some(adapter) = &self.substrate_adapter {
+                let watcher = adapter.block_watcher();
+                spawn_subscription_feed("Block", chain, cancellation_token, async move {
+                    watcher.watch(mode, on_block).await
+                });
+                return Ok(subscription);
+            }
+        }
+
+        #[cfg(feature = "evm")]
+        if matches!(chain.chain_type(), ChainType::Evm | ChainType::Hybrid) {
+            if let Some(adapter) = &self.evm_adapter {
+                let watcher = adapter.block_watcher();
+                spawn_subscription_feed("Block", chain, cancellation_token, async move {
+                    watcher.watch(mode, on_block).await
+                });
+                return Ok(subscription);
+            }
+        }
+
+        Err(Error::UnsupportedChain(format!(
+            "No adapter configured for {} blocks",
+            chain.name()
+        )))
+    }
+
+    /// Subscribe to events matching a filter on the given chain.
+    ///
+    /// Substrate chains stream `System.Events` from finalized blocks. EVM chains
+    /// subscribe with `eth_subscribe("logs")` over the WebSocket endpoint set
+    /// with [`ApexSDKBuilder::with_evm_ws_endpoint`](crate::ApexSDKBuilder::with_evm_ws_endpoint),
+    /// falling back to `eth_getLogs` polling without one, if the subscription
+    /// fails, or when `filter.to_block` is set. Hybrid chains use the Substrate adapter when one is
+    /// configured. The subscription ends once `filter.to_block` has been
+    /// processed, when it is stopped, or if the underlying source fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use apex_sdk::{ApexSDK, types::Chain};
+    /// # use apex_sdk_types::EventFilter;
+    /// # async fn example(sdk: ApexSDK) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = EventFilter {
+    ///     event_names: Some(vec!["Balances.Transfer".to_string()]),
+    ///     addresses: None,
+    ///     from_block: None,
+    ///     to_block: None,
+    /// };
+    ///
+    /// let mut events = sdk.subscribe_events(&Chain::Polkadot, filter).await?;
+    /// while let Some(event) = events.next().await {
+    ///     println!("{} in block {:?}: {}", event.name, event.block_number, event.data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_events(
+        &self,
+        chain: &Chain,
+        filter: EventFilter,
+    ) -> Result<EventSubscription> {
+        let (sender, cancellation_token, subscription) = EventSubscription::new();
+        let on_event = move |event| sender.send(event).is_ok();
+
+        #[cfg(feature = "substrate")]
+        if matches!(chain.chain_type(), ChainType::Substrate | ChainType::Hybrid) {
+            if let Some(adapter) = &self.substrate_adapter {
+                let watcher = adapter.event_watcher();
+                spawn_subscription_feed("Event", chain, cancellation_token, async move {
+                    watcher.watch(filter, on_event).await
+                });
+                return Ok(subscription);
+            }
+        }
+
+        #[cfg(feature = "evm")]
+        if matches!(chain.chain_type(), ChainType::Evm | ChainType::Hybrid) {
+            if let Some(adapter) = &self.evm_adapter {
+                let watcher = adapter.event_watcher();
+                spawn_subscription_feed("Event", chain, cancellation_token, async move {
+                    watcher.watch(filter, on_event).await
+                });
+                return Ok(subscription);
+            }
+        }
+
+        Err(Error::UnsupportedChain(format!(
+            "No adapter configured for {} events",
+            chain.name()
+        )))
+    }
+
+    /// Execute a cross-chain transaction and track it to the destination chain.
+    ///
+    /// Submits the source leg on `route.source_chain`, then follows the
+    /// transfer in the background until the destination leg lands, fails or
+    /// `route.delivery_timeout` passes. XCM routes match the message on the
+    /// destination chain through `route`'s destination adapter; bridge routes
+    /// poll the [`Bridge`](crate::cross_chain::Bridge). Progress is saved to the
+    /// configured [`CrossChainStore`] under the handle's ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use apex_sdk::{ApexSDK, types::Chain};
+    /// # use apex_sdk::cross_chain::CrossChainRoute;
+    /// # use apex_sdk::substrate::{MultiLocation, SubstrateAdapter, XcmTransferType};
+    /// # use std::sync::Arc;
+    /// # async fn example(sdk: ApexSDK) -> Result<(), Box<dyn std::error::Error>> {
+    /// let destination = Arc::new(SubstrateAdapter::connect("wss://wss.api.moonbeam.network").await?);
+    /// let route = CrossChainRoute::xcm(
+    ///     Chain::Polkadot,
+    ///     MultiLocation::parachain(2004),
+    ///     XcmTransferType::LimitedReserveTransfer,
+    ///     destination,
+    /// );
+    ///
+    /// let tx = sdk.transaction()
+    ///     .from_substrate_account("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY")
+    ///     .to_substrate_account("5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty")
+    ///     .amount(1_000_000_000)
+    ///     .chain(Chain::Moonbeam)
+    ///     .build()?;
+    ///
+    /// let mut handle = sdk.execute_cross_chain(tx, route).await?;
+    /// let transfer = handle.wait().await;
+    /// println!("Processed in {:?}: {:?}", transfer.destination_block_hash, transfer.status.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_cross_chain(
+        &self,
+        transaction: Transaction,
+        route: CrossChainRoute,
+    ) -> Result<CrossChainHandle> {
+        let transfer = CrossChainTransaction {
+            id: cross_chain_id(&transaction),
+            source_chain: route.source_chain.clone(),
+            destination_chain: transaction.destination_chain(),
+            source_tx_hash: None,
+            destination_tx_hash: None,
+            destination_block_hash: None,
+            status: TransactionStatus::pending(String::new()),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        };
+
+        tracing::info!(
+            "Executing cross-chain transaction {} from {} to {}",
+            transfer.id,
+            transfer.source_chain.name(),
+            transfer.destination_chain.name()
+        );
+
+        let (tracker, handle) =
+            CrossChainTracker::start(self.cross_chain_store.clone(), transfer).await?;
+
+        match &route.transport {
+            #[cfg(feature = "substrate")]
+            CrossChainTransport::Xcm {
+                destination,
+                transfer_type,
+                destination_adapter,
+            } => {
+                // Note the destination head first so that delivery cannot be missed
+                let from_block = destination_adapter
+                    .client()
+                    .blocks()
+                    .at_latest()
+                    .await
+                    .map_err(|e| {
+                        Error::Connection(format!("Failed to query destination chain: {}", e))
+                    })?
+                    .number() as u64;
+
+                let submission = match self
+                    .submit_xcm_leg(&route, &transaction, destination.clone(), *transfer_type)
+                    .await
+                {
+                    Ok(submission) => submission,
+                    Err(e) => {
+                        tracker.failed(e.to_string()).await;
+                        return Err(e);
+                    }
+                };
+                tracker.source_submitted(&submission.tx_hash).await;
+
+                match submission.message_id {
+                    Some(message_id) => {
+                        tokio::spawn(crate::cross_chain::track_xcm(
+                            tracker,
+                            destination_adapter.clone(),
+                            message_id,
+                            from_block,
+                            route.delivery_timeout,
+                        ));
+                    }
+                    None => {
+                        tracker
+                            .unresolved("Source extrinsic emitted no XCM Sent event")
+                            .await
+                    }
+                }
+            }
+            CrossChainTransport::Bridge(bridge) => {
+                let tx_hash = match bridge.submit(&transaction).await {
+                    Ok(tx_hash) => tx_hash,
+                    Err(e) => {
+                        tracker.failed(e.to_string()).await;
+                        return Err(e);
+                    }
+                };
+                tracing::info!("{} bridge accepted transfer: {}", bridge.name(), tx_hash);
+                tracker.source_submitted(&tx_hash).await;
+
+                tokio::spawn(crate::cross_chain::track_bridge(
+                    tracker,
+                    bridge.clone(),
+                    route.clone(),
+                ));
+            }
+        }
+
+        Ok(handle)
+    }
+
+    /// Load a cross-chain transaction from the configured store.
+    pub async fn get_cross_chain_transaction(
+        &self,
+        id: &str,
+    ) -> Result<Option<CrossChainTransaction>> {
+        self.cross_chain_store.load(id).await
+    }
+
+    /// Resolve the adapter that submits an XCM route's source leg
+    ///
+    /// The route's own source adapter wins. Otherwise the SDK's Substrate
+    /// adapter is used if it can serve `route.source_chain`.
+    #[cfg(feature = "substrate")]
+    fn xcm_source_adapter(&self, route: &CrossChainRoute) -> Result<Arc<SubstrateAdapter>> {
+        if let Some(adapter) = &route.source_adapter {
+            return Ok(adapter.clone());
+        }
+
+        let chain = &route.source_chain;
+        if chain.chain_type() == ChainType::Evm {
+            return Err(Error::UnsupportedChain(format!(
+                "XCM transfers cannot start on EVM chain {}",
+                chain.name()
+            )));
+        }
+
+        let adapter = self.substrate_adapter.as_ref().ok_or_else(|| {
+            Error::UnsupportedChain(format!(
+                "Substrate adapter not configured for {}",
+                chain.name()
+            ))
+        })?;
+
+        // Adapters connected without a known chain name are trusted as-is
+        match Chain::from_str_case_insensitive(adapter.chain_name()) {
+            Some(connected) if connected != *chain => Err(Error::UnsupportedChain(format!(
+                "Substrate adapter is connected to {}, not {}; set a source adapter on the route",
+                connected.name(),
+                chain.name()
+            ))),
+            _ => Ok(adapter.clone()),
+        }
+    }
+
+    /// Submit the source leg of an XCM transfer
+    #[cfg(feature = "substrate")]
+    async fn submit_xcm_leg(
+        &self,
+        route: &CrossChainRoute,
+        transaction: &Transaction,
+        destination: apex_sdk_substrate::MultiLocation,
+        transfer_type: apex_sdk_substrate::XcmTransferType,
+    ) -> Result<apex_sdk_substrate::XcmSubmission> {
+        use sp_core::crypto::{AccountId32, Ss58Codec};
+
+        let adapter = self.xcm_source_adapter(route)?;
+        let wallet = self.substrate_wallet.as_ref().ok_or_else(|| {
+            Error::Transaction("Substrate wallet not configured for XCM transfers".to_string())
+        })?;
+
+        let beneficiary = match &transaction.to {
+            Address::Substrate(addr) => AccountId32::from_ss58check(addr)
+                .map(|account| {
+                    apex_sdk_substrate::MultiLocation::account(*AsRef::<[u8; 32]>::as_ref(&account))
+                })
+                .map_err(|e| Error::InvalidAddress(format!("{}: {:?}", addr, e)))?,
+            // EVM accounts on the destination (e.g. Moonbeam) use AccountKey20
+            Address::Evm(addr) => hex::decode(addr.trim_start_matches("0x"))
+                .ok()
+                .and_then(|bytes| <[u8; 20]>::try_from(bytes).ok())
+                .map(apex_sdk_substrate::MultiLocation::account_key20)
+                .ok_or_else(|| Error::InvalidAddress(addr.clone()))?,
+        };
+
+        apex_sdk_substrate::XcmExecutor::new(adapter.client().clone())
+            .transfer(
+                wallet,
+                transfer_type,
+                destination,
+                beneficiary,
+                vec![apex_sdk_substrate::XcmAsset::native(transaction.amount)],
+            )
+            .await
+            .map_err(|e| Error::Transaction(format!("XCM transfer failed: {}", e)))
+    }
+
     #[cfg(feature = "substrate")]
     async fn execute_substrate_transaction(
         &self,
@@ -709,6 +1078,47 @@ struct ReceiptInfo {
     status: bool,
 }
 
+/// Derive a unique ID for a cross-chain transaction
+fn cross_chain_id(transaction: &Transaction) -> String {
+    use sha3::{Digest, Keccak256};
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(transaction.hash().as_bytes());
+    hasher.update(nanos.to_be_bytes());
+    format!("0x{:x}", hasher.finalize())
+}
+
+/// Drive a chain watcher until it finishes or the subscription is cancelled
+#[cfg(any(feature = "substrate", feature = "evm"))]
+fn spawn_subscription_feed<F, E>(
+    kind: &'static str,
+    chain: &Chain,
+    cancellation_token: CancellationToken,
+    watch: F,
+) where
+    F: Future<Output = std::result::Result<(), E>> + Send + 'static,
+    E: std::fmt::Display,
+{
+    let chain_name = chain.name().to_string();
+    tokio::spawn(async move {
+        tokio::select! {
+            result = watch => {
+                if let Err(e) = result {
+                    tracing::error!("{} subscription on {} failed: {}", kind, chain_name, e);
+                }
+            }
+            _ = cancellation_token.cancelled() => {
+                tracing::debug!("{} subscription on {} cancelled", kind, chain_name);
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -763,6 +1173,7 @@ mod tests {
             #[cfg(feature = "evm")]
             evm_wallet: None,
             timeout: Duration::from_secs(30),
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         };
 
         assert!(!sdk.is_chain_supported(&Chain::Polkadot));
@@ -913,6 +1324,7 @@ mod tests {
             evm_adapter: None,
             evm_wallet: None,
             timeout: Duration::from_secs(30),
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         };
 
         let result = sdk.evm();
@@ -934,6 +1346,7 @@ mod tests {
             #[cfg(feature = "evm")]
             evm_wallet: None,
             timeout: Duration::from_secs(30),
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         };
 
         let rt = tokio::runtime::Runtime::new().unwrap();
@@ -956,6 +1369,7 @@ mod tests {
             evm_adapter: None,
             evm_wallet: None,
             timeout: Duration::from_secs(30),
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         };
 
         let rt = tokio::runtime::Runtime::new().unwrap();
@@ -981,6 +1395,7 @@ mod tests {
             #[cfg(feature = "evm")]
             evm_wallet: None,
             timeout: Duration::from_secs(30),
+            cross_chain_store: Arc::new(InMemoryCrossChainStore::new()),
         };
 
         let from_addr = Address::evm("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEbD".to_string());
@@ -1034,4 +1449,145 @@ mod tests {
         let builder_type_name = std::any::type_name_of_val(&builder);
         assert!(builder_type_name.contains("ApexSDKBuilder"));
     }
+
+    /// Bridge that reports delivery after a number of polls
+    struct MockBridge {
+        polls: std::sync::atomic::AtomicU32,
+        deliver_after: u32,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::cross_chain::Bridge for MockBridge {
+        fn name(&self) -> &str {
+            "Mock"
+        }
+
+        async fn submit(&self, _transaction: &Transaction) -> Result<String> {
+            Ok("0xsource".to_string())
+        }
+
+        async fn poll_destination(
+            &self,
+            transfer: &CrossChainTransaction,
+        ) -> Result<crate::cross_chain::DestinationLeg> {
+            assert_eq!(transfer.source_tx_hash.as_deref(), Some("0xsource"));
+            let polls = self.polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(if polls + 1 >= self.deliver_after {
+                crate::cross_chain::DestinationLeg::Delivered {
+                    tx_hash: "0xdestination".to_string(),
+                }
+            } else {
+                crate::cross_chain::DestinationLeg::Pending
+            })
+        }
+    }
+
+    fn sdk_without_adapters(store: Arc<dyn CrossChainStore>) -> ApexSDK {
+        ApexSDK {
+            config: SdkConfig::default(),
+            #[cfg(feature = "substrate")]
+            substrate_adapter: None,
+            #[cfg(feature = "substrate")]
+            substrate_wallet: None,
+            #[cfg(feature = "evm")]
+            evm_adapter: None,
+            #[cfg(feature = "evm")]
+            evm_wallet: None,
+            timeout: Duration::from_secs(30),
+            cross_chain_store: store,
+        }
+    }
+
+    fn bridge_transfer() -> Transaction {
+        Transaction::builder()
+            .from_evm_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb7")
+            .to_substrate_account("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY")
+            .amount(1_000)
+            .chain(Chain::Polkadot)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_execute_cross_chain_through_bridge() {
+        let store = Arc::new(InMemoryCrossChainStore::new());
+        let sdk = sdk_without_adapters(store.clone());
+        let bridge = Arc::new(MockBridge {
+            polls: Default::default(),
+            deliver_after: 3,
+        });
+        let route = CrossChainRoute::bridge(Chain::Ethereum, bridge)
+            .with_poll_interval(Duration::from_millis(10));
+
+        let mut handle = sdk
+            .execute_cross_chain(bridge_transfer(), route)
+            .await
+            .unwrap();
+        assert_eq!(handle.current().source_tx_hash.as_deref(), Some("0xsource"));
+
+        let transfer = handle.wait().await;
+        assert_eq!(transfer.status.status, TxStatus::Confirmed);
+        assert_eq!(transfer.source_chain, Chain::Ethereum);
+        assert_eq!(transfer.destination_chain, Chain::Polkadot);
+        assert_eq!(
+            transfer.destination_tx_hash.as_deref(),
+            Some("0xdestination")
+        );
+
+        let stored = sdk
+            .get_cross_chain_transaction(&handle.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.destination_tx_hash.as_deref(), Some("0xdestination"));
+    }
+
+    #[cfg(feature = "substrate")]
+    #[test]
+    fn test_xcm_source_adapter_follows_route() {
+        let sdk = sdk_without_adapters(Arc::new(InMemoryCrossChainStore::new()));
+        let bridge = Arc::new(MockBridge {
+            polls: Default::default(),
+            deliver_after: 1,
+        });
+
+        let route = CrossChainRoute::bridge(Chain::Ethereum, bridge.clone());
+        assert!(matches!(
+            sdk.xcm_source_adapter(&route),
+            Err(Error::UnsupportedChain(_))
+        ));
+
+        let route = CrossChainRoute::bridge(Chain::Polkadot, bridge);
+        assert!(matches!(
+            sdk.xcm_source_adapter(&route),
+            Err(Error::UnsupportedChain(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_cross_chain_times_out() {
+        let sdk = sdk_without_adapters(Arc::new(InMemoryCrossChainStore::new()));
+        let bridge = Arc::new(MockBridge {
+            polls: Default::default(),
+            deliver_after: u32::MAX,
+        });
+        let route = CrossChainRoute::bridge(Chain::Ethereum, bridge)
+            .with_poll_interval(Duration::from_millis(10))
+            .with_delivery_timeout(Duration::from_millis(50));
+
+        let mut handle = sdk
+            .execute_cross_chain(bridge_transfer(), route)
+            .await
+            .unwrap();
+        let transfer = handle.wait().await;
+
+        assert_eq!(transfer.status.status, TxStatus::Unknown);
+        assert!(transfer.destination_tx_hash.is_none());
+        assert!(transfer
+            .status
+            .error
+            .unwrap()
+            .message
+            .contains("Mock bridge"));
+    }
 }
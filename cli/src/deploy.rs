@@ -11,6 +11,7 @@ pub async fn deploy_contract(
     chain: &str,
     endpoint: &str,
     account_name: Option<String>,
+    salt: Option<String>,
     dry_run: bool,
 ) -> Result<()> {
     // Determine chain type using centralized logic
@@ -20,9 +21,12 @@ pub async fn deploy_contract(
             .unwrap_or(false);
 
     if is_substrate {
+        if salt.is_some() {
+            anyhow::bail!("--salt is only supported for EVM deployments");
+        }
         deploy_substrate_contract(contract_path, chain, endpoint, account_name, dry_run).await
     } else {
-        deploy_evm_contract(contract_path, chain, endpoint, account_name, dry_run).await
+        deploy_evm_contract(contract_path, chain, endpoint, account_name, salt, dry_run).await
     }
 }
 
@@ -279,9 +283,20 @@ async fn deploy_evm_contract(
     chain: &str,
     endpoint: &str,
     account_name: Option<String>,
+    salt: Option<String>,
     dry_run: bool,
 ) -> Result<()> {
+    use alloy::primitives::B256;
+    use apex_sdk_evm::contract::{
+        code_exists, parse_abi, ContractDeployer, DETERMINISTIC_DEPLOYER,
+    };
     use apex_sdk_evm::{wallet::Wallet, EvmAdapter};
+    use std::str::FromStr;
+
+    // Validate the CREATE2 salt before doing any work
+    let salt = salt
+        .map(|salt| B256::from_str(&salt).context("Salt must be a 32-byte hex string"))
+        .transpose()?;
 
     let title = if dry_run {
         "Dry-Run: EVM Contract Deployment"
@@ -324,12 +339,14 @@ async fn deploy_evm_contract(
 
     // Check if it's bytecode (.bin) or ABI+bytecode (.json)
     let extension = path.extension().and_then(|s| s.to_str());
-    let contract_data = match extension {
+    let (contract_data, abi) = match extension {
         Some("bin") | Some("hex") => {
             // Raw bytecode
             let code = std::fs::read_to_string(contract_path)
                 .context("Failed to read contract bytecode")?;
-            hex::decode(code.trim().trim_start_matches("0x")).context("Invalid hex bytecode")?
+            let code = hex::decode(code.trim().trim_start_matches("0x"))
+                .context("Invalid hex bytecode")?;
+            (code, None)
         }
         Some("json") => {
             // JSON with bytecode (common Solidity compiler output)
@@ -346,8 +363,15 @@ async fn deploy_evm_contract(
                 .and_then(|v| v.as_str())
                 .ok_or_else(|| anyhow::anyhow!("Could not find bytecode in JSON file"))?;
 
-            hex::decode(bytecode_hex.trim().trim_start_matches("0x"))
-                .context("Invalid hex bytecode in JSON")?
+            let code = hex::decode(bytecode_hex.trim().trim_start_matches("0x"))
+                .context("Invalid hex bytecode in JSON")?;
+
+            // Keep the ABI of compiler artifacts for constructor encoding
+            let abi = match json.get("abi") {
+                Some(_) => Some(parse_abi(&json_str).context("Invalid ABI in JSON")?),
+                None => None,
+            };
+            (code, abi)
         }
         Some(ext) => {
             anyhow::bail!(
@@ -408,12 +432,47 @@ async fn deploy_evm_contract(
 
     let wallet = wallet.with_chain_id(chain_id);
 
+    let mut deployer = ContractDeployer::new(contract_data.clone());
+    if let Some(abi) = abi {
+        deployer = deployer.with_abi(abi);
+    }
+    if let Some(salt) = salt {
+        deployer = deployer.with_salt(salt);
+    }
+
+    spinner.set_message("Predicting contract address...");
+
+    let predicted_address = deployer
+        .predict_address(&adapter, wallet.eth_address())
+        .await
+        .context("Failed to predict contract address")?;
+
+    if salt.is_some() {
+        if code_exists(&adapter, predicted_address)
+            .await
+            .context("Failed to check for existing contract")?
+        {
+            spinner.finish_and_clear();
+            anyhow::bail!("Contract already deployed at {}", predicted_address);
+        }
+        if !code_exists(&adapter, DETERMINISTIC_DEPLOYER)
+            .await
+            .context("Failed to check for CREATE2 factory")?
+        {
+            spinner.finish_and_clear();
+            anyhow::bail!(
+                "CREATE2 factory {} is not deployed on this chain",
+                DETERMINISTIC_DEPLOYER
+            );
+        }
+    }
+
     spinner.set_message("Estimating gas...");
 
     // Get current gas price from the network
     use alloy::providers::Provider;
-    let provider = adapter.provider();
-    let gas_price = provider
+    let gas_price = adapter
+        .provider()
         .provider
         .get_gas_price()
         .await
@@ -439,6 +498,11 @@ async fn deploy_evm_contract(
     println!("{}: {}", "Deployer".dimmed(), signer_name);
     println!("{}: {}", "From Address".dimmed(), wallet.address());
     println!("{}: {}", "Chain ID".dimmed(), chain_id);
+    match salt {
+        Some(salt) => println!("{}: CREATE2 (salt {})", "Method".dimmed(), salt),
+        None => println!("{}: CREATE", "Method".dimmed()),
+    }
+    println!("{}: {}", "Predicted Address".dimmed(), predicted_address);
     println!("{}: {}", "Gas Estimate".dimmed(), gas_estimate);
 
     // Display actual gas price from network
@@ -461,8 +525,8 @@ async fn deploy_evm_contract(
         println!("  - Contract file is valid");
         println!("  - Connected to chain");
         println!("  - Account is ready");
+        println!("  - Contract address predicted");
         println!("  - Gas estimation successful");
-        println!("  - Transaction can be constructed");
         println!();
         println!("{}", "Ready for Real Deployment".cyan().bold());
         println!("To perform the actual deployment, run the same command without --dry-run:");
@@ -493,86 +557,37 @@ async fn deploy_evm_contract(
         }
 
         println!("\n{}", "Broadcasting transaction...".cyan());
-
-        // Build deployment transaction
-        use alloy::network::TransactionBuilder;
-        use alloy::primitives::{Bytes, U256};
-        use alloy::rpc::types::TransactionRequest;
-
-        // Get nonce
-        let from_address: alloy::primitives::Address = wallet
-            .address()
-            .to_string()
-            .parse()
-            .context("Failed to parse wallet address")?;
-
-        let nonce = provider
-            .provider
-            .get_transaction_count(from_address)
-            .await
-            .context("Failed to get nonce")?;
-
-        // Create deployment transaction request (no 'to' address for contract creation)
-        let tx_request = TransactionRequest::default()
-            .with_from(from_address)
-            .with_chain_id(chain_id)
-            .with_nonce(nonce)
-            .with_gas_limit(gas_estimate)
-            .with_gas_price(gas_price)
-            .with_value(U256::ZERO)
-            .with_input(Bytes::from(contract_data.clone()));
-
-        // Send transaction directly using provider
-        use alloy::providers::Provider;
-        let pending_tx = provider
-            .provider
-            .send_transaction(tx_request)
-            .await
-            .context("Failed to send deployment transaction")?;
-
-        let tx_hash = format!("0x{:x}", *pending_tx.tx_hash());
-        println!("{}: {}", "Transaction Hash".cyan(), &tx_hash);
-
-        // Wait for confirmation
         println!("{}", "Waiting for confirmation...".yellow());
 
-        // Wait for transaction receipt
-        let receipt = pending_tx
-            .get_receipt()
-            .await
-            .context("Failed to get transaction receipt")?;
-
-        // Extract results from receipt
-        let contract_address = receipt
-            .contract_address
-            .map(|addr| format!("0x{:x}", addr))
-            .unwrap_or_else(|| "N/A".to_string());
+        let executor = adapter.get_transaction_executor();
+        let deployment = match deployer.deploy(&adapter, &executor, &wallet).await {
+            Ok(deployment) => deployment,
+            Err(e) => {
+                println!("\n{}", "Deployment Failed!".red().bold());
+                println!("{}: {}", "Error".red(), e);
+                return Err(anyhow::anyhow!("Contract deployment failed: {}", e));
+            }
+        };
 
+        let receipt = &deployment.receipt;
         let block_number = receipt.block_number.unwrap_or(0);
         let actual_gas_used = receipt.gas_used;
         let effective_gas_price = receipt.effective_gas_price;
 
-        // Check deployment status
-        if !receipt.status() {
-            println!("\n{}", "Deployment Failed!".red().bold());
-            println!("{}: Transaction reverted", "Error".red());
-            return Err(anyhow::anyhow!("Contract deployment transaction reverted"));
-        }
-
         println!("\n{}", "Deployment Successful".green().bold());
         println!("{}", "═══════════════════════════════════════".dimmed());
         println!(
             "{}: {}",
             "Contract Address".green().bold(),
-            contract_address
+            deployment.address
         );
-        println!("{}: {}", "Transaction Hash".cyan(), tx_hash);
+        println!("{}: 0x{:x}", "Transaction Hash".cyan(), deployment.tx_hash);
         println!("{}: {}", "Block Number".dimmed(), block_number);
         println!("{}: {}", "Gas Used".dimmed(), actual_gas_used);
 
         // Calculate actual cost with real values from receipt
         let gas_used = actual_gas_used as u128;
-        let actual_cost_wei = gas_used * (effective_gas_price as u128);
+        let actual_cost_wei = gas_used * effective_gas_price;
 
         // Format to ETH
         let actual_cost_eth = format_wei_to_eth(actual_cost_wei);
@@ -714,6 +729,7 @@ mod tests {
             "polkadot",
             "wss://polkadot.api.onfinality.io",
             None,
+            None,
             true, // dry_run
         )
         .await;
@@ -734,6 +750,7 @@ mod tests {
             "invalid_chain",
             "https://invalid.endpoint",
             None,
+            None,
             true, // dry_run
         )
         .await;
@@ -839,6 +856,7 @@ mod tests {
             "polkadot",
             "wss://invalid.endpoint", // Invalid endpoint should be okay for dry run
             Some("Ilara".to_string()),
+            None,
             true, // dry_run
         )
         .await;
@@ -55,6 +55,9 @@ enum Commands {
         /// Account name to use for deployment
         #[arg(short, long)]
         account: Option<String>,
+        /// 32-byte hex salt to deploy EVM contracts with CREATE2
+        #[arg(long)]
+        salt: Option<String>,
         /// Perform a dry-run without broadcasting the transaction
         #[arg(long)]
         dry_run: bool,
@@ -217,6 +220,7 @@ async fn main() -> anyhow::Result<()> {
             chain,
             endpoint,
             account,
+            salt,
             dry_run,
         } => {
             if dry_run {
@@ -224,7 +228,7 @@ async fn main() -> anyhow::Result<()> {
             } else {
                 println!("Deploying contract...");
             }
-            deploy::deploy_contract(&contract, &chain, &endpoint, account, dry_run).await?;
+            deploy::deploy_contract(&contract, &chain, &endpoint, account, salt, dry_run).await?;
         }
         Commands::Account { action } => match action {
             AccountCommands::Generate { account_type, name } => {